@@ -1,8 +1,10 @@
 use casper_event_types::metrics;
+use serde::Serialize;
 
 /// Helper enum determining in what state connection to a node is in.
 /// It's used to named different situations in which the connection can be.
-pub(super) enum EventListenerStatus {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum EventListenerStatus {
     /// Event Listener has not yet started to attempt the connection
     Preparing,
     /// Event Listener started establishing relevant sse connections to filters of the node
@@ -30,9 +32,47 @@ impl EventListenerStatus {
             EventListenerStatus::Defunct => -1,
             EventListenerStatus::IncompatibleVersion => -2,
         } as f64;
-        let node_label = format!("{}:{}", node_address, sse_port);
         metrics::NODE_STATUSES
-            .with_label_values(&[node_label.as_str()])
+            .with_label_values(&[node_label(node_address, sse_port).as_str()])
             .set(status);
     }
+
+    /// Returns the last status logged for the node, `Preparing` if none was logged yet.
+    pub fn of_node(node_address: &str, sse_port: u16) -> Self {
+        let status = metrics::NODE_STATUSES
+            .with_label_values(&[node_label(node_address, sse_port).as_str()])
+            .get() as i64;
+        match status {
+            1 => EventListenerStatus::Connecting,
+            2 => EventListenerStatus::Connected,
+            3 => EventListenerStatus::Reconnecting,
+            -1 => EventListenerStatus::Defunct,
+            -2 => EventListenerStatus::IncompatibleVersion,
+            _ => EventListenerStatus::Preparing,
+        }
+    }
+}
+
+pub(crate) fn node_label(node_address: &str, sse_port: u16) -> String {
+    format!("{}:{}", node_address, sse_port)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_back_logged_status_of_node() {
+        assert_eq!(
+            EventListenerStatus::of_node("10.0.0.1", 9999),
+            EventListenerStatus::Preparing
+        );
+
+        EventListenerStatus::IncompatibleVersion.log_status("10.0.0.1", 9999);
+
+        assert_eq!(
+            EventListenerStatus::of_node("10.0.0.1", 9999),
+            EventListenerStatus::IncompatibleVersion
+        );
+    }
 }
@@ -7,16 +7,18 @@ mod connection_tasks;
 pub mod connections_builder;
 mod event_listener_status;
 mod keep_alive_monitor;
+mod node_state;
 mod sse_connector;
 mod types;
 mod version_fetcher;
-use crate::event_listener_status::*;
 use anyhow::Error;
 use casper_event_types::Filter;
 use casper_types::ProtocolVersion;
 use connection_manager::{ConnectionManager, ConnectionManagerError};
 use connection_tasks::ConnectionTasks;
 use connections_builder::{ConnectionsBuilder, DefaultConnectionsBuilder};
+pub use event_listener_status::EventListenerStatus;
+pub use node_state::NodeState;
 use std::{collections::HashMap, net::IpAddr, str::FromStr, sync::Arc, time::Duration};
 use tokio::{
     sync::{
@@ -120,6 +122,11 @@ impl EventListener {
             match self.get_version(current_attempt).await {
                 GetVersionResult::Ok(Some(protocol_version)) => {
                     self.node_build_version = protocol_version;
+                    NodeState::record_build_version(
+                        &self.node.ip_address.to_string(),
+                        self.node.sse_port,
+                        protocol_version,
+                    );
                     current_attempt = 1 // Restart counter if the nodes version changed
                 }
                 GetVersionResult::Retry => {
@@ -272,6 +279,7 @@ impl EventListener {
         tokio::spawn(async move {
             while let Some((filter, id)) = last_seen_event_id_receiver.recv().await {
                 EventListenerStatus::Connected.log_status(node_address.as_str(), sse_port);
+                NodeState::record_last_event_id(node_address.as_str(), sse_port, &filter, id);
                 let last_event_id_for_filter_clone = last_event_id_for_filter_for_thread.clone();
                 let mut guard = last_event_id_for_filter_clone.lock().await;
                 guard.insert(filter, id);
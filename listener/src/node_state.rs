@@ -0,0 +1,94 @@
+use crate::event_listener_status::node_label;
+use casper_event_types::Filter;
+use casper_types::ProtocolVersion;
+use once_cell::sync::Lazy;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::RwLock,
+};
+
+/// State of the connections of every node the listeners of this process connect to, keyed by
+/// node label.
+static NODE_STATES: Lazy<RwLock<HashMap<String, NodeState>>> = Lazy::new(Default::default);
+
+/// What Event Listener knows about a node besides its [EventListenerStatus](crate::EventListenerStatus).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NodeState {
+    /// Build version reported by the node, `None` until it was fetched.
+    pub node_build_version: Option<ProtocolVersion>,
+    /// Id of the last event received through each filter of the node, keyed by the path of the
+    /// filter, e.g. `events/main`.
+    pub last_event_id_for_filter: BTreeMap<String, u32>,
+}
+
+impl NodeState {
+    /// Returns the state recorded for the node, the default one if nothing was recorded yet.
+    pub fn of_node(node_address: &str, sse_port: u16) -> Self {
+        NODE_STATES
+            .read()
+            .expect("Error acquiring lock on node states")
+            .get(&node_label(node_address, sse_port))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub(super) fn record_build_version(
+        node_address: &str,
+        sse_port: u16,
+        node_build_version: ProtocolVersion,
+    ) {
+        update(node_address, sse_port, |state| {
+            state.node_build_version = Some(node_build_version)
+        });
+    }
+
+    pub(super) fn record_last_event_id(
+        node_address: &str,
+        sse_port: u16,
+        filter: &Filter,
+        event_id: u32,
+    ) {
+        update(node_address, sse_port, |state| {
+            state
+                .last_event_id_for_filter
+                .insert(filter.to_string(), event_id);
+        });
+    }
+}
+
+fn update(node_address: &str, sse_port: u16, update_state: impl FnOnce(&mut NodeState)) {
+    let mut node_states = NODE_STATES
+        .write()
+        .expect("Error acquiring lock on node states");
+    update_state(
+        node_states
+            .entry(node_label(node_address, sse_port))
+            .or_default(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_back_recorded_state_of_node() {
+        assert_eq!(NodeState::of_node("10.0.0.2", 9999), NodeState::default());
+
+        let node_build_version = ProtocolVersion::from_parts(1, 5, 2);
+        NodeState::record_build_version("10.0.0.2", 9999, node_build_version);
+        NodeState::record_last_event_id("10.0.0.2", 9999, &Filter::Main, 5);
+        NodeState::record_last_event_id("10.0.0.2", 9999, &Filter::Main, 7);
+        NodeState::record_last_event_id("10.0.0.2", 9999, &Filter::Sigs, 3);
+
+        let state = NodeState::of_node("10.0.0.2", 9999);
+        assert_eq!(state.node_build_version, Some(node_build_version));
+        assert_eq!(
+            state.last_event_id_for_filter,
+            BTreeMap::from([
+                ("events/main".to_string(), 7),
+                ("events/sigs".to_string(), 3)
+            ])
+        );
+    }
+}
@@ -1,27 +1,42 @@
-use crate::types::config::AdminServerConfig;
-use crate::utils::{resolve_address, root_filter, Unexpected};
+mod health;
+
+use crate::connections;
+use crate::types::config::{AdminServerConfig, TlsConfig};
+use crate::utils::{bind_address, resolve_address, root_filter, Unexpected};
 use anyhow::Error;
 use casper_event_types::metrics::metrics_summary;
-use hyper::Server;
-use std::net::TcpListener;
+use health::ReadinessChecks;
+pub(crate) use health::{DatabaseHandle, SidecarState};
+use hyper::{server::accept, Server};
+use std::sync::Arc;
 use std::time::Duration;
 use tower::{buffer::Buffer, make::Shared, ServiceBuilder};
 use warp::Filter;
 use warp::{Rejection, Reply};
 
-const BIND_ALL_INTERFACES: &str = "0.0.0.0";
 struct AdminServer {
+    address: Option<String>,
     port: u16,
+    unix_socket_path: Option<String>,
     max_concurrent_requests: u32,
     max_requests_per_second: u32,
+    tls: Option<TlsConfig>,
+    readiness_checks: Arc<ReadinessChecks>,
 }
 
 impl AdminServer {
     pub async fn start(&self) -> Result<(), Error> {
-        let api = root_filter().or(metrics_filter());
-        let address = format!("{}:{}", BIND_ALL_INTERFACES, self.port);
+        let api = root_filter()
+            .or(metrics_filter())
+            .or(health::live_filter())
+            .or(health::ready_filter(self.readiness_checks.clone()));
+        let address = bind_address(self.address.as_deref(), self.port);
         let socket_address = resolve_address(&address)?;
-        let listener = TcpListener::bind(socket_address)?;
+        let (_, connections) = connections::listen(
+            socket_address,
+            self.unix_socket_path.as_deref(),
+            self.tls.as_ref(),
+        )?;
 
         let warp_service = warp::service(api);
         let tower_service = ServiceBuilder::new()
@@ -29,7 +44,7 @@ impl AdminServer {
             .rate_limit(self.max_requests_per_second as u64, Duration::from_secs(1))
             .service(warp_service);
 
-        Server::from_tcp(listener)?
+        Server::builder(accept::from_stream(connections))
             .serve(Shared::new(Buffer::new(tower_service, 50)))
             .await?;
 
@@ -37,11 +52,19 @@ impl AdminServer {
     }
 }
 
-pub async fn run_server(config: AdminServerConfig) -> Result<(), Error> {
+pub async fn run_server(
+    config: AdminServerConfig,
+    sidecar_state: SidecarState,
+) -> Result<(), Error> {
+    let readiness_checks = Arc::new(ReadinessChecks::new(&config, sidecar_state));
     AdminServer {
+        address: config.address,
         port: config.port,
+        unix_socket_path: config.unix_socket_path,
         max_concurrent_requests: config.max_concurrent_requests,
         max_requests_per_second: config.max_requests_per_second,
+        tls: config.tls,
+        readiness_checks,
     }
     .start()
     .await
@@ -65,26 +88,120 @@ async fn metrics_handler() -> Result<impl Reply, Rejection> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{admin_server::run_server, types::config::AdminServerConfig};
+    use crate::{
+        admin_server::{run_server, SidecarState},
+        types::config::AdminServerConfig,
+    };
+    use http::StatusCode;
     use portpicker::pick_unused_port;
     use reqwest::Response;
+    use tokio::sync::{mpsc, watch};
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     async fn given_config_should_start_admin_server() {
         let port = pick_unused_port().unwrap();
         let request_url = format!("http://localhost:{}/metrics", port);
         let admin_config = AdminServerConfig {
+            address: None,
             port,
+            unix_socket_path: None,
             max_concurrent_requests: 1,
             max_requests_per_second: 1,
+            tls: None,
+            max_event_age_in_seconds: None,
+            max_outbound_channel_saturation_percent: None,
         };
-        tokio::spawn(run_server(admin_config));
+        tokio::spawn(run_server(admin_config, build_sidecar_state()));
 
         let response = fetch_metrics_data(&request_url).await;
         let text = response.text().await.unwrap();
         assert!(text.contains("process_cpu_seconds_total"));
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn given_database_not_built_should_be_live_but_not_ready() {
+        let port = pick_unused_port().unwrap();
+        let admin_config = AdminServerConfig {
+            address: None,
+            port,
+            unix_socket_path: None,
+            max_concurrent_requests: 10,
+            max_requests_per_second: 10,
+            tls: None,
+            max_event_age_in_seconds: None,
+            max_outbound_channel_saturation_percent: None,
+        };
+        tokio::spawn(run_server(admin_config, build_sidecar_state()));
+
+        let live_url = format!("http://localhost:{}/health/live", port);
+        let response = reqwest::get(&live_url)
+            .await
+            .expect("Error requesting the /health/live endpoint");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let ready_url = format!("http://localhost:{}/health/ready", port);
+        let response = reqwest::get(&ready_url)
+            .await
+            .expect("Error requesting the /health/ready endpoint");
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body: serde_json::Value = serde_json::from_str(&response.text().await.unwrap())
+            .expect("Error parsing readiness report");
+        assert_eq!(body["database"], "not_ready");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn given_unix_socket_path_should_serve_admin_server_on_socket() {
+        use hyper::{body, client::conn, Body, Request};
+        use std::time::Duration;
+        use tokio::net::UnixStream;
+
+        let socket_dir = tempfile::tempdir().unwrap();
+        let socket_path = socket_dir.path().join("admin.sock");
+        let admin_config = AdminServerConfig {
+            address: None,
+            port: 0,
+            unix_socket_path: Some(socket_path.to_str().unwrap().to_string()),
+            max_concurrent_requests: 1,
+            max_requests_per_second: 1,
+            tls: None,
+            max_event_age_in_seconds: None,
+            max_outbound_channel_saturation_percent: None,
+        };
+        tokio::spawn(run_server(admin_config, build_sidecar_state()));
+
+        let mut attempts = 0;
+        let stream = loop {
+            match UnixStream::connect(&socket_path).await {
+                Ok(stream) => break stream,
+                Err(error) if attempts == 50 => panic!("Error connecting to socket: {}", error),
+                Err(_) => {
+                    attempts += 1;
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+            }
+        };
+        let (mut sender, connection) = conn::handshake(stream).await.unwrap();
+        tokio::spawn(connection);
+        let request = Request::get("/metrics")
+            .header("host", "localhost")
+            .body(Body::empty())
+            .unwrap();
+        let response = sender.send_request(request).await.unwrap();
+        let bytes = body::to_bytes(response.into_body()).await.unwrap();
+
+        assert!(String::from_utf8_lossy(&bytes).contains("process_cpu_seconds_total"));
+    }
+
+    /// Builds the state of a sidecar whose database is still being built.
+    fn build_sidecar_state() -> SidecarState {
+        SidecarState {
+            database: watch::channel(None).1,
+            nodes: vec![],
+            outbound_sender: mpsc::channel(10).0,
+        }
+    }
+
     async fn fetch_metrics_data(request_url: &String) -> Response {
         reqwest::Client::new()
             .get(request_url)
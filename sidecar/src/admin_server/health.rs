@@ -0,0 +1,325 @@
+//! Liveness and readiness probes of the sidecar.
+
+use crate::types::{
+    config::AdminServerConfig,
+    database::{Database, DatabaseReader},
+};
+use casper_event_listener::EventListenerStatus;
+use casper_event_types::{metrics, sse_data::SseData, Filter as SseFilter};
+use serde::Serialize;
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::{mpsc::Sender, watch};
+use warp::{http::StatusCode, Filter, Rejection, Reply};
+
+/// Default percentage of the outbound channel which can be filled for the sidecar to be ready.
+const DEFAULT_MAX_OUTBOUND_CHANNEL_SATURATION_PERCENT: u32 = 90;
+
+/// Sender of the channel through which events are passed to the event stream server.
+pub(crate) type OutboundSender = Sender<(SseData, Option<SseFilter>, Option<String>)>;
+
+/// The database, set once it's built and its migrations ran.
+pub(crate) type DatabaseHandle = watch::Receiver<Option<Database>>;
+
+/// Parts of the running sidecar inspected by the readiness probe.
+pub(crate) struct SidecarState {
+    pub(crate) database: DatabaseHandle,
+    /// IP address and SSE port of every node the sidecar connects to.
+    pub(crate) nodes: Vec<(String, u16)>,
+    pub(crate) outbound_sender: OutboundSender,
+}
+
+pub(super) struct ReadinessChecks {
+    state: SidecarState,
+    max_event_age: Option<Duration>,
+    max_outbound_channel_saturation_percent: u32,
+}
+
+#[derive(Serialize)]
+struct ReadinessReport {
+    ready: bool,
+    database: DatabaseStatus,
+    nodes: Vec<NodeReport>,
+    /// `None` if no event was received yet.
+    last_event_age_in_seconds: Option<u64>,
+    outbound_channel_saturation_percent: u32,
+    /// Reasons why the sidecar isn't ready, empty if it is.
+    failures: Vec<String>,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum DatabaseStatus {
+    /// The database is still being built, e.g. because its migrations are running.
+    NotReady,
+    Unreachable,
+    Reachable,
+}
+
+#[derive(Serialize)]
+struct NodeReport {
+    node: String,
+    status: EventListenerStatus,
+}
+
+impl ReadinessChecks {
+    pub(super) fn new(config: &AdminServerConfig, state: SidecarState) -> Self {
+        ReadinessChecks {
+            state,
+            max_event_age: config.max_event_age_in_seconds.map(Duration::from_secs),
+            max_outbound_channel_saturation_percent: config
+                .max_outbound_channel_saturation_percent
+                .unwrap_or(DEFAULT_MAX_OUTBOUND_CHANNEL_SATURATION_PERCENT),
+        }
+    }
+
+    async fn report(&self) -> ReadinessReport {
+        let mut failures = Vec::new();
+
+        let database = self.database_status().await;
+        match database {
+            DatabaseStatus::NotReady => failures.push("database is not ready".to_string()),
+            DatabaseStatus::Unreachable => failures.push("database is unreachable".to_string()),
+            DatabaseStatus::Reachable => {}
+        }
+
+        let nodes: Vec<NodeReport> = self
+            .state
+            .nodes
+            .iter()
+            .map(|(node_address, sse_port)| NodeReport {
+                node: format!("{}:{}", node_address, sse_port),
+                status: EventListenerStatus::of_node(node_address, *sse_port),
+            })
+            .collect();
+        if !nodes
+            .iter()
+            .any(|node| node.status == EventListenerStatus::Connected)
+        {
+            failures.push("no node is connected".to_string());
+        }
+
+        let last_event_age = last_event_age();
+        if let Some(max_event_age) = self.max_event_age {
+            match last_event_age {
+                Some(age) if age <= max_event_age => {}
+                Some(age) => failures.push(format!(
+                    "last event was received {} seconds ago",
+                    age.as_secs()
+                )),
+                None => failures.push("no event was received yet".to_string()),
+            }
+        }
+
+        let saturation_percent = outbound_channel_saturation_percent(&self.state.outbound_sender);
+        if saturation_percent > self.max_outbound_channel_saturation_percent {
+            failures.push(format!("outbound channel is {}% full", saturation_percent));
+        }
+
+        ReadinessReport {
+            ready: failures.is_empty(),
+            database,
+            nodes,
+            last_event_age_in_seconds: last_event_age.map(|age| age.as_secs()),
+            outbound_channel_saturation_percent: saturation_percent,
+            failures,
+        }
+    }
+
+    async fn database_status(&self) -> DatabaseStatus {
+        // The handle is cloned so that it isn't borrowed while the database is queried.
+        let maybe_database = self.state.database.borrow().clone();
+        let newest_migration_version = match maybe_database {
+            Some(Database::SqliteDatabaseWrapper(db)) => db.get_newest_migration_version().await,
+            Some(Database::PostgreSqlDatabaseWrapper(db)) => {
+                db.get_newest_migration_version().await
+            }
+            None => return DatabaseStatus::NotReady,
+        };
+        match newest_migration_version {
+            Ok(_) => DatabaseStatus::Reachable,
+            Err(_) => DatabaseStatus::Unreachable,
+        }
+    }
+}
+
+/// Return: 200 as long as the admin server responds, including while the database is being built.
+/// Path URL: health/live
+/// Example: curl http://127.0.0.1:18887/health/live
+/// {"status":"live"}
+pub(super) fn live_filter() -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+    warp::path!("health" / "live")
+        .and(warp::get())
+        .map(|| warp::reply::json(&serde_json::json!({ "status": "live" })))
+}
+
+/// Return: the state of the database, of the node connections, of event ingestion and of the
+/// outbound channel, with 200 if the sidecar is ready and 503 otherwise.
+/// Path URL: health/ready
+/// Example: curl http://127.0.0.1:18887/health/ready
+/// {"ready":true,"database":"reachable","nodes":[{"node":"127.0.0.1:18101","status":"Connected"}],"last_event_age_in_seconds":4,"outbound_channel_saturation_percent":0,"failures":[]}
+pub(super) fn ready_filter(
+    checks: Arc<ReadinessChecks>,
+) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+    warp::path!("health" / "ready")
+        .and(warp::get())
+        .and_then(move || {
+            let checks = checks.clone();
+            async move {
+                let report = checks.report().await;
+                let status = if report.ready {
+                    StatusCode::OK
+                } else {
+                    StatusCode::SERVICE_UNAVAILABLE
+                };
+                Ok::<_, Rejection>(warp::reply::with_status(warp::reply::json(&report), status))
+            }
+        })
+}
+
+fn last_event_age() -> Option<Duration> {
+    let last_event_timestamp = metrics::LAST_INGESTED_EVENT_TIMESTAMP.get();
+    if last_event_timestamp <= 0.0 {
+        return None;
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+    Some(now.saturating_sub(Duration::from_secs_f64(last_event_timestamp)))
+}
+
+fn outbound_channel_saturation_percent(outbound_sender: &OutboundSender) -> u32 {
+    let max_capacity = outbound_sender.max_capacity();
+    let used = max_capacity - outbound_sender.capacity();
+    (used * 100 / max_capacity) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::sqlite_database::SqliteDatabase;
+    use tokio::sync::mpsc;
+
+    async fn build_database() -> Option<Database> {
+        let sqlite_database = SqliteDatabase::new_in_memory(1)
+            .await
+            .expect("Error opening database in memory");
+        Some(Database::SqliteDatabaseWrapper(sqlite_database))
+    }
+
+    fn build_checks(
+        maybe_database: Option<Database>,
+        nodes: Vec<(String, u16)>,
+        outbound_sender: OutboundSender,
+    ) -> Arc<ReadinessChecks> {
+        let config = AdminServerConfig {
+            address: None,
+            port: 0,
+            unix_socket_path: None,
+            max_concurrent_requests: 1,
+            max_requests_per_second: 1,
+            tls: None,
+            max_event_age_in_seconds: None,
+            max_outbound_channel_saturation_percent: None,
+        };
+        let (_database_sender, database) = watch::channel(maybe_database);
+        let state = SidecarState {
+            database,
+            nodes,
+            outbound_sender,
+        };
+        Arc::new(ReadinessChecks::new(&config, state))
+    }
+
+    fn set_connected(node: &str) {
+        // The event listener logs `Connected` as 2.
+        metrics::NODE_STATUSES.with_label_values(&[node]).set(2.0);
+    }
+
+    async fn request_readiness(checks: Arc<ReadinessChecks>) -> (StatusCode, serde_json::Value) {
+        let response = warp::test::request()
+            .path("/health/ready")
+            .reply(&ready_filter(checks))
+            .await;
+        let body = serde_json::from_slice(response.body()).expect("Error parsing readiness report");
+        (response.status(), body)
+    }
+
+    #[tokio::test]
+    async fn should_report_liveness() {
+        let response = warp::test::request()
+            .path("/health/live")
+            .reply(&live_filter())
+            .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_not_be_ready_without_connected_node() {
+        let (outbound_sender, _outbound_receiver) = mpsc::channel(10);
+        let checks = build_checks(
+            build_database().await,
+            vec![("127.0.0.3".to_string(), 1)],
+            outbound_sender,
+        );
+
+        let (status, body) = request_readiness(checks).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["ready"], false);
+        assert_eq!(body["nodes"][0]["status"], "Preparing");
+        assert_eq!(body["failures"][0], "no node is connected");
+    }
+
+    #[tokio::test]
+    async fn should_be_ready_with_connected_node() {
+        set_connected("127.0.0.2:1");
+        let (outbound_sender, _outbound_receiver) = mpsc::channel(10);
+        let checks = build_checks(
+            build_database().await,
+            vec![("127.0.0.2".to_string(), 1)],
+            outbound_sender,
+        );
+
+        let (status, body) = request_readiness(checks).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["ready"], true);
+        assert_eq!(body["database"], "reachable");
+        assert_eq!(body["outbound_channel_saturation_percent"], 0);
+    }
+
+    #[tokio::test]
+    async fn should_not_be_ready_with_saturated_outbound_channel() {
+        set_connected("127.0.0.4:1");
+        let (outbound_sender, _outbound_receiver) = mpsc::channel(1);
+        outbound_sender
+            .send((SseData::Shutdown, None, None))
+            .await
+            .unwrap();
+        let checks = build_checks(
+            build_database().await,
+            vec![("127.0.0.4".to_string(), 1)],
+            outbound_sender,
+        );
+
+        let (status, body) = request_readiness(checks).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["outbound_channel_saturation_percent"], 100);
+    }
+
+    #[tokio::test]
+    async fn should_not_be_ready_before_database_is_built() {
+        set_connected("127.0.0.5:1");
+        let (outbound_sender, _outbound_receiver) = mpsc::channel(10);
+        let checks = build_checks(None, vec![("127.0.0.5".to_string(), 1)], outbound_sender);
+
+        let (status, body) = request_readiness(checks).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["database"], "not_ready");
+        assert_eq!(body["failures"][0], "database is not ready");
+    }
+}
@@ -0,0 +1,83 @@
+//! Parts of the API key authentication shared by the REST server and the event stream server.
+//!
+//! Clients pass their key in the [API_KEY_HEADER] header or, if they can't set headers (as is the
+//! case of browsers' `EventSource`), in the [API_KEY_QUERY_PARAM] query parameter.
+
+use casper_event_types::metrics;
+use std::collections::HashMap;
+use subtle::ConstantTimeEq;
+
+/// Name of the header holding the API key.
+pub(crate) const API_KEY_HEADER: &str = "x-api-key";
+/// Name of the query parameter holding the API key, used if the header isn't set.
+pub(crate) const API_KEY_QUERY_PARAM: &str = "api_key";
+
+/// Reason for rejecting a request because of its API key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ApiKeyRejection {
+    /// No key was provided.
+    Missing,
+    /// The provided key isn't configured.
+    Unknown,
+    /// The key exceeded its number of requests per second.
+    RateLimited,
+    /// The key reached its number of concurrent subscriptions.
+    TooManySubscriptions,
+}
+
+impl ApiKeyRejection {
+    fn as_label(&self) -> &'static str {
+        match self {
+            ApiKeyRejection::Missing => "missing",
+            ApiKeyRejection::Unknown => "unknown",
+            ApiKeyRejection::RateLimited => "rate_limited",
+            ApiKeyRejection::TooManySubscriptions => "too_many_subscriptions",
+        }
+    }
+
+    /// Counts the rejection in the `api_key_rejections` metric.
+    ///
+    /// * `server` - name of the server which rejected the request
+    /// * `key_name` - configured name of the provided key, empty if no known key was provided
+    pub(crate) fn record(&self, server: &str, key_name: &str) {
+        metrics::API_KEY_REJECTIONS
+            .with_label_values(&[server, key_name, self.as_label()])
+            .inc();
+    }
+}
+
+/// Returns the API key provided in the header or, if the header isn't set, in the query.
+pub(crate) fn provided_api_key(
+    maybe_header: Option<String>,
+    query: &HashMap<String, String>,
+) -> Option<String> {
+    maybe_header.or_else(|| query.get(API_KEY_QUERY_PARAM).cloned())
+}
+
+/// Returns the value configured for `api_key` among the `(key, value)` pairs of `keys`. Keys are
+/// compared in constant time, and all of them are compared, so that the time taken doesn't reveal
+/// how much of a configured key was guessed.
+pub(crate) fn find_api_key<'a, T>(keys: &'a [(String, T)], api_key: &str) -> Option<&'a T> {
+    let mut found = None;
+    for (key, value) in keys {
+        if bool::from(key.as_bytes().ct_eq(api_key.as_bytes())) {
+            found = Some(value);
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_configured_api_key_only() {
+        let keys = vec![("first".to_string(), 1), ("second".to_string(), 2)];
+
+        assert_eq!(find_api_key(&keys, "second"), Some(&2));
+        assert_eq!(find_api_key(&keys, "secon"), None);
+        assert_eq!(find_api_key(&keys, "second!"), None);
+        assert_eq!(find_api_key(&keys, ""), None);
+    }
+}
@@ -0,0 +1,142 @@
+//! Listeners shared by the REST server, the event stream server and the admin server.
+//!
+//! A server listens either on a TCP address or on a Unix domain socket, and optionally terminates
+//! TLS on the accepted connections. Either way it gets a single stream of [Connection]s to serve.
+
+use crate::{tls, types::config::TlsConfig, utils::ListeningError};
+use futures::{Stream, StreamExt};
+use std::{
+    fmt::{self, Display, Formatter},
+    io,
+    net::{SocketAddr, TcpListener as StdTcpListener},
+    path::PathBuf,
+    pin::Pin,
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpListener,
+    time,
+};
+use tokio_stream::wrappers::TcpListenerStream;
+use tracing::warn;
+
+/// Pause after a failure to accept a connection, e.g. because of too many open files.
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_secs(1);
+
+/// A connection accepted by one of the servers.
+pub(crate) trait Connection: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Connection for T {}
+
+/// The connections accepted by a server, to be served by `hyper` or `warp`.
+pub(crate) type Connections =
+    Pin<Box<dyn Stream<Item = Result<Box<dyn Connection>, io::Error>> + Send>>;
+
+/// Where a server accepts connections.
+#[derive(Clone, Debug)]
+pub(crate) enum ListeningOn {
+    Tcp(SocketAddr),
+    UnixSocket(PathBuf),
+}
+
+impl Display for ListeningOn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ListeningOn::Tcp(address) => write!(f, "{}", address),
+            ListeningOn::UnixSocket(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+/// Starts listening on the Unix domain socket at `unix_socket_path` if it's set, on `address`
+/// otherwise. If `tls_config` is set, only the connections which completed the TLS handshake are
+/// yielded.
+pub(crate) fn listen(
+    address: SocketAddr,
+    unix_socket_path: Option<&str>,
+    tls_config: Option<&TlsConfig>,
+) -> Result<(ListeningOn, Connections), ListeningError> {
+    let (listening_on, connections) = match unix_socket_path {
+        Some(path) => (
+            ListeningOn::UnixSocket(PathBuf::from(path)),
+            listen_on_unix_socket(path)?,
+        ),
+        None => {
+            let listening_error = |error: io::Error| ListeningError::Listen {
+                address,
+                error: Box::new(error),
+            };
+            let listener = StdTcpListener::bind(address).map_err(listening_error)?;
+            let listening_address = listener.local_addr().map_err(listening_error)?;
+            listener.set_nonblocking(true).map_err(listening_error)?;
+            let listener = TcpListener::from_std(listener).map_err(listening_error)?;
+            (
+                ListeningOn::Tcp(listening_address),
+                into_connections(TcpListenerStream::new(listener)),
+            )
+        }
+    };
+    let connections = match tls_config {
+        Some(tls_config) => {
+            let acceptor =
+                tls::build_acceptor(tls_config).map_err(|error| ListeningError::Tls {
+                    listening_on: listening_on.to_string(),
+                    error,
+                })?;
+            into_connections(tls::accept_tls_connections(connections, acceptor).map(Ok))
+        }
+        None => connections,
+    };
+    Ok((listening_on, connections))
+}
+
+#[cfg(unix)]
+fn listen_on_unix_socket(path: &str) -> Result<Connections, ListeningError> {
+    use std::{fs, os::unix::fs::FileTypeExt};
+    use tokio::net::UnixListener;
+    use tokio_stream::wrappers::UnixListenerStream;
+
+    let listening_error = |error: io::Error| ListeningError::ListenUnixSocket {
+        path: path.to_string(),
+        error: Box::new(error),
+    };
+    // A socket left behind by a previous run would make binding fail. Anything else at the path
+    // is left alone.
+    if let Ok(metadata) = fs::symlink_metadata(path) {
+        if metadata.file_type().is_socket() {
+            fs::remove_file(path).map_err(listening_error)?;
+        }
+    }
+    let listener = UnixListener::bind(path).map_err(listening_error)?;
+    Ok(into_connections(UnixListenerStream::new(listener)))
+}
+
+#[cfg(not(unix))]
+fn listen_on_unix_socket(path: &str) -> Result<Connections, ListeningError> {
+    Err(ListeningError::ListenUnixSocket {
+        path: path.to_string(),
+        error: "Unix domain sockets aren't supported on this platform".into(),
+    })
+}
+
+/// Boxes the accepted connections. Failures to accept a connection are logged and skipped, as
+/// `hyper` would otherwise stop serving.
+fn into_connections<S, C>(accepted: S) -> Connections
+where
+    S: Stream<Item = Result<C, io::Error>> + Send + 'static,
+    C: Connection + 'static,
+{
+    accepted
+        .filter_map(|accepted| async move {
+            match accepted {
+                Ok(connection) => Some(Ok(Box::new(connection) as Box<dyn Connection>)),
+                Err(error) => {
+                    warn!(%error, "error accepting connection");
+                    time::sleep(ACCEPT_ERROR_BACKOFF).await;
+                    None
+                }
+            }
+        })
+        .boxed()
+}
@@ -1,10 +1,19 @@
 use crate::database::{migration_manager::MigrationManager, sqlite_database::SqliteDatabase};
+use crate::sql::tables::{self, event_type::EventTypeId};
 use crate::types::database::{
-    DatabaseReader, DatabaseWriteError, Migration, MigrationScriptExecutor, StatementWrapper,
-    TransactionWrapper,
+    DatabaseReader, DatabaseWriteError, DeployStatus, Migration, MigrationScriptExecutor,
+    StatementWrapper, TransactionWrapper, ValidatorEraReward, ValidatorEraWeight,
 };
+use crate::types::sse_events::{BlockAdded, DeployAccepted, DeployProcessed};
 use async_trait::async_trait;
+use casper_event_types::{block::json_compatibility::JsonEraEnd, ExecutableDeployItem};
+use casper_types::{
+    account::AccountHash, testing::TestRng, AccessRights, AsymmetricType, ContractHash, PublicKey,
+    RuntimeArgs, SecretKey, Transfer, URef, U512,
+};
 use itertools::Itertools;
+use rand::Rng;
+use sea_query::{Alias, Query, SqliteQueryBuilder};
 use sqlx::{Executor, Row};
 use std::sync::Arc;
 
@@ -236,6 +245,446 @@ async fn should_store_failed_version_if_script_fails() {
     assert!(sqlite_db.get_newest_migration_version().await.unwrap() == Some((1, false)))
 }
 
+#[tokio::test]
+async fn migration_2_should_backfill_account_of_stored_deploys() {
+    let mut test_rng = TestRng::new();
+    let sqlite_db = SqliteDatabase::new_in_memory_no_migrations(MAX_CONNECTIONS)
+        .await
+        .expect("Error opening database in memory");
+    MigrationManager::apply_migrations(sqlite_db.clone(), vec![Migration::migration_1()])
+        .await
+        .expect("Error applying migration 1");
+
+    let deploy_accepted = DeployAccepted::random(&mut test_rng);
+    let insert_event_log_stmt = tables::event_log::create_insert_stmt(
+        EventTypeId::DeployAccepted as u8,
+        "127.0.0.1",
+        1,
+        &deploy_accepted.hex_encoded_hash(),
+    )
+    .expect("Error creating event_log insert SQL")
+    .to_string(SqliteQueryBuilder);
+    // Mimics a write made before the account column existed
+    let insert_deploy_accepted_stmt = Query::insert()
+        .into_table(Alias::new("DeployAccepted"))
+        .columns([
+            Alias::new("deploy_hash"),
+            Alias::new("raw"),
+            Alias::new("event_log_id"),
+        ])
+        .values_panic([
+            deploy_accepted.hex_encoded_hash().into(),
+            serde_json::to_string(&deploy_accepted).unwrap().into(),
+            1u64.into(),
+        ])
+        .to_string(SqliteQueryBuilder);
+    sqlite_db
+        .connection_pool
+        .execute(format!("{insert_event_log_stmt};{insert_deploy_accepted_stmt}").as_str())
+        .await
+        .expect("Error inserting DeployAccepted");
+
+    let apply_res = MigrationManager::apply_migrations(
+        sqlite_db.clone(),
+        vec![Migration::migration_1(), Migration::migration_2()],
+    )
+    .await;
+
+    assert!(apply_res.is_ok());
+    assert!(sqlite_db.get_newest_migration_version().await.unwrap() == Some((2, true)));
+    let page = sqlite_db
+        .get_deploy_summaries_by_account(&deploy_accepted.hex_encoded_account(), None, 10)
+        .await
+        .expect("Error getting deploy summaries by account");
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(
+        page.items[0].deploy_hash,
+        deploy_accepted.hex_encoded_hash()
+    );
+    assert_eq!(page.items[0].status, DeployStatus::Accepted);
+}
+
+#[tokio::test]
+async fn migration_4_should_backfill_era_id_of_stored_blocks() {
+    let mut test_rng = TestRng::new();
+    let sqlite_db = SqliteDatabase::new_in_memory_no_migrations(MAX_CONNECTIONS)
+        .await
+        .expect("Error opening database in memory");
+    let migrations_before_era_id = vec![
+        Migration::migration_1(),
+        Migration::migration_2(),
+        Migration::migration_3(),
+    ];
+    MigrationManager::apply_migrations(sqlite_db.clone(), migrations_before_era_id.clone())
+        .await
+        .expect("Error applying migrations 1 to 3");
+
+    let block_added = BlockAdded::random_in_era(&mut test_rng, 7, 70, None);
+    let insert_event_log_stmt = tables::event_log::create_insert_stmt(
+        EventTypeId::BlockAdded as u8,
+        "127.0.0.1",
+        1,
+        &block_added.hex_encoded_hash(),
+    )
+    .expect("Error creating event_log insert SQL")
+    .to_string(SqliteQueryBuilder);
+    // Mimics a write made before the era_id column existed
+    let insert_block_added_stmt = Query::insert()
+        .into_table(Alias::new("BlockAdded"))
+        .columns([
+            Alias::new("height"),
+            Alias::new("block_hash"),
+            Alias::new("raw"),
+            Alias::new("event_log_id"),
+        ])
+        .values_panic([
+            block_added.get_height().into(),
+            block_added.hex_encoded_hash().into(),
+            serde_json::to_string(&block_added).unwrap().into(),
+            1u64.into(),
+        ])
+        .to_string(SqliteQueryBuilder);
+    sqlite_db
+        .connection_pool
+        .execute(format!("{insert_event_log_stmt};{insert_block_added_stmt}").as_str())
+        .await
+        .expect("Error inserting BlockAdded");
+
+    let mut migrations = migrations_before_era_id;
+    migrations.push(Migration::migration_4());
+    let apply_res = MigrationManager::apply_migrations(sqlite_db.clone(), migrations).await;
+
+    assert!(apply_res.is_ok());
+    assert!(sqlite_db.get_newest_migration_version().await.unwrap() == Some((4, true)));
+    let block_range = sqlite_db
+        .get_era_block_range(7)
+        .await
+        .expect("Error getting block range of era");
+    assert_eq!(block_range.first_block_height, 70);
+    assert_eq!(block_range.block_count, 1);
+}
+
+#[tokio::test]
+async fn migration_5_should_backfill_validator_rewards_and_weights_of_stored_switch_blocks() {
+    let mut test_rng = TestRng::new();
+    let sqlite_db = SqliteDatabase::new_in_memory_no_migrations(MAX_CONNECTIONS)
+        .await
+        .expect("Error opening database in memory");
+    let migrations_before_validator_tables = vec![
+        Migration::migration_1(),
+        Migration::migration_2(),
+        Migration::migration_3(),
+        Migration::migration_4(),
+    ];
+    MigrationManager::apply_migrations(
+        sqlite_db.clone(),
+        migrations_before_validator_tables.clone(),
+    )
+    .await
+    .expect("Error applying migrations 1 to 4");
+
+    let validator = PublicKey::from(&SecretKey::random(&mut test_rng));
+    let era_end = JsonEraEnd::random_with_rewards_and_validator_weights(
+        &mut test_rng,
+        vec![(validator.clone(), 500)],
+        vec![(validator.clone(), U512::from(7000))],
+    );
+    let blocks = vec![
+        BlockAdded::random_in_era(&mut test_rng, 7, 69, None),
+        BlockAdded::random_in_era(&mut test_rng, 7, 70, Some(era_end)),
+    ];
+    // Mimics writes made before the validator tables existed
+    for (event_log_id, block_added) in (1u64..).zip(blocks.iter()) {
+        let insert_event_log_stmt = tables::event_log::create_insert_stmt(
+            EventTypeId::BlockAdded as u8,
+            "127.0.0.1",
+            event_log_id as u32,
+            &block_added.hex_encoded_hash(),
+        )
+        .expect("Error creating event_log insert SQL")
+        .to_string(SqliteQueryBuilder);
+        let insert_block_added_stmt = tables::block_added::create_insert_stmt(
+            block_added.get_height(),
+            block_added.hex_encoded_hash(),
+            block_added.get_era_id(),
+            serde_json::to_string(block_added).unwrap(),
+            event_log_id,
+        )
+        .expect("Error creating block_added insert SQL")
+        .to_string(SqliteQueryBuilder);
+        sqlite_db
+            .connection_pool
+            .execute(format!("{insert_event_log_stmt};{insert_block_added_stmt}").as_str())
+            .await
+            .expect("Error inserting BlockAdded");
+    }
+
+    let mut migrations = migrations_before_validator_tables;
+    migrations.push(Migration::migration_5());
+    let apply_res = MigrationManager::apply_migrations(sqlite_db.clone(), migrations).await;
+
+    assert!(apply_res.is_ok());
+    assert!(sqlite_db.get_newest_migration_version().await.unwrap() == Some((5, true)));
+    let rewards = sqlite_db
+        .get_validator_rewards(&validator.to_hex(), None, None)
+        .await
+        .expect("Error getting validator rewards");
+    assert_eq!(
+        rewards,
+        vec![ValidatorEraReward {
+            era_id: 7,
+            amount: 500,
+        }]
+    );
+    let weights = sqlite_db
+        .get_validator_weights(&validator.to_hex(), None, None)
+        .await
+        .expect("Error getting validator weights");
+    assert_eq!(
+        weights,
+        vec![ValidatorEraWeight {
+            era_id: 8,
+            weight: "7000".to_string(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn migration_6_should_backfill_execution_outcome_of_stored_deploys() {
+    let mut test_rng = TestRng::new();
+    let sqlite_db = SqliteDatabase::new_in_memory_no_migrations(MAX_CONNECTIONS)
+        .await
+        .expect("Error opening database in memory");
+    let migrations_before_execution_outcome = vec![
+        Migration::migration_1(),
+        Migration::migration_2(),
+        Migration::migration_3(),
+        Migration::migration_4(),
+        Migration::migration_5(),
+    ];
+    MigrationManager::apply_migrations(
+        sqlite_db.clone(),
+        migrations_before_execution_outcome.clone(),
+    )
+    .await
+    .expect("Error applying migrations 1 to 5");
+
+    let block_hash = BlockAdded::random(&mut test_rng).block_hash();
+    let deploy_processed =
+        DeployProcessed::random_failed(&mut test_rng, block_hash, "User error: 7");
+    let insert_event_log_stmt = tables::event_log::create_insert_stmt(
+        EventTypeId::DeployProcessed as u8,
+        "127.0.0.1",
+        1,
+        &deploy_processed.hex_encoded_hash(),
+    )
+    .expect("Error creating event_log insert SQL")
+    .to_string(SqliteQueryBuilder);
+    // Mimics a write made before the execution outcome columns existed
+    let insert_deploy_processed_stmt = Query::insert()
+        .into_table(Alias::new("DeployProcessed"))
+        .columns([
+            Alias::new("deploy_hash"),
+            Alias::new("block_hash"),
+            Alias::new("raw"),
+            Alias::new("event_log_id"),
+        ])
+        .values_panic([
+            deploy_processed.hex_encoded_hash().into(),
+            deploy_processed.hex_encoded_block_hash().into(),
+            serde_json::to_string(&deploy_processed).unwrap().into(),
+            1u64.into(),
+        ])
+        .to_string(SqliteQueryBuilder);
+    sqlite_db
+        .connection_pool
+        .execute(format!("{insert_event_log_stmt};{insert_deploy_processed_stmt}").as_str())
+        .await
+        .expect("Error inserting DeployProcessed");
+
+    let mut migrations = migrations_before_execution_outcome;
+    migrations.push(Migration::migration_6());
+    let apply_res = MigrationManager::apply_migrations(sqlite_db.clone(), migrations).await;
+
+    assert!(apply_res.is_ok());
+    assert!(sqlite_db.get_newest_migration_version().await.unwrap() == Some((6, true)));
+    let page = sqlite_db
+        .get_failed_deploys(Some("user error"), None, None, None, 10)
+        .await
+        .expect("Error getting failed deploys");
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(
+        page.items[0].deploy_hash,
+        deploy_processed.hex_encoded_hash()
+    );
+    assert_eq!(page.items[0].cost, deploy_processed.cost().to_string());
+}
+
+#[tokio::test]
+async fn migration_7_should_backfill_transfers_of_stored_deploys() {
+    let mut test_rng = TestRng::new();
+    let sqlite_db = SqliteDatabase::new_in_memory_no_migrations(MAX_CONNECTIONS)
+        .await
+        .expect("Error opening database in memory");
+    let migrations_before_transfers = vec![
+        Migration::migration_1(),
+        Migration::migration_2(),
+        Migration::migration_3(),
+        Migration::migration_4(),
+        Migration::migration_5(),
+        Migration::migration_6(),
+    ];
+    MigrationManager::apply_migrations(sqlite_db.clone(), migrations_before_transfers.clone())
+        .await
+        .expect("Error applying migrations 1 to 6");
+
+    let account = AccountHash::new(test_rng.gen());
+    let block_hash = BlockAdded::random(&mut test_rng).block_hash();
+    let mut deploys = vec![];
+    for event_log_id in 1..=2u64 {
+        let transfer = Transfer::new(
+            casper_types::DeployHash::new(test_rng.gen()),
+            account,
+            None,
+            URef::new(test_rng.gen(), AccessRights::READ_ADD_WRITE),
+            URef::new(test_rng.gen(), AccessRights::READ_ADD_WRITE),
+            U512::from(event_log_id),
+            U512::zero(),
+            Some(event_log_id),
+        );
+        let deploy_processed =
+            DeployProcessed::random_with_transfers(&mut test_rng, block_hash, vec![transfer]);
+        let insert_event_log_stmt = tables::event_log::create_insert_stmt(
+            EventTypeId::DeployProcessed as u8,
+            "127.0.0.1",
+            event_log_id as u32,
+            &deploy_processed.hex_encoded_hash(),
+        )
+        .expect("Error creating event_log insert SQL")
+        .to_string(SqliteQueryBuilder);
+        // Mimics a write made before the Transfer table existed
+        let insert_deploy_processed_stmt = Query::insert()
+            .into_table(Alias::new("DeployProcessed"))
+            .columns([
+                Alias::new("deploy_hash"),
+                Alias::new("block_hash"),
+                Alias::new("raw"),
+                Alias::new("event_log_id"),
+            ])
+            .values_panic([
+                deploy_processed.hex_encoded_hash().into(),
+                deploy_processed.hex_encoded_block_hash().into(),
+                serde_json::to_string(&deploy_processed).unwrap().into(),
+                event_log_id.into(),
+            ])
+            .to_string(SqliteQueryBuilder);
+        sqlite_db
+            .connection_pool
+            .execute(format!("{insert_event_log_stmt};{insert_deploy_processed_stmt}").as_str())
+            .await
+            .expect("Error inserting DeployProcessed");
+        deploys.push(deploy_processed);
+    }
+
+    let mut migrations = migrations_before_transfers;
+    migrations.push(Migration::migration_7());
+    let apply_res = MigrationManager::apply_migrations(sqlite_db.clone(), migrations).await;
+
+    assert!(apply_res.is_ok());
+    assert!(sqlite_db.get_newest_migration_version().await.unwrap() == Some((7, true)));
+    let page = sqlite_db
+        .get_transfers_by_account(&hex::encode(account.value()), None, None, None, 10)
+        .await
+        .expect("Error getting transfers");
+    // The backfilled transfers keep the order of their deploys, newest first.
+    assert_eq!(
+        page.items
+            .iter()
+            .map(|transfer| (transfer.deploy_hash.clone(), transfer.transfer_id))
+            .collect::<Vec<_>>(),
+        vec![
+            (deploys[1].hex_encoded_hash(), Some(2)),
+            (deploys[0].hex_encoded_hash(), Some(1)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn migration_8_should_backfill_contract_calls_of_stored_deploys() {
+    let mut test_rng = TestRng::new();
+    let sqlite_db = SqliteDatabase::new_in_memory_no_migrations(MAX_CONNECTIONS)
+        .await
+        .expect("Error opening database in memory");
+    let migrations_before_contract_calls = vec![
+        Migration::migration_1(),
+        Migration::migration_2(),
+        Migration::migration_3(),
+        Migration::migration_4(),
+        Migration::migration_5(),
+        Migration::migration_6(),
+        Migration::migration_7(),
+    ];
+    MigrationManager::apply_migrations(sqlite_db.clone(), migrations_before_contract_calls.clone())
+        .await
+        .expect("Error applying migrations 1 to 7");
+
+    let contract_hash = ContractHash::new(test_rng.gen());
+    let contract_call = DeployAccepted::random_with_session(
+        &mut test_rng,
+        ExecutableDeployItem::StoredContractByHash {
+            hash: contract_hash,
+            entry_point: "transfer".to_string(),
+            args: RuntimeArgs::new(),
+        },
+    );
+    let module_bytes = DeployAccepted::random_with_session(
+        &mut test_rng,
+        ExecutableDeployItem::ModuleBytes {
+            module_bytes: Default::default(),
+            args: RuntimeArgs::new(),
+        },
+    );
+    for (event_log_id, deploy_accepted) in (1..).zip([&contract_call, &module_bytes]) {
+        let insert_event_log_stmt = tables::event_log::create_insert_stmt(
+            EventTypeId::DeployAccepted as u8,
+            "127.0.0.1",
+            event_log_id as u32,
+            &deploy_accepted.hex_encoded_hash(),
+        )
+        .expect("Error creating event_log insert SQL")
+        .to_string(SqliteQueryBuilder);
+        // Mimics a write made before the ContractCall table existed
+        let insert_deploy_accepted_stmt = tables::deploy_accepted::create_insert_stmt(
+            deploy_accepted.hex_encoded_hash(),
+            deploy_accepted.hex_encoded_account(),
+            serde_json::to_string(deploy_accepted).unwrap(),
+            event_log_id,
+        )
+        .expect("Error creating deploy_accepted insert SQL")
+        .to_string(SqliteQueryBuilder);
+        sqlite_db
+            .connection_pool
+            .execute(format!("{insert_event_log_stmt};{insert_deploy_accepted_stmt}").as_str())
+            .await
+            .expect("Error inserting DeployAccepted");
+    }
+
+    let mut migrations = migrations_before_contract_calls;
+    migrations.push(Migration::migration_8());
+    let apply_res = MigrationManager::apply_migrations(sqlite_db.clone(), migrations).await;
+
+    assert!(apply_res.is_ok());
+    assert!(sqlite_db.get_newest_migration_version().await.unwrap() == Some((8, true)));
+    let page = sqlite_db
+        .get_deploys_by_contract(&hex::encode(contract_hash.value()), None, None, 10)
+        .await
+        .expect("Error getting deploys by contract");
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].deploy_hash, contract_call.hex_encoded_hash());
+    assert_eq!(page.items[0].entry_point, "transfer");
+    assert_eq!(page.items[0].status, DeployStatus::Accepted);
+}
+
 fn build_ok_migration(version: u32) -> Migration {
     Migration {
         version: Some(version),
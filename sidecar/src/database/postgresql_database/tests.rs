@@ -36,6 +36,18 @@ async fn should_save_and_retrieve_block_added() {
     crate::database::tests::should_save_and_retrieve_block_added(test_context.db.clone()).await;
 }
 
+#[tokio::test]
+async fn should_retrieve_blocks_by_height_range() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_blocks_by_height_range(test_context.db.clone()).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_latest_blocks() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_latest_blocks(test_context.db.clone()).await;
+}
+
 #[tokio::test]
 async fn should_save_and_retrieve_deploy_accepted() {
     let test_context = build_postgres_database().await.unwrap();
@@ -76,6 +88,85 @@ async fn should_retrieve_deploy_aggregate_of_expired() {
         .await;
 }
 
+#[tokio::test]
+async fn should_retrieve_deploy_aggregates_by_block() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_deploy_aggregates_by_block(test_context.db.clone())
+        .await;
+}
+
+#[tokio::test]
+async fn should_retrieve_deploy_aggregates_by_hashes() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_deploy_aggregates_by_hashes(test_context.db.clone())
+        .await;
+}
+
+#[tokio::test]
+async fn should_retrieve_era_block_range_and_switch_block() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_era_block_range_and_switch_block(
+        test_context.db.clone(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn should_retrieve_validator_rewards_and_weights() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_validator_rewards_and_weights(test_context.db.clone())
+        .await;
+}
+
+#[tokio::test]
+async fn should_retrieve_deploy_timeline() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_deploy_timeline(test_context.db.clone()).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_failed_deploys_by_error_message() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_failed_deploys_by_error_message(
+        test_context.db.clone(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn should_retrieve_deploy_summaries_by_account() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_deploy_summaries_by_account(test_context.db.clone())
+        .await;
+}
+
+#[tokio::test]
+async fn should_retrieve_transfers_by_account_and_deploy() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_transfers_by_account_and_deploy(
+        test_context.db.clone(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn should_retrieve_deploys_by_contract() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_deploys_by_contract(test_context.db.clone()).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_event_log() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_event_log(test_context.db.clone()).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_node_event_statistics() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_node_event_statistics(test_context.db.clone()).await;
+}
+
 #[tokio::test]
 async fn should_save_and_retrieve_fault() {
     let test_context = build_postgres_database().await.unwrap();
@@ -96,6 +187,22 @@ async fn should_save_and_retrieve_finality_signature() {
         .await;
 }
 
+#[tokio::test]
+async fn should_find_finality_signatures_by_public_key() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_find_finality_signatures_by_public_key(test_context.db.clone())
+        .await;
+}
+
+#[tokio::test]
+async fn should_retrieve_validator_signatures_and_participation() {
+    let test_context = build_postgres_database().await.unwrap();
+    crate::database::tests::should_retrieve_validator_signatures_and_participation(
+        test_context.db.clone(),
+    )
+    .await;
+}
+
 #[tokio::test]
 async fn should_save_and_retrieve_step() {
     let test_context = build_postgres_database().await.unwrap();
@@ -6,13 +6,21 @@ macro_rules! database_reader_implementation {
         use anyhow::Error;
         use async_trait::async_trait;
         use casper_event_types::FinalitySignature as FinSig;
+        use casper_types::Timestamp;
         use serde::Deserialize;
         use sqlx::{Executor, Row};
+        use std::{collections::HashMap, str::FromStr};
         use $crate::{
             database::errors::{wrap_query_error, DbError},
-            sql::tables,
+            sql::{tables, tables::event_type::EventTypeId},
             types::{
-                database::{DatabaseReadError, DatabaseReader, DeployAggregate},
+                database::{
+                    ContractDeploy, DatabaseReadError, DatabaseReader, DeployAggregate,
+                    DeployEventTiming, DeployStatus, DeploySummary, DeployTimeline, EraBlockRange,
+                    EventLogEntry, FailedDeploy, NativeTransfer, NodeEventStatistics, Page,
+                    ValidatorEraParticipation, ValidatorEraReward, ValidatorEraWeight,
+                    ValidatorSignature,
+                },
                 sse_events::*,
             },
         };
@@ -29,6 +37,46 @@ macro_rules! database_reader_implementation {
                 parse_block_from_row(row)
             }
 
+            async fn get_latest_blocks(
+                &self,
+                count: u32,
+            ) -> Result<Vec<BlockAdded>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::block_added::create_get_latest_blocks_stmt(count)
+                    .to_string($query_materializer_expr);
+
+                let blocks = db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))?
+                    .into_iter()
+                    .map(parse_block_from_row)
+                    .collect::<Result<Vec<BlockAdded>, DatabaseReadError>>()?;
+
+                if blocks.is_empty() {
+                    return Err(DatabaseReadError::NotFound);
+                }
+                Ok(blocks)
+            }
+
+            async fn get_last_block_added_event_log_id(&self) -> Result<u64, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::block_added::create_get_last_event_log_id_stmt()
+                    .to_string($query_materializer_expr);
+
+                let row = db_connection
+                    .fetch_one(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))?;
+                // The aggregate is NULL if no block is stored.
+                row.try_get::<Option<i64>, &str>("event_log_id")
+                    .map_err(|err| wrap_query_error(err.into()))?
+                    .map(|event_log_id| event_log_id as u64)
+                    .ok_or(DatabaseReadError::NotFound)
+            }
+
             async fn get_block_by_height(
                 &self,
                 height: u64,
@@ -59,6 +107,79 @@ macro_rules! database_reader_implementation {
                     })
             }
 
+            async fn get_blocks_by_height_range(
+                &self,
+                from_height: u64,
+                to_height: Option<u64>,
+                limit: u32,
+            ) -> Result<Vec<BlockAdded>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::block_added::create_get_by_height_range_stmt(
+                    from_height,
+                    to_height,
+                    limit,
+                )
+                .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| rows.into_iter().map(parse_block_from_row).collect())
+            }
+
+            async fn get_era_block_range(
+                &self,
+                era_id: u64,
+            ) -> Result<EraBlockRange, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::block_added::create_get_era_block_range_stmt(era_id)
+                    .to_string($query_materializer_expr);
+
+                let row = db_connection
+                    .fetch_one(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))?;
+                // The aggregates are NULL if no block of the era is stored.
+                let first_height = row
+                    .try_get::<Option<i64>, &str>("first_height")
+                    .map_err(|err| wrap_query_error(err.into()))?;
+                let last_height = row
+                    .try_get::<Option<i64>, &str>("last_height")
+                    .map_err(|err| wrap_query_error(err.into()))?;
+                let block_count = row
+                    .try_get::<i64, &str>("block_count")
+                    .map_err(|err| wrap_query_error(err.into()))?;
+                match (first_height, last_height) {
+                    (Some(first_height), Some(last_height)) => Ok(EraBlockRange {
+                        first_block_height: first_height as u64,
+                        last_block_height: last_height as u64,
+                        block_count: block_count as u64,
+                    }),
+                    _ => Err(DatabaseReadError::NotFound),
+                }
+            }
+
+            async fn get_switch_block_of_era(
+                &self,
+                era_id: u64,
+            ) -> Result<BlockAdded, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::block_added::create_get_last_of_era_stmt(era_id)
+                    .to_string($query_materializer_expr);
+
+                let row = fetch_optional_with_error_check(db_connection, stmt).await?;
+                let block = parse_block_from_row(row)?;
+                // Only the last block of an era carries the era end.
+                if block.era_end().is_none() {
+                    return Err(DatabaseReadError::NotFound);
+                }
+                Ok(block)
+            }
+
             async fn get_deploy_aggregate_by_hash(
                 &self,
                 hash: &str,
@@ -73,6 +194,7 @@ macro_rules! database_reader_implementation {
                         deploy_accepted: Some(deploy_accepted),
                         deploy_processed: Some(deploy_processed),
                         deploy_expired: false,
+                        timeline: None,
                     }),
                     Err(err) => {
                         // If the error is anything other than NotFound return the error.
@@ -85,6 +207,7 @@ macro_rules! database_reader_implementation {
                                 deploy_accepted: Some(deploy_accepted),
                                 deploy_processed: None,
                                 deploy_expired: true,
+                                timeline: None,
                             }),
                             Err(err) => {
                                 // If the error is anything other than NotFound return the error.
@@ -96,6 +219,7 @@ macro_rules! database_reader_implementation {
                                     deploy_accepted: Some(deploy_accepted),
                                     deploy_processed: None,
                                     deploy_expired: false,
+                                    timeline: None,
                                 })
                             }
                         }
@@ -175,6 +299,317 @@ macro_rules! database_reader_implementation {
                     })
             }
 
+            async fn get_deploy_aggregates_by_block(
+                &self,
+                block_hash: &str,
+            ) -> Result<Vec<DeployAggregate>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::deploy_processed::create_get_with_accepted_by_block_hash_stmt(
+                    block_hash.to_string(),
+                )
+                .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| {
+                        rows.into_iter()
+                            .map(parse_processed_deploy_aggregate_from_row)
+                            .collect()
+                    })
+            }
+
+            async fn get_deploy_aggregates_by_hashes(
+                &self,
+                deploy_hashes: &[String],
+            ) -> Result<Vec<DeployAggregate>, DatabaseReadError> {
+                // An empty IN list isn't valid SQL for every backend.
+                if deploy_hashes.is_empty() {
+                    return Ok(Vec::new());
+                }
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::deploy_accepted::create_get_aggregates_by_hashes_stmt(
+                    deploy_hashes.to_vec(),
+                )
+                .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| {
+                        rows.into_iter()
+                            .map(parse_deploy_aggregate_from_row)
+                            .collect()
+                    })
+            }
+
+            async fn get_deploy_timeline(
+                &self,
+                hash: &str,
+            ) -> Result<DeployTimeline, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::deploy_event::create_get_event_logs_stmt(hash.to_string())
+                    .to_string($query_materializer_expr);
+
+                let rows = db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))?;
+                if rows.is_empty() {
+                    return Err(DatabaseReadError::NotFound);
+                }
+                parse_deploy_timeline_from_rows(rows)
+            }
+
+            async fn get_deploy_summaries_by_account(
+                &self,
+                public_key: &str,
+                cursor: Option<u64>,
+                limit: u32,
+            ) -> Result<Page<DeploySummary>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                // One record more than requested is fetched to find out if there is a next page.
+                let stmt = tables::deploy_accepted::create_get_summaries_by_account_stmt(
+                    public_key.to_string(),
+                    cursor,
+                    limit + 1,
+                )
+                .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| {
+                        rows.into_iter()
+                            .map(parse_deploy_summary_from_row)
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .map(|summaries| {
+                        Page::from_overfetched(summaries, limit, |(event_log_id, _)| *event_log_id)
+                            .map(|(_, summary)| summary)
+                    })
+            }
+
+            async fn get_failed_deploys(
+                &self,
+                error_contains: Option<&str>,
+                from_height: Option<u64>,
+                to_height: Option<u64>,
+                cursor: Option<u64>,
+                limit: u32,
+            ) -> Result<Page<FailedDeploy>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                // One record more than requested is fetched to find out if there is a next page.
+                let stmt = tables::deploy_processed::create_get_failed_stmt(
+                    error_contains.map(str::to_string),
+                    from_height,
+                    to_height,
+                    cursor,
+                    limit + 1,
+                )
+                .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| {
+                        rows.into_iter()
+                            .map(parse_failed_deploy_from_row)
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .map(|failed_deploys| {
+                        Page::from_overfetched(failed_deploys, limit, |(event_log_id, _)| {
+                            *event_log_id
+                        })
+                        .map(|(_, failed_deploy)| failed_deploy)
+                    })
+            }
+
+            async fn get_transfers_by_account(
+                &self,
+                account: &str,
+                from_height: Option<u64>,
+                to_height: Option<u64>,
+                cursor: Option<u64>,
+                limit: u32,
+            ) -> Result<Page<NativeTransfer>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                // One record more than requested is fetched to find out if there is a next page.
+                let stmt = tables::transfer::create_get_by_account_stmt(
+                    account.to_string(),
+                    from_height,
+                    to_height,
+                    cursor,
+                    limit + 1,
+                )
+                .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| {
+                        rows.into_iter()
+                            .map(parse_transfer_from_row)
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .map(|transfers| {
+                        Page::from_overfetched(transfers, limit, |(id, _)| *id)
+                            .map(|(_, transfer)| transfer)
+                    })
+            }
+
+            async fn get_transfers_by_deploy(
+                &self,
+                hash: &str,
+            ) -> Result<Vec<NativeTransfer>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::transfer::create_get_by_deploy_hash_stmt(hash.to_string())
+                    .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| {
+                        rows.into_iter()
+                            .map(|row| parse_transfer_from_row(row).map(|(_, transfer)| transfer))
+                            .collect()
+                    })
+            }
+
+            async fn get_deploys_by_contract(
+                &self,
+                hash: &str,
+                entry_point: Option<&str>,
+                cursor: Option<u64>,
+                limit: u32,
+            ) -> Result<Page<ContractDeploy>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                // One record more than requested is fetched to find out if there is a next page.
+                let stmt = tables::contract_call::create_get_by_contract_hash_stmt(
+                    hash.to_string(),
+                    entry_point.map(str::to_string),
+                    cursor,
+                    limit + 1,
+                )
+                .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| {
+                        rows.into_iter()
+                            .map(parse_contract_deploy_from_row)
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .map(|deploys| {
+                        Page::from_overfetched(deploys, limit, |(event_log_id, _)| *event_log_id)
+                            .map(|(_, deploy)| deploy)
+                    })
+            }
+
+            async fn get_event_log(
+                &self,
+                event_type: Option<EventTypeId>,
+                event_source_address: Option<&str>,
+                since: Option<Timestamp>,
+                until: Option<Timestamp>,
+                cursor: Option<u64>,
+                limit: u32,
+            ) -> Result<Page<EventLogEntry>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                // One record more than requested is fetched to find out if there is a next page.
+                let stmt = tables::event_log::create_get_stmt(
+                    event_type.map(|event_type| event_type as u8),
+                    event_source_address.map(str::to_string),
+                    since,
+                    until,
+                    cursor,
+                    limit + 1,
+                )
+                .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| {
+                        rows.into_iter()
+                            .map(parse_event_log_entry_from_row)
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .map(|entries| {
+                        Page::from_overfetched(entries, limit, |entry| entry.event_log_id)
+                    })
+            }
+
+            async fn get_node_event_statistics(
+                &self,
+                event_source_address: &str,
+                max_shutdowns: u32,
+            ) -> Result<NodeEventStatistics, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::event_log::create_get_statistics_by_event_source_address_stmt(
+                    event_source_address.to_string(),
+                )
+                .to_string($query_materializer_expr);
+                let rows = db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))?;
+                let mut statistics = NodeEventStatistics::default();
+                for row in rows {
+                    let event_type = row
+                        .try_get::<String, &str>("event_type_name")
+                        .map_err(|err| wrap_query_error(err.into()))?;
+                    let event_count = row
+                        .try_get::<i64, &str>("event_count")
+                        .map_err(|err| wrap_query_error(err.into()))?;
+                    let last_inserted_timestamp = row
+                        .try_get::<String, &str>("last_inserted_timestamp")
+                        .map_err(|err| wrap_query_error(err.into()))
+                        .and_then(|timestamp| parse_timestamp(&timestamp))?;
+                    statistics
+                        .events_received
+                        .insert(event_type, event_count as u64);
+                    statistics.last_seen = statistics.last_seen.max(Some(last_inserted_timestamp));
+                }
+
+                let stmt = tables::shutdown::create_get_by_event_source_address_stmt(
+                    event_source_address.to_string(),
+                    max_shutdowns,
+                )
+                .to_string($query_materializer_expr);
+                statistics.shutdowns = db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))?
+                    .into_iter()
+                    .map(|row| {
+                        row.try_get::<String, &str>("shutdown_timestamp")
+                            .map_err(|err| wrap_query_error(err.into()))
+                            .and_then(|timestamp| parse_timestamp(&timestamp))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(statistics)
+            }
+
             async fn get_faults_by_public_key(
                 &self,
                 public_key: &str,
@@ -205,6 +640,86 @@ macro_rules! database_reader_implementation {
                     .and_then(parse_faults_from_rows)
             }
 
+            async fn get_validator_rewards(
+                &self,
+                public_key: &str,
+                from_era: Option<u64>,
+                to_era: Option<u64>,
+            ) -> Result<Vec<ValidatorEraReward>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::validator_reward::create_get_by_public_key_stmt(
+                    public_key.to_string(),
+                    from_era,
+                    to_era,
+                )
+                .to_string($query_materializer_expr);
+
+                let rows = db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))?;
+                let mut rewards = Vec::new();
+                for row in rows {
+                    let era_id = row
+                        .try_get::<i64, &str>("era_id")
+                        .map_err(|err| wrap_query_error(err.into()))?;
+                    let amount = row
+                        .try_get::<String, &str>("amount")
+                        .map_err(|err| wrap_query_error(err.into()))?
+                        .parse::<u64>()
+                        .map_err(|err| DatabaseReadError::Unhandled(Error::from(err)))?;
+                    rewards.push(ValidatorEraReward {
+                        era_id: era_id as u64,
+                        amount,
+                    });
+                }
+
+                if rewards.is_empty() {
+                    return Err(DatabaseReadError::NotFound);
+                }
+                Ok(rewards)
+            }
+
+            async fn get_validator_weights(
+                &self,
+                public_key: &str,
+                from_era: Option<u64>,
+                to_era: Option<u64>,
+            ) -> Result<Vec<ValidatorEraWeight>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::validator_weight::create_get_by_public_key_stmt(
+                    public_key.to_string(),
+                    from_era,
+                    to_era,
+                )
+                .to_string($query_materializer_expr);
+
+                let rows = db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))?;
+                let mut weights = Vec::new();
+                for row in rows {
+                    let era_id = row
+                        .try_get::<i64, &str>("era_id")
+                        .map_err(|err| wrap_query_error(err.into()))?;
+                    let weight = row
+                        .try_get::<String, &str>("weight")
+                        .map_err(|err| wrap_query_error(err.into()))?;
+                    weights.push(ValidatorEraWeight {
+                        era_id: era_id as u64,
+                        weight,
+                    });
+                }
+
+                if weights.is_empty() {
+                    return Err(DatabaseReadError::NotFound);
+                }
+                Ok(weights)
+            }
+
             async fn get_finality_signatures_by_block(
                 &self,
                 block_hash: &str,
@@ -224,6 +739,97 @@ macro_rules! database_reader_implementation {
                     .and_then(parse_finality_signatures_from_rows)
             }
 
+            async fn has_finality_signatures_by_public_key(
+                &self,
+                public_key: &str,
+            ) -> Result<bool, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt =
+                    tables::finality_signature::create_has_finality_signatures_by_public_key_stmt(
+                        public_key.to_string(),
+                    )
+                    .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_optional(stmt.as_str())
+                    .await
+                    .map(|maybe_row| maybe_row.is_some())
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+            }
+
+            async fn get_validator_signatures(
+                &self,
+                public_key: &str,
+                from_height: u64,
+                to_height: Option<u64>,
+                limit: u32,
+            ) -> Result<Vec<ValidatorSignature>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                let stmt = tables::finality_signature::create_get_by_public_key_stmt(
+                    public_key.to_string(),
+                    from_height,
+                    to_height,
+                    limit,
+                )
+                .to_string($query_materializer_expr);
+
+                db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| {
+                        rows.into_iter()
+                            .map(parse_validator_signature_from_row)
+                            .collect()
+                    })
+            }
+
+            async fn get_validator_participation(
+                &self,
+                public_key: &str,
+                from_era: Option<u64>,
+                to_era: Option<u64>,
+            ) -> Result<Vec<ValidatorEraParticipation>, DatabaseReadError> {
+                let db_connection = &self.connection_pool;
+
+                // The validator is expected to sign the blocks of the eras it had a weight in.
+                let weights = self
+                    .get_validator_weights(public_key, from_era, to_era)
+                    .await?;
+
+                let stmt = tables::block_added::create_count_per_era_stmt(from_era, to_era)
+                    .to_string($query_materializer_expr);
+                let block_counts = db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| parse_count_per_era_from_rows(rows, "block_count"))?;
+
+                let stmt = tables::finality_signature::create_count_by_public_key_per_era_stmt(
+                    public_key.to_string(),
+                    from_era,
+                    to_era,
+                )
+                .to_string($query_materializer_expr);
+                let signed_counts = db_connection
+                    .fetch_all(stmt.as_str())
+                    .await
+                    .map_err(|sql_err| DatabaseReadError::Unhandled(Error::from(sql_err)))
+                    .and_then(|rows| parse_count_per_era_from_rows(rows, "signed_blocks"))?;
+
+                Ok(weights
+                    .into_iter()
+                    .map(|weight| ValidatorEraParticipation {
+                        era_id: weight.era_id,
+                        weight: weight.weight,
+                        expected_blocks: block_counts.get(&weight.era_id).copied().unwrap_or(0),
+                        signed_blocks: signed_counts.get(&weight.era_id).copied().unwrap_or(0),
+                    })
+                    .collect())
+            }
+
             async fn get_step_by_era(&self, era: u64) -> Result<Step, DatabaseReadError> {
                 let db_connection = &self.connection_pool;
 
@@ -288,6 +894,306 @@ macro_rules! database_reader_implementation {
             deserialize_data::<BlockAdded>(&raw_data).map_err(wrap_query_error)
         }
 
+        fn parse_processed_deploy_aggregate_from_row(
+            row: $row_type,
+        ) -> Result<DeployAggregate, DatabaseReadError> {
+            let deploy_hash = row
+                .try_get::<String, &str>("deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let processed_raw = row
+                .try_get::<String, &str>("processed_raw")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let accepted_raw = row
+                .try_get::<Option<String>, &str>("accepted_raw")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let deploy_processed =
+                deserialize_data::<DeployProcessed>(&processed_raw).map_err(wrap_query_error)?;
+            let deploy_accepted = accepted_raw
+                .map(|raw| deserialize_data::<DeployAccepted>(&raw).map_err(wrap_query_error))
+                .transpose()?;
+            Ok(DeployAggregate {
+                deploy_hash,
+                deploy_accepted,
+                deploy_processed: Some(deploy_processed),
+                deploy_expired: false,
+                timeline: None,
+            })
+        }
+
+        /// Parses a row selected by the statement aggregating accepted deploys by their hashes.
+        fn parse_deploy_aggregate_from_row(
+            row: $row_type,
+        ) -> Result<DeployAggregate, DatabaseReadError> {
+            let deploy_hash = row
+                .try_get::<String, &str>("deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let accepted_raw = row
+                .try_get::<String, &str>("accepted_raw")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let processed_raw = row
+                .try_get::<Option<String>, &str>("processed_raw")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let expired_deploy_hash = row
+                .try_get::<Option<String>, &str>("expired_deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let deploy_accepted =
+                deserialize_data::<DeployAccepted>(&accepted_raw).map_err(wrap_query_error)?;
+            let deploy_processed = processed_raw
+                .map(|raw| deserialize_data::<DeployProcessed>(&raw).map_err(wrap_query_error))
+                .transpose()?;
+            // Like in get_deploy_aggregate_by_hash a processed deploy isn't reported as expired.
+            let deploy_expired = deploy_processed.is_none() && expired_deploy_hash.is_some();
+            Ok(DeployAggregate {
+                deploy_hash,
+                deploy_accepted: Some(deploy_accepted),
+                deploy_processed,
+                deploy_expired,
+                timeline: None,
+            })
+        }
+
+        /// Parses a row selected by the deploy summary statements into its `event_log_id` and the summary itself.
+        fn parse_deploy_summary_from_row(
+            row: $row_type,
+        ) -> Result<(u64, DeploySummary), DatabaseReadError> {
+            let deploy_hash = row
+                .try_get::<String, &str>("deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let event_log_id = row
+                .try_get::<i64, &str>("event_log_id")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let processed_deploy_hash = row
+                .try_get::<Option<String>, &str>("processed_deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let expired_deploy_hash = row
+                .try_get::<Option<String>, &str>("expired_deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let status = DeployStatus::from_flags(
+                processed_deploy_hash.is_some(),
+                expired_deploy_hash.is_some(),
+            );
+            Ok((
+                event_log_id as u64,
+                DeploySummary {
+                    deploy_hash,
+                    status,
+                },
+            ))
+        }
+
+        fn parse_failed_deploy_from_row(
+            row: $row_type,
+        ) -> Result<(u64, FailedDeploy), DatabaseReadError> {
+            let deploy_hash = row
+                .try_get::<String, &str>("deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let event_log_id = row
+                .try_get::<i64, &str>("event_log_id")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let block_hash = row
+                .try_get::<String, &str>("block_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let block_height = row
+                .try_get::<Option<i64>, &str>("height")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let cost = row
+                .try_get::<String, &str>("cost")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let error_message = row
+                .try_get::<String, &str>("error_message")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            Ok((
+                event_log_id as u64,
+                FailedDeploy {
+                    deploy_hash,
+                    block_hash,
+                    block_height: block_height.map(|height| height as u64),
+                    cost,
+                    error_message,
+                },
+            ))
+        }
+
+        fn parse_deploy_timeline_from_rows(
+            rows: Vec<$row_type>,
+        ) -> Result<DeployTimeline, DatabaseReadError> {
+            let mut accepted = None;
+            let mut processed = None;
+            let mut expired = None;
+            for row in rows {
+                let event_type_id = row
+                    .try_get::<i16, &str>("event_type_id")
+                    .map_err(|err| wrap_query_error(err.into()))?;
+                let slot = match event_type_id {
+                    id if id == EventTypeId::DeployAccepted as i16 => &mut accepted,
+                    id if id == EventTypeId::DeployProcessed as i16 => &mut processed,
+                    id if id == EventTypeId::DeployExpired as i16 => &mut expired,
+                    _ => continue,
+                };
+                let event_source_address = row
+                    .try_get::<String, &str>("event_source_address")
+                    .map_err(|err| wrap_query_error(err.into()))?;
+                let inserted_timestamp = row
+                    .try_get::<String, &str>("inserted_timestamp")
+                    .map_err(|err| wrap_query_error(err.into()))?;
+                let emitted_timestamp = row
+                    .try_get::<String, &str>("emitted_timestamp")
+                    .map_err(|err| wrap_query_error(err.into()))?;
+                *slot = Some(DeployEventTiming {
+                    event_source_address,
+                    inserted_timestamp: parse_timestamp(&inserted_timestamp)?,
+                    emitted_timestamp: parse_timestamp(&emitted_timestamp)?,
+                });
+            }
+            Ok(DeployTimeline::new(accepted, processed, expired))
+        }
+
+        fn parse_event_log_entry_from_row(
+            row: $row_type,
+        ) -> Result<EventLogEntry, DatabaseReadError> {
+            let event_log_id = row
+                .try_get::<i64, &str>("event_log_id")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let event_type = row
+                .try_get::<String, &str>("event_type_name")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let event_source_address = row
+                .try_get::<String, &str>("event_source_address")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let event_id = row
+                .try_get::<i64, &str>("event_id")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let event_key = row
+                .try_get::<String, &str>("event_key")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let inserted_timestamp = row
+                .try_get::<String, &str>("inserted_timestamp")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let emitted_timestamp = row
+                .try_get::<String, &str>("emitted_timestamp")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let payload = row
+                .try_get::<Option<String>, &str>("payload")
+                .map_err(|err| wrap_query_error(err.into()))?
+                .map(|raw| serde_json::from_str(&raw))
+                .transpose()
+                .map_err(DatabaseReadError::Serialisation)?;
+            Ok(EventLogEntry {
+                event_log_id: event_log_id as u64,
+                event_type,
+                event_source_address,
+                event_id: event_id as u32,
+                event_key,
+                inserted_timestamp: parse_timestamp(&inserted_timestamp)?,
+                emitted_timestamp: parse_timestamp(&emitted_timestamp)?,
+                payload,
+            })
+        }
+
+        fn parse_timestamp(value: &str) -> Result<Timestamp, DatabaseReadError> {
+            Timestamp::from_str(value).map_err(|err| {
+                DatabaseReadError::Unhandled(Error::msg(format!(
+                    "Error parsing timestamp '{}': {}",
+                    value, err
+                )))
+            })
+        }
+
+        /// Parses a row selected by the transfer statements into its `id` and the transfer itself.
+        fn parse_transfer_from_row(
+            row: $row_type,
+        ) -> Result<(u64, NativeTransfer), DatabaseReadError> {
+            let id = row
+                .try_get::<i64, &str>("id")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let deploy_hash = row
+                .try_get::<String, &str>("deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let block_hash = row
+                .try_get::<String, &str>("block_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let block_height = row
+                .try_get::<Option<i64>, &str>("height")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let from = row
+                .try_get::<String, &str>("from_account")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let to = row
+                .try_get::<Option<String>, &str>("to_account")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let amount = row
+                .try_get::<String, &str>("amount")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let transfer_id = row
+                .try_get::<Option<String>, &str>("transfer_id")
+                .map_err(|err| wrap_query_error(err.into()))?
+                .map(|transfer_id| {
+                    transfer_id.parse::<u64>().map_err(|err| {
+                        DatabaseReadError::Unhandled(Error::msg(format!(
+                            "Error parsing transfer id '{}': {}",
+                            transfer_id, err
+                        )))
+                    })
+                })
+                .transpose()?;
+            Ok((
+                id as u64,
+                NativeTransfer {
+                    deploy_hash,
+                    block_hash,
+                    block_height: block_height.map(|height| height as u64),
+                    from,
+                    to,
+                    amount,
+                    transfer_id,
+                },
+            ))
+        }
+
+        fn parse_contract_deploy_from_row(
+            row: $row_type,
+        ) -> Result<(u64, ContractDeploy), DatabaseReadError> {
+            let event_log_id = row
+                .try_get::<i64, &str>("event_log_id")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let deploy_hash = row
+                .try_get::<String, &str>("deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let contract_hash = row
+                .try_get::<Option<String>, &str>("contract_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let contract_package_hash = row
+                .try_get::<Option<String>, &str>("contract_package_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let entry_point = row
+                .try_get::<String, &str>("entry_point")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let is_success = row
+                .try_get::<Option<bool>, &str>("is_success")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let block_hash = row
+                .try_get::<Option<String>, &str>("block_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let expired_deploy_hash = row
+                .try_get::<Option<String>, &str>("expired_deploy_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            Ok((
+                event_log_id as u64,
+                ContractDeploy {
+                    deploy_hash,
+                    contract_hash,
+                    contract_package_hash,
+                    entry_point,
+                    status: DeployStatus::from_flags(
+                        block_hash.is_some(),
+                        expired_deploy_hash.is_some(),
+                    ),
+                    is_success,
+                    block_hash,
+                },
+            ))
+        }
+
         fn parse_finality_signatures_from_rows(
             rows: Vec<$row_type>,
         ) -> Result<Vec<FinSig>, DatabaseReadError> {
@@ -308,6 +1214,56 @@ macro_rules! database_reader_implementation {
             Ok(finality_signatures)
         }
 
+        fn parse_validator_signature_from_row(
+            row: $row_type,
+        ) -> Result<ValidatorSignature, DatabaseReadError> {
+            let height = row
+                .try_get::<i64, &str>("height")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let block_hash = row
+                .try_get::<String, &str>("block_hash")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            // The validator missed the block if no signature of it was joined.
+            let maybe_raw = row
+                .try_get::<Option<String>, &str>("raw")
+                .map_err(|err| wrap_query_error(err.into()))?;
+            let finality_signature = match maybe_raw {
+                Some(raw) => Some(
+                    deserialize_data::<FinalitySignature>(&raw)
+                        .map_err(wrap_query_error)?
+                        .inner(),
+                ),
+                None => None,
+            };
+            Ok(ValidatorSignature {
+                block_height: height as u64,
+                block_hash,
+                signed: finality_signature.is_some(),
+                finality_signature,
+            })
+        }
+
+        /// Parses the rows of a count grouped by era, keyed by era id. Blocks stored before their
+        /// era was recorded are grouped under a NULL era and skipped.
+        fn parse_count_per_era_from_rows(
+            rows: Vec<$row_type>,
+            count_column: &str,
+        ) -> Result<HashMap<u64, u64>, DatabaseReadError> {
+            let mut counts = HashMap::new();
+            for row in rows {
+                let era_id = row
+                    .try_get::<Option<i64>, &str>("era_id")
+                    .map_err(|err| wrap_query_error(err.into()))?;
+                let count = row
+                    .try_get::<i64, &str>(count_column)
+                    .map_err(|err| wrap_query_error(err.into()))?;
+                if let Some(era_id) = era_id {
+                    counts.insert(era_id as u64, count as u64);
+                }
+            }
+            Ok(counts)
+        }
+
         fn parse_faults_from_rows(rows: Vec<$row_type>) -> Result<Vec<Fault>, DatabaseReadError> {
             let mut faults = Vec::new();
             for row in rows {
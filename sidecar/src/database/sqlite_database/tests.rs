@@ -48,6 +48,18 @@ async fn should_save_and_retrieve_block_added() {
     crate::database::tests::should_save_and_retrieve_block_added(sqlite_db).await;
 }
 
+#[tokio::test]
+async fn should_retrieve_blocks_by_height_range() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_blocks_by_height_range(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_latest_blocks() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_latest_blocks(sqlite_db).await;
+}
+
 #[tokio::test]
 async fn should_save_and_retrieve_deploy_accepted() {
     let sqlite_db = build_database().await;
@@ -84,6 +96,72 @@ async fn should_retrieve_deploy_aggregate_of_expired() {
     crate::database::tests::should_retrieve_deploy_aggregate_of_expired(sqlite_db).await;
 }
 
+#[tokio::test]
+async fn should_retrieve_deploy_aggregates_by_block() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_deploy_aggregates_by_block(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_deploy_aggregates_by_hashes() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_deploy_aggregates_by_hashes(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_era_block_range_and_switch_block() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_era_block_range_and_switch_block(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_validator_rewards_and_weights() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_validator_rewards_and_weights(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_deploy_timeline() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_deploy_timeline(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_failed_deploys_by_error_message() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_failed_deploys_by_error_message(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_deploy_summaries_by_account() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_deploy_summaries_by_account(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_transfers_by_account_and_deploy() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_transfers_by_account_and_deploy(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_deploys_by_contract() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_deploys_by_contract(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_event_log() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_event_log(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_node_event_statistics() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_node_event_statistics(sqlite_db).await;
+}
+
 #[tokio::test]
 async fn should_save_and_retrieve_fault() {
     let sqlite_db = build_database().await;
@@ -102,6 +180,18 @@ async fn should_save_and_retrieve_finality_signature() {
     crate::database::tests::should_save_and_retrieve_finality_signature(sqlite_db).await;
 }
 
+#[tokio::test]
+async fn should_find_finality_signatures_by_public_key() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_find_finality_signatures_by_public_key(sqlite_db).await;
+}
+
+#[tokio::test]
+async fn should_retrieve_validator_signatures_and_participation() {
+    let sqlite_db = build_database().await;
+    crate::database::tests::should_retrieve_validator_signatures_and_participation(sqlite_db).await;
+}
+
 #[tokio::test]
 async fn should_save_and_retrieve_step() {
     let sqlite_db = build_database().await;
@@ -1,9 +1,21 @@
+use crate::sql::tables::event_type::EventTypeId;
 use crate::types::{
-    database::{DatabaseReader, DatabaseWriteError, DatabaseWriter},
+    database::{
+        DatabaseReadError, DatabaseReader, DatabaseWriteError, DatabaseWriter, DeployStatus,
+        EraBlockRange, FailedDeploy, NativeTransfer, ValidatorEraParticipation, ValidatorEraReward,
+        ValidatorEraWeight,
+    },
     sse_events::*,
 };
-use casper_types::{testing::TestRng, AsymmetricType, EraId};
+use casper_event_types::{block::json_compatibility::JsonEraEnd, ExecutableDeployItem};
+use casper_types::{
+    account::AccountHash, testing::TestRng, AccessRights, AsymmetricType, ContractHash,
+    ContractPackageHash, EraId, PublicKey, RuntimeArgs, SecretKey, Timestamp, Transfer, URef, U512,
+};
 use rand::Rng;
+use std::collections::BTreeMap;
+
+const DAY_IN_MILLIS: u64 = 24 * 60 * 60 * 1000;
 
 pub async fn should_save_and_retrieve_block_added<DB: DatabaseReader + DatabaseWriter>(db: DB) {
     let mut test_rng = TestRng::new();
@@ -26,6 +38,94 @@ pub async fn should_save_and_retrieve_block_added<DB: DatabaseReader + DatabaseW
         .expect("Error getting block_added by height");
 }
 
+pub async fn should_retrieve_blocks_by_height_range<DB: DatabaseReader + DatabaseWriter>(db: DB) {
+    let mut test_rng = TestRng::new();
+
+    for height in (1..=5).rev() {
+        let block_added = BlockAdded::random_with_height(&mut test_rng, height);
+        db.save_block_added(block_added, height as u32, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving block_added");
+    }
+
+    let blocks = db
+        .get_blocks_by_height_range(2, Some(4), 10)
+        .await
+        .expect("Error getting blocks by height range");
+    let heights: Vec<u64> = blocks.iter().map(BlockAdded::get_height).collect();
+    assert_eq!(heights, vec![2, 3, 4]);
+
+    let blocks = db
+        .get_blocks_by_height_range(2, None, 2)
+        .await
+        .expect("Error getting limited blocks by height range");
+    let heights: Vec<u64> = blocks.iter().map(BlockAdded::get_height).collect();
+    assert_eq!(heights, vec![2, 3]);
+
+    let blocks = db
+        .get_blocks_by_height_range(6, None, 10)
+        .await
+        .expect("Error getting blocks by height range");
+    assert!(blocks.is_empty());
+}
+
+pub async fn should_retrieve_latest_blocks<DB: DatabaseReader + DatabaseWriter>(db: DB) {
+    let mut test_rng = TestRng::new();
+
+    assert!(matches!(
+        db.get_latest_blocks(10).await,
+        Err(DatabaseReadError::NotFound)
+    ));
+    assert!(matches!(
+        db.get_last_block_added_event_log_id().await,
+        Err(DatabaseReadError::NotFound)
+    ));
+
+    for height in 1..=5 {
+        let block_added = BlockAdded::random_with_height(&mut test_rng, height);
+        db.save_block_added(block_added, height as u32, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving block_added");
+    }
+
+    let blocks = db
+        .get_latest_blocks(3)
+        .await
+        .expect("Error getting latest blocks");
+    let heights: Vec<u64> = blocks.iter().map(BlockAdded::get_height).collect();
+    assert_eq!(heights, vec![5, 4, 3]);
+
+    let last_event_log_id = db
+        .get_last_block_added_event_log_id()
+        .await
+        .expect("Error getting event log id of last block");
+    db.save_step(Step::random(&mut test_rng), 6, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving step");
+
+    assert_eq!(
+        db.get_last_block_added_event_log_id()
+            .await
+            .expect("Error getting event log id of last block"),
+        last_event_log_id
+    );
+
+    db.save_block_added(
+        BlockAdded::random_with_height(&mut test_rng, 6),
+        7,
+        "127.0.0.1".to_string(),
+    )
+    .await
+    .expect("Error saving block_added");
+
+    assert!(
+        db.get_last_block_added_event_log_id()
+            .await
+            .expect("Error getting event log id of last block")
+            > last_event_log_id
+    );
+}
+
 pub async fn should_save_and_retrieve_deploy_accepted<DB: DatabaseReader + DatabaseWriter>(db: DB) {
     let mut test_rng = TestRng::new();
 
@@ -125,6 +225,815 @@ pub async fn should_retrieve_deploy_aggregate_of_expired<DB: DatabaseReader + Da
         .expect("Error getting deploy aggregate by hash");
 }
 
+pub async fn should_retrieve_deploy_timeline<DB: DatabaseReader + DatabaseWriter>(db: DB) {
+    let mut test_rng = TestRng::new();
+    let deploy_accepted = DeployAccepted::random(&mut test_rng);
+    let deploy_processed =
+        DeployProcessed::random(&mut test_rng, Some(deploy_accepted.deploy_hash()));
+    let deploy_hash = deploy_accepted.hex_encoded_hash();
+
+    db.save_deploy_accepted(deploy_accepted, 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_accepted");
+
+    let timeline = db
+        .get_deploy_timeline(&deploy_hash)
+        .await
+        .expect("Error getting deploy timeline");
+    let accepted = timeline
+        .accepted
+        .expect("Accepted timing should be present");
+    assert_eq!(accepted.event_source_address, "127.0.0.1");
+    assert!(timeline.processed.is_none());
+    assert!(timeline.expired.is_none());
+    assert_eq!(timeline.accept_to_process_latency_ms, None);
+
+    db.save_deploy_processed(deploy_processed, 2, "127.0.0.3".to_string())
+        .await
+        .expect("Error saving deploy_processed");
+
+    let timeline = db
+        .get_deploy_timeline(&deploy_hash)
+        .await
+        .expect("Error getting deploy timeline");
+    let accepted = timeline
+        .accepted
+        .expect("Accepted timing should be present");
+    let processed = timeline
+        .processed
+        .expect("Processed timing should be present");
+    assert_eq!(processed.event_source_address, "127.0.0.3");
+    assert!(processed.inserted_timestamp >= accepted.inserted_timestamp);
+    assert_eq!(
+        timeline.accept_to_process_latency_ms,
+        Some(processed.inserted_timestamp.millis() - accepted.inserted_timestamp.millis())
+    );
+
+    let not_stored = db
+        .get_deploy_timeline(&DeployAccepted::random(&mut test_rng).hex_encoded_hash())
+        .await;
+    assert!(matches!(not_stored, Err(DatabaseReadError::NotFound)));
+}
+
+pub async fn should_retrieve_deploy_aggregates_by_block<DB: DatabaseReader + DatabaseWriter>(
+    db: DB,
+) {
+    let mut test_rng = TestRng::new();
+    let deploy_accepted = DeployAccepted::random(&mut test_rng);
+    let other_deploy_hash = DeployAccepted::random(&mut test_rng).deploy_hash();
+    let block_added = BlockAdded::random_with_deploy_hashes(
+        &mut test_rng,
+        vec![deploy_accepted.deploy_hash(), other_deploy_hash],
+    );
+    let block_hash = block_added.hex_encoded_hash();
+
+    db.save_deploy_accepted(deploy_accepted.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_accepted");
+    let deploy_processed = DeployProcessed::random_in_block(
+        &mut test_rng,
+        deploy_accepted.deploy_hash(),
+        block_added.block_hash(),
+    );
+    db.save_deploy_processed(deploy_processed, 2, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_processed");
+    db.save_deploy_processed(
+        DeployProcessed::random(&mut test_rng, None),
+        3,
+        "127.0.0.1".to_string(),
+    )
+    .await
+    .expect("Error saving deploy_processed of another block");
+
+    let aggregates = db
+        .get_deploy_aggregates_by_block(&block_hash)
+        .await
+        .expect("Error getting deploy aggregates by block");
+
+    assert_eq!(aggregates.len(), 1);
+    assert_eq!(
+        aggregates[0].deploy_hash,
+        deploy_accepted.hex_encoded_hash()
+    );
+    assert!(aggregates[0].deploy_accepted.is_some());
+    assert!(aggregates[0].deploy_processed.is_some());
+}
+
+pub async fn should_retrieve_deploy_aggregates_by_hashes<DB: DatabaseReader + DatabaseWriter>(
+    db: DB,
+) {
+    let mut test_rng = TestRng::new();
+    let processed_deploy = DeployAccepted::random(&mut test_rng);
+    let expired_deploy = DeployAccepted::random(&mut test_rng);
+    let accepted_deploy = DeployAccepted::random(&mut test_rng);
+    let unknown_deploy_hash = DeployAccepted::random(&mut test_rng).hex_encoded_hash();
+
+    for &(event_id, deploy_accepted) in [
+        (1, &processed_deploy),
+        (2, &expired_deploy),
+        (3, &accepted_deploy),
+    ]
+    .iter()
+    {
+        db.save_deploy_accepted(deploy_accepted.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving deploy_accepted");
+    }
+    db.save_deploy_processed(
+        DeployProcessed::random(&mut test_rng, Some(processed_deploy.deploy_hash())),
+        4,
+        "127.0.0.1".to_string(),
+    )
+    .await
+    .expect("Error saving deploy_processed");
+    db.save_deploy_expired(
+        DeployExpired::random(&mut test_rng, Some(expired_deploy.deploy_hash())),
+        5,
+        "127.0.0.1".to_string(),
+    )
+    .await
+    .expect("Error saving deploy_expired");
+
+    let mut aggregates = db
+        .get_deploy_aggregates_by_hashes(&[
+            processed_deploy.hex_encoded_hash(),
+            expired_deploy.hex_encoded_hash(),
+            accepted_deploy.hex_encoded_hash(),
+            unknown_deploy_hash,
+        ])
+        .await
+        .expect("Error getting deploy aggregates by hashes");
+
+    assert_eq!(aggregates.len(), 3);
+    aggregates.sort_by_key(|aggregate| {
+        if aggregate.deploy_hash == processed_deploy.hex_encoded_hash() {
+            0
+        } else if aggregate.deploy_hash == expired_deploy.hex_encoded_hash() {
+            1
+        } else {
+            2
+        }
+    });
+    assert_eq!(
+        aggregates[0].deploy_hash,
+        processed_deploy.hex_encoded_hash()
+    );
+    assert!(aggregates[0].deploy_processed.is_some());
+    assert!(!aggregates[0].deploy_expired);
+    assert_eq!(aggregates[1].deploy_hash, expired_deploy.hex_encoded_hash());
+    assert!(aggregates[1].deploy_processed.is_none());
+    assert!(aggregates[1].deploy_expired);
+    assert_eq!(
+        aggregates[2].deploy_hash,
+        accepted_deploy.hex_encoded_hash()
+    );
+    assert!(aggregates[2].deploy_accepted.is_some());
+    assert!(aggregates[2].deploy_processed.is_none());
+    assert!(!aggregates[2].deploy_expired);
+
+    let aggregates = db
+        .get_deploy_aggregates_by_hashes(&[])
+        .await
+        .expect("Error getting deploy aggregates of no hashes");
+    assert!(aggregates.is_empty());
+}
+
+pub async fn should_retrieve_era_block_range_and_switch_block<
+    DB: DatabaseReader + DatabaseWriter,
+>(
+    db: DB,
+) {
+    let mut test_rng = TestRng::new();
+    let era_end = JsonEraEnd::random_with_validator_weights(&mut test_rng, vec![]);
+    let blocks = vec![
+        BlockAdded::random_in_era(&mut test_rng, 4, 9, None),
+        BlockAdded::random_in_era(&mut test_rng, 4, 10, Some(era_end)),
+        BlockAdded::random_in_era(&mut test_rng, 5, 11, None),
+        BlockAdded::random_in_era(&mut test_rng, 5, 12, None),
+        BlockAdded::random_in_era(&mut test_rng, 5, 13, None),
+    ];
+    for (event_id, block_added) in (1..).zip(blocks.iter()) {
+        db.save_block_added(block_added.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving block_added");
+    }
+
+    let block_range = db
+        .get_era_block_range(5)
+        .await
+        .expect("Error getting block range of era");
+
+    assert_eq!(
+        block_range,
+        EraBlockRange {
+            first_block_height: 11,
+            last_block_height: 13,
+            block_count: 3,
+        }
+    );
+
+    let switch_block = db
+        .get_switch_block_of_era(4)
+        .await
+        .expect("Error getting switch block of era");
+
+    assert_eq!(
+        switch_block.hex_encoded_hash(),
+        blocks[1].hex_encoded_hash()
+    );
+    assert!(matches!(
+        db.get_switch_block_of_era(5).await,
+        Err(DatabaseReadError::NotFound)
+    ));
+    assert!(matches!(
+        db.get_era_block_range(6).await,
+        Err(DatabaseReadError::NotFound)
+    ));
+}
+
+pub async fn should_retrieve_validator_rewards_and_weights<DB: DatabaseReader + DatabaseWriter>(
+    db: DB,
+) {
+    let mut test_rng = TestRng::new();
+    let validator = PublicKey::from(&SecretKey::random(&mut test_rng));
+    let other_validator = PublicKey::from(&SecretKey::random(&mut test_rng));
+    let blocks = vec![
+        BlockAdded::random_in_era(
+            &mut test_rng,
+            4,
+            10,
+            Some(JsonEraEnd::random_with_rewards_and_validator_weights(
+                &mut test_rng,
+                vec![(validator.clone(), 100), (other_validator.clone(), 150)],
+                vec![
+                    (validator.clone(), U512::from(1000)),
+                    (other_validator.clone(), U512::from(2000)),
+                ],
+            )),
+        ),
+        BlockAdded::random_in_era(&mut test_rng, 5, 11, None),
+        BlockAdded::random_in_era(
+            &mut test_rng,
+            5,
+            12,
+            Some(JsonEraEnd::random_with_rewards_and_validator_weights(
+                &mut test_rng,
+                // Rewards beyond the range of a signed 64-bit integer are stored too.
+                vec![(validator.clone(), u64::MAX)],
+                vec![(validator.clone(), U512::from(1100))],
+            )),
+        ),
+    ];
+    for (event_id, block_added) in (1..).zip(blocks.iter()) {
+        db.save_block_added(block_added.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving block_added");
+    }
+
+    let rewards = db
+        .get_validator_rewards(&validator.to_hex(), None, None)
+        .await
+        .expect("Error getting validator rewards");
+
+    assert_eq!(
+        rewards,
+        vec![
+            ValidatorEraReward {
+                era_id: 4,
+                amount: 100,
+            },
+            ValidatorEraReward {
+                era_id: 5,
+                amount: u64::MAX,
+            },
+        ]
+    );
+
+    // Weights are announced by the switch block of the preceding era.
+    let weights = db
+        .get_validator_weights(&validator.to_hex(), Some(6), Some(6))
+        .await
+        .expect("Error getting validator weights");
+
+    assert_eq!(
+        weights,
+        vec![ValidatorEraWeight {
+            era_id: 6,
+            weight: "1100".to_string(),
+        }]
+    );
+    assert!(matches!(
+        db.get_validator_rewards(&other_validator.to_hex(), Some(5), None)
+            .await,
+        Err(DatabaseReadError::NotFound)
+    ));
+}
+
+pub async fn should_retrieve_deploy_summaries_by_account<DB: DatabaseReader + DatabaseWriter>(
+    db: DB,
+) {
+    let mut test_rng = TestRng::new();
+    let secret_key = SecretKey::random(&mut test_rng);
+    let account = PublicKey::from(&secret_key).to_hex();
+
+    let mut deploys = Vec::new();
+    for event_id in 1..=3 {
+        let deploy_accepted = DeployAccepted::random_with_secret_key(&mut test_rng, &secret_key);
+        db.save_deploy_accepted(deploy_accepted.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving deploy_accepted");
+        deploys.push(deploy_accepted);
+    }
+    db.save_deploy_accepted(
+        DeployAccepted::random(&mut test_rng),
+        4,
+        "127.0.0.1".to_string(),
+    )
+    .await
+    .expect("Error saving deploy_accepted of another account");
+    let deploy_processed = DeployProcessed::random(&mut test_rng, Some(deploys[0].deploy_hash()));
+    db.save_deploy_processed(deploy_processed, 5, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_processed");
+    let deploy_expired = DeployExpired::random(&mut test_rng, Some(deploys[1].deploy_hash()));
+    db.save_deploy_expired(deploy_expired, 6, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_expired");
+
+    let first_page = db
+        .get_deploy_summaries_by_account(&account, None, 2)
+        .await
+        .expect("Error getting deploy summaries by account");
+    let got: Vec<(String, DeployStatus)> = first_page
+        .items
+        .iter()
+        .map(|summary| (summary.deploy_hash.clone(), summary.status))
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            (deploys[2].hex_encoded_hash(), DeployStatus::Accepted),
+            (deploys[1].hex_encoded_hash(), DeployStatus::Expired),
+        ]
+    );
+    assert!(first_page.next_cursor.is_some());
+
+    let second_page = db
+        .get_deploy_summaries_by_account(&account, first_page.next_cursor, 2)
+        .await
+        .expect("Error getting second page of deploy summaries by account");
+    assert_eq!(second_page.items.len(), 1);
+    assert_eq!(
+        second_page.items[0].deploy_hash,
+        deploys[0].hex_encoded_hash()
+    );
+    assert_eq!(second_page.items[0].status, DeployStatus::Processed);
+    assert!(second_page.next_cursor.is_none());
+}
+
+pub async fn should_retrieve_failed_deploys_by_error_message<
+    DB: DatabaseReader + DatabaseWriter,
+>(
+    db: DB,
+) {
+    let mut test_rng = TestRng::new();
+    let block_added = BlockAdded::random_with_height(&mut test_rng, 10);
+    db.save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+    let not_stored_block_hash = BlockAdded::random(&mut test_rng).block_hash();
+    let deploys = vec![
+        DeployProcessed::random_failed(&mut test_rng, block_added.block_hash(), "Out of gas error"),
+        DeployProcessed::random_failed(&mut test_rng, block_added.block_hash(), "out of GAS"),
+        DeployProcessed::random_failed(
+            &mut test_rng,
+            block_added.block_hash(),
+            "Unknown entry point",
+        ),
+        DeployProcessed::random_failed(&mut test_rng, not_stored_block_hash, "Out of gas"),
+    ];
+    for (event_id, deploy_processed) in (2..).zip(deploys.iter()) {
+        db.save_deploy_processed(deploy_processed.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving deploy_processed");
+    }
+
+    let first_page = db
+        .get_failed_deploys(Some("OUT OF GAS"), None, None, None, 2)
+        .await
+        .expect("Error getting first page of failed deploys");
+
+    assert_eq!(
+        first_page
+            .items
+            .iter()
+            .map(|failed_deploy| failed_deploy.deploy_hash.clone())
+            .collect::<Vec<_>>(),
+        vec![deploys[3].hex_encoded_hash(), deploys[1].hex_encoded_hash()]
+    );
+    assert_eq!(first_page.items[0].block_height, None);
+    assert!(first_page.next_cursor.is_some());
+
+    let second_page = db
+        .get_failed_deploys(Some("OUT OF GAS"), None, None, first_page.next_cursor, 2)
+        .await
+        .expect("Error getting second page of failed deploys");
+
+    assert_eq!(
+        second_page.items,
+        vec![FailedDeploy {
+            deploy_hash: deploys[0].hex_encoded_hash(),
+            block_hash: block_added.hex_encoded_hash(),
+            block_height: Some(10),
+            cost: deploys[0].cost().to_string(),
+            error_message: "Out of gas error".to_string(),
+        }]
+    );
+    assert!(second_page.next_cursor.is_none());
+
+    let in_height_range = db
+        .get_failed_deploys(Some("gas"), Some(10), Some(10), None, 10)
+        .await
+        .expect("Error getting failed deploys in height range");
+
+    assert_eq!(in_height_range.items.len(), 2);
+    assert!(in_height_range
+        .items
+        .iter()
+        .all(|failed_deploy| failed_deploy.block_height == Some(10)));
+}
+
+pub async fn should_retrieve_transfers_by_account_and_deploy<
+    DB: DatabaseReader + DatabaseWriter,
+>(
+    db: DB,
+) {
+    let mut test_rng = TestRng::new();
+    let account = AccountHash::new(test_rng.gen());
+    let other_account = AccountHash::new(test_rng.gen());
+    let block_added = BlockAdded::random_with_height(&mut test_rng, 10);
+    db.save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+    let not_stored_block_hash = BlockAdded::random(&mut test_rng).block_hash();
+    let first_transfer = random_transfer(&mut test_rng, account, Some(other_account), Some(7));
+    let second_transfer = random_transfer(&mut test_rng, other_account, Some(account), None);
+    let to_purse_transfer = random_transfer(&mut test_rng, account, None, Some(u64::MAX));
+    let deploys = vec![
+        DeployProcessed::random_with_transfers(
+            &mut test_rng,
+            block_added.block_hash(),
+            vec![first_transfer.clone(), second_transfer],
+        ),
+        DeployProcessed::random_with_transfers(
+            &mut test_rng,
+            not_stored_block_hash,
+            vec![to_purse_transfer.clone()],
+        ),
+    ];
+    for (event_id, deploy_processed) in (2..).zip(deploys.iter()) {
+        db.save_deploy_processed(deploy_processed.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving deploy_processed");
+    }
+
+    let first_page = db
+        .get_transfers_by_account(&hex::encode(account.value()), None, None, None, 2)
+        .await
+        .expect("Error getting first page of transfers");
+
+    assert_eq!(
+        first_page.items[0],
+        NativeTransfer {
+            deploy_hash: deploys[1].hex_encoded_hash(),
+            block_hash: hex::encode(not_stored_block_hash.inner()),
+            block_height: None,
+            from: hex::encode(account.value()),
+            to: None,
+            amount: to_purse_transfer.amount.to_string(),
+            transfer_id: Some(u64::MAX),
+        }
+    );
+    assert_eq!(first_page.items.len(), 2);
+    assert!(first_page.next_cursor.is_some());
+
+    let second_page = db
+        .get_transfers_by_account(
+            &hex::encode(account.value()),
+            None,
+            None,
+            first_page.next_cursor,
+            2,
+        )
+        .await
+        .expect("Error getting second page of transfers");
+
+    assert_eq!(second_page.items.len(), 1);
+    assert!(second_page.next_cursor.is_none());
+
+    let in_height_range = db
+        .get_transfers_by_account(&hex::encode(account.value()), Some(10), Some(10), None, 10)
+        .await
+        .expect("Error getting transfers in height range");
+
+    assert_eq!(in_height_range.items.len(), 2);
+    assert!(in_height_range
+        .items
+        .iter()
+        .all(|transfer| transfer.block_height == Some(10)));
+
+    let deploy_transfers = db
+        .get_transfers_by_deploy(&deploys[0].hex_encoded_hash())
+        .await
+        .expect("Error getting transfers of deploy");
+
+    assert_eq!(
+        deploy_transfers
+            .iter()
+            .map(|transfer| transfer.from.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            hex::encode(account.value()),
+            hex::encode(other_account.value())
+        ]
+    );
+    assert_eq!(deploy_transfers[0].transfer_id, first_transfer.id);
+    assert_eq!(
+        deploy_transfers[0].to,
+        Some(hex::encode(other_account.value()))
+    );
+}
+
+fn random_transfer(
+    rng: &mut TestRng,
+    from: AccountHash,
+    to: Option<AccountHash>,
+    id: Option<u64>,
+) -> Transfer {
+    Transfer::new(
+        casper_types::DeployHash::new(rng.gen()),
+        from,
+        to,
+        URef::new(rng.gen(), AccessRights::READ_ADD_WRITE),
+        URef::new(rng.gen(), AccessRights::READ_ADD_WRITE),
+        U512::from(rng.gen::<u64>()),
+        U512::zero(),
+        id,
+    )
+}
+
+pub async fn should_retrieve_deploys_by_contract<DB: DatabaseReader + DatabaseWriter>(db: DB) {
+    let mut test_rng = TestRng::new();
+    let contract_hash = ContractHash::new(test_rng.gen());
+    let contract_package_hash = ContractPackageHash::new(test_rng.gen());
+    let transfer_call = DeployAccepted::random_with_session(
+        &mut test_rng,
+        ExecutableDeployItem::StoredContractByHash {
+            hash: contract_hash,
+            entry_point: "transfer".to_string(),
+            args: RuntimeArgs::new(),
+        },
+    );
+    let package_call = DeployAccepted::random_with_session(
+        &mut test_rng,
+        ExecutableDeployItem::StoredVersionedContractByHash {
+            hash: contract_package_hash,
+            version: None,
+            entry_point: "mint".to_string(),
+            args: RuntimeArgs::new(),
+        },
+    );
+    let approve_call = DeployAccepted::random_with_session(
+        &mut test_rng,
+        ExecutableDeployItem::StoredContractByHash {
+            hash: contract_hash,
+            entry_point: "approve".to_string(),
+            args: RuntimeArgs::new(),
+        },
+    );
+    for (event_id, deploy_accepted) in (1..).zip([&transfer_call, &package_call, &approve_call]) {
+        db.save_deploy_accepted(deploy_accepted.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving deploy_accepted");
+    }
+    let block_hash = BlockAdded::random(&mut test_rng).block_hash();
+    db.save_deploy_processed(
+        DeployProcessed::random_in_block(&mut test_rng, transfer_call.deploy_hash(), block_hash),
+        4,
+        "127.0.0.1".to_string(),
+    )
+    .await
+    .expect("Error saving deploy_processed");
+    db.save_deploy_expired(
+        DeployExpired::random(&mut test_rng, Some(approve_call.deploy_hash())),
+        5,
+        "127.0.0.1".to_string(),
+    )
+    .await
+    .expect("Error saving deploy_expired");
+
+    let first_page = db
+        .get_deploys_by_contract(&hex::encode(contract_hash.value()), None, None, 1)
+        .await
+        .expect("Error getting first page of deploys by contract");
+
+    assert_eq!(first_page.items.len(), 1);
+    assert_eq!(
+        first_page.items[0].deploy_hash,
+        approve_call.hex_encoded_hash()
+    );
+    assert_eq!(first_page.items[0].status, DeployStatus::Expired);
+    assert!(first_page.next_cursor.is_some());
+
+    let second_page = db
+        .get_deploys_by_contract(
+            &hex::encode(contract_hash.value()),
+            None,
+            first_page.next_cursor,
+            1,
+        )
+        .await
+        .expect("Error getting second page of deploys by contract");
+
+    assert_eq!(second_page.items.len(), 1);
+    assert_eq!(
+        second_page.items[0].deploy_hash,
+        transfer_call.hex_encoded_hash()
+    );
+    assert_eq!(second_page.items[0].status, DeployStatus::Processed);
+    assert!(second_page.items[0].is_success.is_some());
+    assert_eq!(
+        second_page.items[0].block_hash,
+        Some(hex::encode(block_hash.inner()))
+    );
+    assert!(second_page.next_cursor.is_none());
+
+    let by_entry_point = db
+        .get_deploys_by_contract(
+            &hex::encode(contract_hash.value()),
+            Some("transfer"),
+            None,
+            10,
+        )
+        .await
+        .expect("Error getting deploys by contract and entry point");
+
+    assert_eq!(by_entry_point.items.len(), 1);
+    assert_eq!(
+        by_entry_point.items[0].deploy_hash,
+        transfer_call.hex_encoded_hash()
+    );
+
+    let by_package = db
+        .get_deploys_by_contract(&hex::encode(contract_package_hash.value()), None, None, 10)
+        .await
+        .expect("Error getting deploys by contract package");
+
+    assert_eq!(by_package.items.len(), 1);
+    assert_eq!(
+        by_package.items[0].contract_package_hash,
+        Some(hex::encode(contract_package_hash.value()))
+    );
+    assert_eq!(by_package.items[0].contract_hash, None);
+    assert_eq!(by_package.items[0].entry_point, "mint");
+    assert_eq!(by_package.items[0].status, DeployStatus::Accepted);
+    assert_eq!(by_package.items[0].is_success, None);
+}
+
+pub async fn should_retrieve_event_log<DB: DatabaseReader + DatabaseWriter>(db: DB) {
+    let mut test_rng = TestRng::new();
+    let block_added = BlockAdded::random(&mut test_rng);
+    let deploy_accepted = DeployAccepted::random(&mut test_rng);
+    db.save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+    db.save_deploy_accepted(deploy_accepted.clone(), 1, "127.0.0.2".to_string())
+        .await
+        .expect("Error saving deploy_accepted");
+    db.save_shutdown(2, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving shutdown");
+
+    let first_page = db
+        .get_event_log(None, Some("127.0.0.1"), None, None, None, 1)
+        .await
+        .expect("Error getting first page of event log");
+
+    assert_eq!(first_page.items.len(), 1);
+    assert_eq!(first_page.items[0].event_type, "BlockAdded");
+    assert_eq!(first_page.items[0].event_id, 1);
+    assert_eq!(
+        first_page.items[0].event_key,
+        block_added.hex_encoded_hash()
+    );
+    assert_eq!(
+        first_page.items[0].payload,
+        Some(serde_json::to_value(&block_added).unwrap())
+    );
+    assert!(first_page.next_cursor.is_some());
+
+    let second_page = db
+        .get_event_log(
+            None,
+            Some("127.0.0.1"),
+            None,
+            None,
+            first_page.next_cursor,
+            1,
+        )
+        .await
+        .expect("Error getting second page of event log");
+
+    assert_eq!(second_page.items.len(), 1);
+    assert_eq!(second_page.items[0].event_type, "Shutdown");
+    assert_eq!(second_page.items[0].payload, None);
+    assert!(second_page.next_cursor.is_none());
+
+    let accepted_page = db
+        .get_event_log(
+            Some(EventTypeId::DeployAccepted),
+            None,
+            None,
+            None,
+            None,
+            10,
+        )
+        .await
+        .expect("Error getting event log by type");
+
+    assert_eq!(accepted_page.items.len(), 1);
+    assert_eq!(accepted_page.items[0].event_source_address, "127.0.0.2");
+    assert_eq!(
+        accepted_page.items[0].payload,
+        Some(serde_json::to_value(&deploy_accepted).unwrap())
+    );
+
+    // The bounds are a day away from now, so that they hold whatever the clock of the database.
+    let yesterday = Timestamp::from(Timestamp::now().millis() - DAY_IN_MILLIS);
+    let tomorrow = Timestamp::from(Timestamp::now().millis() + DAY_IN_MILLIS);
+    let page_in_range = db
+        .get_event_log(None, None, Some(yesterday), Some(tomorrow), None, 10)
+        .await
+        .expect("Error getting event log within time range");
+    let page_after_range = db
+        .get_event_log(None, None, Some(tomorrow), None, None, 10)
+        .await
+        .expect("Error getting event log after time range");
+
+    assert_eq!(page_in_range.items.len(), 3);
+    assert!(page_after_range.items.is_empty());
+}
+
+pub async fn should_retrieve_node_event_statistics<DB: DatabaseReader + DatabaseWriter>(db: DB) {
+    let mut test_rng = TestRng::new();
+    db.save_block_added(
+        BlockAdded::random(&mut test_rng),
+        1,
+        "127.0.0.1".to_string(),
+    )
+    .await
+    .expect("Error saving block_added");
+    db.save_block_added(
+        BlockAdded::random(&mut test_rng),
+        2,
+        "127.0.0.1".to_string(),
+    )
+    .await
+    .expect("Error saving block_added");
+    db.save_deploy_accepted(
+        DeployAccepted::random(&mut test_rng),
+        3,
+        "127.0.0.2".to_string(),
+    )
+    .await
+    .expect("Error saving deploy_accepted");
+    db.save_shutdown(4, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving shutdown");
+
+    let statistics = db
+        .get_node_event_statistics("127.0.0.1", 10)
+        .await
+        .expect("Error getting node event statistics");
+
+    assert_eq!(
+        statistics.events_received,
+        BTreeMap::from([("BlockAdded".to_string(), 2), ("Shutdown".to_string(), 1)])
+    );
+    assert!(statistics.last_seen.is_some());
+    assert_eq!(statistics.shutdowns.len(), 1);
+
+    let unknown_node_statistics = db
+        .get_node_event_statistics("127.0.0.3", 10)
+        .await
+        .expect("Error getting node event statistics of unknown node");
+
+    assert!(unknown_node_statistics.events_received.is_empty());
+    assert!(unknown_node_statistics.last_seen.is_none());
+    assert!(unknown_node_statistics.shutdowns.is_empty());
+}
+
 pub async fn should_save_and_retrieve_fault<DB: DatabaseReader + DatabaseWriter>(db: DB) {
     let mut test_rng = TestRng::new();
     let fault = Fault::random(&mut test_rng);
@@ -183,6 +1092,119 @@ pub async fn should_save_and_retrieve_finality_signature<DB: DatabaseReader + Da
         .expect("Error getting finality signatures by block_hash");
 }
 
+pub async fn should_find_finality_signatures_by_public_key<DB: DatabaseReader + DatabaseWriter>(
+    db: DB,
+) {
+    let mut test_rng = TestRng::new();
+    let finality_signature = FinalitySignature::random(&mut test_rng);
+    let other_public_key = PublicKey::random(&mut test_rng);
+
+    db.save_finality_signature(finality_signature.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving finality_signature");
+
+    assert!(db
+        .has_finality_signatures_by_public_key(&finality_signature.hex_encoded_public_key())
+        .await
+        .expect("Error looking up finality signatures by public key"));
+    assert!(!db
+        .has_finality_signatures_by_public_key(&other_public_key.to_hex())
+        .await
+        .expect("Error looking up finality signatures by public key"));
+}
+
+pub async fn should_retrieve_validator_signatures_and_participation<
+    DB: DatabaseReader + DatabaseWriter,
+>(
+    db: DB,
+) {
+    let mut test_rng = TestRng::new();
+    let secret_key = SecretKey::random(&mut test_rng);
+    let validator = PublicKey::from(&secret_key);
+    let other_secret_key = SecretKey::random(&mut test_rng);
+    let era_end = JsonEraEnd::random_with_validator_weights(
+        &mut test_rng,
+        vec![(validator.clone(), U512::from(1000))],
+    );
+    let blocks = vec![
+        BlockAdded::random_in_era(&mut test_rng, 4, 9, Some(era_end)),
+        BlockAdded::random_in_era(&mut test_rng, 5, 10, None),
+        BlockAdded::random_in_era(&mut test_rng, 5, 11, None),
+        BlockAdded::random_in_era(&mut test_rng, 5, 12, None),
+    ];
+    for (event_id, block_added) in (1..).zip(blocks.iter()) {
+        db.save_block_added(block_added.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving block_added");
+    }
+    let signatures = vec![
+        FinalitySignature::signed_by(blocks[1].block_hash(), 5, &secret_key),
+        FinalitySignature::signed_by(blocks[2].block_hash(), 5, &other_secret_key),
+        FinalitySignature::signed_by(blocks[3].block_hash(), 5, &secret_key),
+    ];
+    for (event_id, finality_signature) in (10..).zip(signatures.iter()) {
+        db.save_finality_signature(
+            finality_signature.clone(),
+            event_id,
+            "127.0.0.1".to_string(),
+        )
+        .await
+        .expect("Error saving finality_signature");
+    }
+
+    let validator_signatures = db
+        .get_validator_signatures(&validator.to_hex(), 0, None, 10)
+        .await
+        .expect("Error getting validator signatures");
+
+    // The block of era 4 isn't listed, as the validator had no weight in it.
+    assert_eq!(
+        validator_signatures
+            .iter()
+            .map(|signature| (signature.block_height, signature.signed))
+            .collect::<Vec<_>>(),
+        vec![(10, true), (11, false), (12, true)]
+    );
+    assert_eq!(
+        validator_signatures[1].block_hash,
+        blocks[2].hex_encoded_hash()
+    );
+    assert_eq!(validator_signatures[1].finality_signature, None);
+    assert_eq!(
+        validator_signatures[2].finality_signature,
+        Some(signatures[2].inner())
+    );
+
+    let limited_signatures = db
+        .get_validator_signatures(&validator.to_hex(), 11, Some(12), 1)
+        .await
+        .expect("Error getting validator signatures");
+
+    assert_eq!(limited_signatures.len(), 1);
+    assert_eq!(limited_signatures[0].block_height, 11);
+    assert!(!limited_signatures[0].signed);
+
+    let participation = db
+        .get_validator_participation(&validator.to_hex(), None, None)
+        .await
+        .expect("Error getting validator participation");
+
+    assert_eq!(
+        participation,
+        vec![ValidatorEraParticipation {
+            era_id: 5,
+            weight: "1000".to_string(),
+            expected_blocks: 3,
+            signed_blocks: 2,
+        }]
+    );
+    assert!(matches!(
+        db.get_validator_participation(&PublicKey::from(&other_secret_key).to_hex(), None, None)
+            .await,
+        Err(DatabaseReadError::NotFound)
+    ));
+}
+
 pub async fn should_save_and_retrieve_step<DB: DatabaseReader + DatabaseWriter>(db: DB) {
     let mut test_rng = TestRng::new();
     let step = Step::random(&mut test_rng);
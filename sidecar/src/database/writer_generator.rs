@@ -16,7 +16,9 @@ use $crate::{
     sql::{tables, tables::event_type::EventTypeId},
     types::{
         database::{
-            DatabaseWriteError, DatabaseWriter, Migration, StatementWrapper, TransactionWrapper,
+            create_contract_call_insert_stmt, create_transfer_insert_stmt,
+            create_validator_era_insert_stmts, execution_outcome_of, DatabaseWriteError,
+            DatabaseWriter, Migration, StatementWrapper, TransactionWrapper,
         },
         sse_events::*,
     },
@@ -43,6 +45,27 @@ impl TransactionWrapper for TransactionWrapperType<'_> {
             .map(|_| ())
             .map_err(DatabaseWriteError::from)
     }
+
+    async fn execute_statement(&self, statement: StatementWrapper) -> Result<(), DatabaseWriteError> {
+        self.execute(materialize_statement(&statement).as_str()).await
+    }
+
+    async fn fetch_text_rows(
+        &self,
+        query: sea_query::SelectStatement,
+    ) -> Result<Vec<Vec<String>>, DatabaseWriteError> {
+        let sql = query.to_string($query_materializer_expr);
+        let mut lock = self.transaction_mutex.lock().await;
+        let rows = lock.fetch_all(sql.as_str()).await?;
+        rows.iter()
+            .map(|row| {
+                (0..row.len())
+                    .map(|index| row.try_get::<String, usize>(index))
+                    .collect::<Result<Vec<String>, sqlx::Error>>()
+            })
+            .collect::<Result<Vec<Vec<String>>, sqlx::Error>>()
+            .map_err(DatabaseWriteError::from)
+    }
 }
 
 #[async_trait]
@@ -67,15 +90,20 @@ impl DatabaseWriter for $extended_type {
             )
             .await?;
 
-        let insert_stmt = tables::block_added::create_insert_stmt(
+        let mut insert_stmts = vec![tables::block_added::create_insert_stmt(
             block_added.get_height(),
             encoded_hash,
+            block_added.get_era_id(),
             json,
             event_log_id,
-        )?
-        .to_string($query_materializer_expr);
+        )?];
+        insert_stmts.extend(create_validator_era_insert_stmts(&block_added)?);
+        let batched_insert_stmts = insert_stmts
+            .iter()
+            .map(|stmt| stmt.to_string($query_materializer_expr))
+            .join(";");
 
-        let res = handle_result(transaction.execute(insert_stmt.as_str()).await);
+        let res = handle_result(transaction.execute(batched_insert_stmts.as_str()).await);
         if res.is_ok() {
             transaction.commit().await?;
         }
@@ -105,13 +133,23 @@ impl DatabaseWriter for $extended_type {
             )
             .await?;
 
-        let batched_insert_stmts = vec![
-            tables::deploy_accepted::create_insert_stmt(encoded_hash.clone(), json, event_log_id)?,
+        let mut insert_stmts = vec![
+            tables::deploy_accepted::create_insert_stmt(
+                encoded_hash.clone(),
+                deploy_accepted.hex_encoded_account(),
+                json,
+                event_log_id,
+            )?,
             tables::deploy_event::create_insert_stmt(event_log_id, encoded_hash)?,
-        ]
-        .iter()
-        .map(|stmt| stmt.to_string($query_materializer_expr))
-        .join(";");
+        ];
+        insert_stmts.extend(create_contract_call_insert_stmt(
+            &deploy_accepted,
+            event_log_id,
+        )?);
+        let batched_insert_stmts = insert_stmts
+            .iter()
+            .map(|stmt| stmt.to_string($query_materializer_expr))
+            .join(";");
 
         let res = handle_result(transaction.execute(batched_insert_stmts.as_str()).await);
         if res.is_ok() {
@@ -142,13 +180,21 @@ impl DatabaseWriter for $extended_type {
             )
             .await?;
 
-        let batched_insert_stmts = vec![
-            tables::deploy_processed::create_insert_stmt(encoded_hash.clone(), json, event_log_id)?,
+        let mut insert_stmts = vec![
+            tables::deploy_processed::create_insert_stmt(
+                encoded_hash.clone(),
+                deploy_processed.hex_encoded_block_hash(),
+                execution_outcome_of(&deploy_processed),
+                json,
+                event_log_id,
+            )?,
             tables::deploy_event::create_insert_stmt(event_log_id, encoded_hash)?,
-        ]
-        .iter()
-        .map(|stmt| stmt.to_string($query_materializer_expr))
-        .join(";");
+        ];
+        insert_stmts.extend(create_transfer_insert_stmt(&deploy_processed)?);
+        let batched_insert_stmts = insert_stmts
+            .iter()
+            .map(|stmt| stmt.to_string($query_materializer_expr))
+            .join(";");
 
         let res = handle_result(transaction.execute(batched_insert_stmts.as_str()).await);
         if res.is_ok() {
@@ -374,16 +420,24 @@ impl DatabaseWriter for $extended_type {
 }
 
 fn materialize_statements(wrappers: Vec<StatementWrapper>) -> Vec<String> {
-    wrappers
-        .iter()
-        .map(|wrapper| match wrapper {
-            StatementWrapper::TableCreateStatement(statement) => {
-                statement.to_string($query_materializer_expr)
-            }
-            StatementWrapper::InsertStatement(statement) => statement.to_string($query_materializer_expr),
-            StatementWrapper::Raw(sql) => sql.to_string(),
-        })
-        .collect()
+    wrappers.iter().map(materialize_statement).collect()
+}
+
+fn materialize_statement(wrapper: &StatementWrapper) -> String {
+    match wrapper {
+        StatementWrapper::TableCreateStatement(statement) => {
+            statement.to_string($query_materializer_expr)
+        }
+        StatementWrapper::TableAlterStatement(statement) => {
+            statement.to_string($query_materializer_expr)
+        }
+        StatementWrapper::IndexCreateStatement(statement) => {
+            statement.to_string($query_materializer_expr)
+        }
+        StatementWrapper::InsertStatement(statement) => statement.to_string($query_materializer_expr),
+        StatementWrapper::UpdateStatement(statement) => statement.to_string($query_materializer_expr),
+        StatementWrapper::Raw(sql) => sql.to_string(),
+    }
 }
 
 fn handle_result(
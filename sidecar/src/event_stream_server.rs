@@ -1,7 +1,8 @@
 //! Event stream server
 //!
 //! The event stream server provides clients with an event-stream returning Server-Sent Events
-//! (SSEs) holding JSON-encoded data.
+//! (SSEs) holding JSON-encoded data.  The same events can be streamed over a WebSocket by upgrading
+//! the request to any of the event stream paths.
 //!
 //! The actual server is run in backgrounded tasks.
 //!
@@ -20,6 +21,7 @@
 //! For details about the SSE model and a list of supported SSEs, see:
 //! <https://github.com/CasperLabs/ceps/blob/master/text/0009-client-api.md#rpcs>
 
+mod auth;
 mod config;
 mod endpoint;
 mod event_indexer;
@@ -27,7 +29,13 @@ mod http_server;
 mod sse_server;
 #[cfg(test)]
 mod tests;
-use crate::utils::{resolve_address, ListeningError};
+mod ws_server;
+use crate::{
+    api_key::API_KEY_HEADER,
+    connections::{self, ListeningOn},
+    utils::{resolve_address, ListeningError},
+};
+use auth::SubscriptionAuthenticator;
 use casper_event_types::{sse_data::SseData, Filter as SseFilter};
 pub use config::Config;
 use event_indexer::{EventIndex, EventIndexer};
@@ -64,7 +72,7 @@ pub(crate) struct EventStreamServer {
     event_indexer: EventIndexer,
     // This is linted as unused because in this implementation it is only printed to the output.
     #[allow(unused)]
-    listening_address: SocketAddr,
+    listening_on: ListeningOn,
 }
 
 impl EventStreamServer {
@@ -77,22 +85,28 @@ impl EventStreamServer {
         let ChannelsAndFilter {
             event_broadcaster,
             new_subscriber_info_receiver,
-            sse_filter,
+            filter,
         } = ChannelsAndFilter::new(
             get_broadcast_channel_size(&config),
             config.max_concurrent_subscribers,
+            SubscriptionAuthenticator::new(&config.api_keys),
         );
         let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
-        let (listening_address, server_with_shutdown) =
-            warp::serve(sse_filter.with(warp::cors().allow_any_origin()))
-                .try_bind_with_graceful_shutdown(required_address, async {
-                    shutdown_receiver.await.ok();
-                })
-                .map_err(|error| ListeningError::Listen {
-                    address: required_address,
-                    error: Box::new(error),
-                })?;
-        info!(address=%listening_address, "started event stream server");
+        let shutdown_signal = async {
+            shutdown_receiver.await.ok();
+        };
+        let (listening_on, connections) = connections::listen(
+            required_address,
+            config.unix_socket_path.as_deref(),
+            config.tls.as_ref(),
+        )?;
+        let cors = warp::cors()
+            .allow_any_origin()
+            .allow_method("GET")
+            .allow_header(API_KEY_HEADER);
+        let server_with_shutdown = warp::serve(filter.with(cors))
+            .serve_incoming_with_graceful_shutdown(connections, shutdown_signal);
+        info!(address=%listening_on, "started event stream server");
 
         tokio::spawn(http_server::run(
             config,
@@ -105,7 +119,7 @@ impl EventStreamServer {
         Ok(EventStreamServer {
             sse_data_sender,
             event_indexer,
-            listening_address,
+            listening_on,
         })
     }
 
@@ -0,0 +1,164 @@
+use crate::{
+    api_key::{find_api_key, ApiKeyRejection},
+    types::config::EventStreamApiKey,
+};
+use futures::{Stream, StreamExt};
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+use tracing::debug;
+
+/// Name of the server in the rejection metric.
+const SERVER_NAME: &str = "event_stream";
+
+/// Checks the API keys of new subscribers and enforces the number of concurrent subscriptions of
+/// each key.
+pub(super) struct SubscriptionAuthenticator {
+    keys: Vec<(String, KeySubscriptions)>,
+}
+
+struct KeySubscriptions {
+    name: String,
+    max_subscribers: Option<u32>,
+    /// Number of open subscriptions made with the key.
+    count: Arc<AtomicU32>,
+}
+
+/// Held by a subscription for as long as it is open, so that it counts towards the subscriptions
+/// of its key.
+pub(super) struct SubscriptionPermit {
+    maybe_count: Option<Arc<AtomicU32>>,
+}
+
+impl SubscriptionAuthenticator {
+    /// Builds an authenticator accepting the given keys, `None` if there are no keys, in which case
+    /// subscribers don't need to be authenticated.
+    pub(super) fn new(api_keys: &[EventStreamApiKey]) -> Option<Arc<Self>> {
+        if api_keys.is_empty() {
+            return None;
+        }
+        let keys = api_keys
+            .iter()
+            .map(|api_key| {
+                let subscriptions = KeySubscriptions {
+                    name: api_key.name.clone(),
+                    max_subscribers: api_key.max_subscribers,
+                    count: Arc::new(AtomicU32::new(0)),
+                };
+                (api_key.key.clone(), subscriptions)
+            })
+            .collect();
+        Some(Arc::new(SubscriptionAuthenticator { keys }))
+    }
+
+    /// Returns the permit of a new subscription made with the given key, or the reason for
+    /// rejecting it.
+    pub(super) fn authorize(
+        &self,
+        maybe_api_key: Option<String>,
+    ) -> Result<SubscriptionPermit, ApiKeyRejection> {
+        let rejection = match maybe_api_key.as_ref() {
+            None => ApiKeyRejection::Missing,
+            Some(api_key) => match find_api_key(&self.keys, api_key) {
+                None => ApiKeyRejection::Unknown,
+                Some(subscriptions) => {
+                    let max_subscribers = subscriptions.max_subscribers.unwrap_or(u32::MAX);
+                    let acquired = subscriptions.count.fetch_update(
+                        Ordering::SeqCst,
+                        Ordering::SeqCst,
+                        |count| (count < max_subscribers).then(|| count + 1),
+                    );
+                    if acquired.is_ok() {
+                        return Ok(SubscriptionPermit {
+                            maybe_count: Some(subscriptions.count.clone()),
+                        });
+                    }
+                    ApiKeyRejection::TooManySubscriptions
+                }
+            },
+        };
+        let key_name = maybe_api_key
+            .and_then(|api_key| find_api_key(&self.keys, &api_key))
+            .map_or("", |subscriptions| subscriptions.name.as_str());
+        debug!(?rejection, %key_name, "rejecting event stream subscriber");
+        rejection.record(SERVER_NAME, key_name);
+        Err(rejection)
+    }
+}
+
+impl SubscriptionPermit {
+    /// Permit of a subscription which doesn't need to be authenticated.
+    pub(super) fn unrestricted() -> Self {
+        SubscriptionPermit { maybe_count: None }
+    }
+
+    /// Ties the permit to `stream`, so that it's released once the stream is dropped.
+    pub(super) fn attach_to<S: Stream>(self, stream: S) -> impl Stream<Item = S::Item> {
+        let permit = self;
+        stream.map(move |item| {
+            // Mentioning the permit moves it into the closure, which lives as long as the stream.
+            let _ = &permit;
+            item
+        })
+    }
+}
+
+impl Drop for SubscriptionPermit {
+    fn drop(&mut self) {
+        if let Some(count) = &self.maybe_count {
+            count.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_authenticator(max_subscribers: Option<u32>) -> Arc<SubscriptionAuthenticator> {
+        SubscriptionAuthenticator::new(&[EventStreamApiKey {
+            name: "client".to_string(),
+            key: "secret".to_string(),
+            max_subscribers,
+        }])
+        .expect("Authenticator should be built for a non-empty list of keys")
+    }
+
+    #[test]
+    fn should_not_build_authenticator_without_keys() {
+        assert!(SubscriptionAuthenticator::new(&[]).is_none());
+    }
+
+    #[test]
+    fn should_reject_missing_and_unknown_keys() {
+        let authenticator = build_authenticator(None);
+
+        assert_eq!(
+            authenticator.authorize(None).err(),
+            Some(ApiKeyRejection::Missing)
+        );
+        assert_eq!(
+            authenticator.authorize(Some("other".to_string())).err(),
+            Some(ApiKeyRejection::Unknown)
+        );
+    }
+
+    #[test]
+    fn should_limit_subscriptions_of_key_until_permit_is_dropped() {
+        let authenticator = build_authenticator(Some(1));
+
+        let permit = authenticator
+            .authorize(Some("secret".to_string()))
+            .expect("First subscription should be authorized");
+
+        assert_eq!(
+            authenticator.authorize(Some("secret".to_string())).err(),
+            Some(ApiKeyRejection::TooManySubscriptions)
+        );
+
+        drop(permit);
+
+        assert!(authenticator.authorize(Some("secret".to_string())).is_ok());
+    }
+}
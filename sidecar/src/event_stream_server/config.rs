@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::config::{EventStreamApiKey, TlsConfig};
+
 /// Default binding address for the SSE HTTP server.
 ///
 /// Uses a fixed port per node, but binds on any interface.
@@ -19,11 +21,23 @@ pub struct Config {
     /// Address to bind event stream SSE HTTP server to.
     pub address: String,
 
+    /// If set, the server listens on the Unix domain socket at this path instead of `address`.
+    #[serde(default)]
+    pub unix_socket_path: Option<String>,
+
     /// Number of SSEs to buffer.
     pub event_stream_buffer_length: u32,
 
     /// Default maximum number of subscribers across all event streams permitted at any one time.
     pub max_concurrent_subscribers: u32,
+
+    /// Keys accepted from subscribers. If empty, subscribers don't need a key.
+    #[serde(default)]
+    pub api_keys: Vec<EventStreamApiKey>,
+
+    /// If set, the server only accepts HTTPS connections.
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
 }
 
 impl Config {
@@ -33,9 +47,12 @@ impl Config {
 
         Config {
             address,
+            unix_socket_path: None,
             event_stream_buffer_length: buffer_length.unwrap_or(DEFAULT_EVENT_STREAM_BUFFER_LENGTH),
             max_concurrent_subscribers: max_subscribers
                 .unwrap_or(DEFAULT_MAX_CONCURRENT_SUBSCRIBERS),
+            api_keys: Vec::new(),
+            tls: None,
         }
     }
 }
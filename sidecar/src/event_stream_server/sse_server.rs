@@ -1,6 +1,11 @@
 //! Types and functions used by the http server to manage the event-stream.
 
-use super::endpoint::Endpoint;
+use super::{
+    auth::{SubscriptionAuthenticator, SubscriptionPermit},
+    endpoint::Endpoint,
+    ws_server,
+};
+use crate::api_key::{provided_api_key, ApiKeyRejection, API_KEY_HEADER, API_KEY_QUERY_PARAM};
 #[cfg(feature = "additional-metrics")]
 use crate::utils::start_metrics_thread;
 use casper_event_types::{sse_data::EventFilter, sse_data::SseData, Deploy, Filter as SseFilter};
@@ -159,13 +164,7 @@ async fn filter_map_server_sent_event(
     stream_filter: &Endpoint,
     event_filter: &[EventFilter],
 ) -> Option<Result<WarpServerSentEvent, RecvError>> {
-    if !event.data.should_include(event_filter) {
-        return None;
-    }
-    let id = match determine_id(event) {
-        Some(id) => id,
-        None => return None,
-    };
+    let id = outbound_id(event, stream_filter, event_filter)?;
 
     match &event.data {
         &SseData::ApiVersion { .. } | &SseData::SidecarVersion { .. } => {
@@ -182,14 +181,25 @@ async fn filter_map_server_sent_event(
             Some(Ok(warp_event))
         }
         SseData::DeployAccepted { deploy } => handle_deploy_accepted(event, deploy, &id),
-        &SseData::Shutdown => {
-            if should_send_shutdown(event, stream_filter) {
-                build_event_for_outbound(event, id)
-            } else {
-                None
-            }
-        }
+        &SseData::Shutdown => build_event_for_outbound(event, id),
+    }
+}
+
+/// Returns the ID with which the `event` should be sent to a client of `stream_filter`, or `None`
+/// if it should be filtered out.  The ID is empty for the events which are sent without one.
+pub(super) fn outbound_id(
+    event: &ServerSentEvent,
+    stream_filter: &Endpoint,
+    event_filter: &[EventFilter],
+) -> Option<String> {
+    if !event.data.should_include(event_filter) {
+        return None;
     }
+    let id = determine_id(event)?;
+    if matches!(&event.data, &SseData::Shutdown) && !should_send_shutdown(event, stream_filter) {
+        return None;
+    }
+    Some(id)
 }
 
 fn should_send_shutdown(event: &ServerSentEvent, stream_filter: &Endpoint) -> bool {
@@ -339,6 +349,22 @@ fn create_422() -> Response {
     response
 }
 
+/// Creates a 401 (Unauthorized) or 429 (Too Many Requests) response to be returned if the API key
+/// of the subscriber was rejected.
+fn create_api_key_rejection_response(rejection: ApiKeyRejection) -> Response {
+    let (status, message) = match rejection {
+        ApiKeyRejection::Missing => (StatusCode::UNAUTHORIZED, "missing API key"),
+        ApiKeyRejection::Unknown => (StatusCode::UNAUTHORIZED, "unknown API key"),
+        ApiKeyRejection::RateLimited | ApiKeyRejection::TooManySubscriptions => (
+            StatusCode::TOO_MANY_REQUESTS,
+            "API key has reached its limit of subscriptions",
+        ),
+    };
+    let mut response = Response::new(Body::from(message));
+    *response.status_mut() = status;
+    response
+}
+
 /// Creates a 503 response (Service Unavailable) to be returned if the server has too many
 /// subscribers.
 fn create_503() -> Response {
@@ -350,7 +376,8 @@ fn create_503() -> Response {
 pub(super) struct ChannelsAndFilter {
     pub(super) event_broadcaster: broadcast::Sender<BroadcastChannelMessage>,
     pub(super) new_subscriber_info_receiver: mpsc::UnboundedReceiver<NewSubscriberInfo>,
-    pub(super) sse_filter: BoxedFilter<(Response,)>,
+    /// Serves the event stream over SSE, or over a WebSocket if the client asks for an upgrade.
+    pub(super) filter: BoxedFilter<(Response,)>,
 }
 
 fn serve_sse_response_handler(
@@ -359,6 +386,7 @@ fn serve_sse_response_handler(
     cloned_broadcaster: tokio::sync::broadcast::Sender<BroadcastChannelMessage>,
     max_concurrent_subscribers: u32,
     new_subscriber_info_sender: UnboundedSender<NewSubscriberInfo>,
+    permit: SubscriptionPermit,
     #[cfg(feature = "additional-metrics")] metrics_sender: Sender<()>,
 ) -> http::Response<Body> {
     if let Some(value) = validate(&cloned_broadcaster, max_concurrent_subscribers) {
@@ -368,7 +396,32 @@ fn serve_sse_response_handler(
         Ok(value) => value,
         Err(error_response) => return error_response,
     };
+    let (initial_events_receiver, ongoing_events_receiver) =
+        subscribe(start_from, &cloned_broadcaster, &new_subscriber_info_sender);
+
+    // The permit is held by the stream, so the subscription counts towards the limit of its API key
+    // until the client disconnects.
+    sse::reply(sse::keep_alive().stream(permit.attach_to(stream_to_client(
+        initial_events_receiver,
+        ongoing_events_receiver,
+        stream_filter,
+        event_filter,
+        #[cfg(feature = "additional-metrics")]
+        metrics_sender,
+    ))))
+    .into_response()
+}
 
+/// Registers a new subscriber, returning the receivers of its initial events and of the ongoing
+/// ones.
+pub(super) fn subscribe(
+    start_from: Option<Id>,
+    broadcaster: &broadcast::Sender<BroadcastChannelMessage>,
+    new_subscriber_info_sender: &UnboundedSender<NewSubscriberInfo>,
+) -> (
+    mpsc::UnboundedReceiver<ServerSentEvent>,
+    broadcast::Receiver<BroadcastChannelMessage>,
+) {
     // Create a channel for the client's handler to receive the stream of initial events.
     let (initial_events_sender, initial_events_receiver) = mpsc::unbounded_channel();
 
@@ -386,20 +439,31 @@ fn serve_sse_response_handler(
     }
 
     // Create a channel for the client's handler to receive the stream of ongoing events.
-    let ongoing_events_receiver = cloned_broadcaster.subscribe();
+    let ongoing_events_receiver = broadcaster.subscribe();
+    (initial_events_receiver, ongoing_events_receiver)
+}
 
-    sse::reply(sse::keep_alive().stream(stream_to_client(
-        initial_events_receiver,
-        ongoing_events_receiver,
-        stream_filter,
-        event_filter,
-        #[cfg(feature = "additional-metrics")]
-        metrics_sender,
-    )))
-    .into_response()
+/// Authorizes a new subscriber if `authenticator` is set, taking the API key from the header or
+/// from the query.  Returns the response to reply with if the API key was rejected.
+pub(super) fn authorize_subscriber(
+    authenticator: &Option<Arc<SubscriptionAuthenticator>>,
+    maybe_api_key_header: Option<String>,
+    query: &mut HashMap<String, String>,
+) -> Result<SubscriptionPermit, Response> {
+    match authenticator {
+        Some(authenticator) => {
+            let maybe_api_key = provided_api_key(maybe_api_key_header, query);
+            // The key isn't one of the event stream's own query fields.
+            query.remove(API_KEY_QUERY_PARAM);
+            authenticator
+                .authorize(maybe_api_key)
+                .map_err(create_api_key_rejection_response)
+        }
+        None => Ok(SubscriptionPermit::unrestricted()),
+    }
 }
 
-fn parse_url_props(
+pub(super) fn parse_url_props(
     maybe_path_param: Option<String>,
     query: HashMap<String, String>,
 ) -> Result<UrlProps, http::Response<Body>> {
@@ -419,7 +483,7 @@ fn parse_url_props(
     Ok((event_filter, stream_filter, start_from))
 }
 
-fn validate(
+pub(super) fn validate(
     cloned_broadcaster: &broadcast::Sender<BroadcastChannelMessage>,
     max_concurrent_subscribers: u32,
 ) -> Option<http::Response<Body>> {
@@ -437,7 +501,13 @@ fn validate(
 impl ChannelsAndFilter {
     /// Creates the message-passing channels required to run the event-stream server and the warp
     /// filter for the event-stream server.
-    pub(super) fn new(broadcast_channel_size: usize, max_concurrent_subscribers: u32) -> Self {
+    ///
+    /// If `authenticator` is set, subscribers must provide one of its API keys.
+    pub(super) fn new(
+        broadcast_channel_size: usize,
+        max_concurrent_subscribers: u32,
+        authenticator: Option<Arc<SubscriptionAuthenticator>>,
+    ) -> Self {
         // Create a channel to broadcast new events to all subscribed clients' streams.
         let (event_broadcaster, _) = broadcast::channel(broadcast_channel_size);
         let cloned_broadcaster = event_broadcaster.clone();
@@ -447,16 +517,30 @@ impl ChannelsAndFilter {
         // Create a channel for `NewSubscriberInfo`s to pass the information required to handle a
         // new client subscription.
         let (new_subscriber_info_sender, new_subscriber_info_receiver) = mpsc::unbounded_channel();
-        let opt = warp::path::param::<String>()
-            .map(Some)
-            .or_else(|_| async { Ok::<(Option<String>,), std::convert::Infallible>((None,)) });
+        let ws_filter = ws_server::build_filter(
+            authenticator.clone(),
+            event_broadcaster.clone(),
+            max_concurrent_subscribers,
+            new_subscriber_info_sender.clone(),
+        );
         let sse_filter = warp::get()
             .and(warp::path!("events" / ..))
-            .and(opt)
+            .and(optional_path_param())
             .and(path::end())
             .and(warp::query())
+            .and(warp::header::optional::<String>(API_KEY_HEADER))
             .map(
-                move |maybe_path_param: Option<String>, query: HashMap<String, String>| {
+                move |maybe_path_param: Option<String>,
+                      mut query: HashMap<String, String>,
+                      maybe_api_key_header: Option<String>| {
+                    let permit = match authorize_subscriber(
+                        &authenticator,
+                        maybe_api_key_header,
+                        &mut query,
+                    ) {
+                        Ok(permit) => permit,
+                        Err(rejection_response) => return rejection_response,
+                    };
                     let new_subscriber_info_sender_clone = new_subscriber_info_sender.clone();
                     serve_sse_response_handler(
                         maybe_path_param,
@@ -464,22 +548,32 @@ impl ChannelsAndFilter {
                         cloned_broadcaster.clone(),
                         max_concurrent_subscribers,
                         new_subscriber_info_sender_clone,
+                        permit,
                         #[cfg(feature = "additional-metrics")]
                         tx.clone(),
                     )
                 },
             )
-            .or_else(|_| async move { Ok::<_, Rejection>((create_404(),)) })
-            .boxed();
+            .or_else(|_| async move { Ok::<_, Rejection>((create_404(),)) });
+        // Requests which aren't WebSocket upgrades are rejected by `ws_filter` and served over SSE.
+        let filter = ws_filter.or(sse_filter).unify().boxed();
 
         ChannelsAndFilter {
             event_broadcaster,
             new_subscriber_info_receiver,
-            sse_filter,
+            filter,
         }
     }
 }
 
+/// Extracts the final URL path element following `events`, if there is one.
+pub(super) fn optional_path_param(
+) -> impl Filter<Extract = (Option<String>,), Error = std::convert::Infallible> + Clone {
+    warp::path::param::<String>()
+        .map(Some)
+        .or_else(|_| async { Ok::<(Option<String>,), std::convert::Infallible>((None,)) })
+}
+
 /// This takes the two channel receivers and turns them into a stream of SSEs to the subscribed
 /// client.
 ///
@@ -501,6 +595,19 @@ fn stream_to_client(
     event_filter: &'static [EventFilter],
     #[cfg(feature = "additional-metrics")] metrics_sender: Sender<()>,
 ) -> impl Stream<Item = Result<WarpServerSentEvent, RecvError>> + 'static {
+    build_combined_events_stream(
+        deduplicated_events(initial_events, ongoing_events),
+        stream_filter,
+        event_filter,
+    )
+}
+
+/// Serves the initial events followed by the ongoing ones, skipping the ongoing events already
+/// delivered in the initial stream.  The stream ends when the server shuts down.
+pub(super) fn deduplicated_events(
+    initial_events: mpsc::UnboundedReceiver<ServerSentEvent>,
+    ongoing_events: broadcast::Receiver<BroadcastChannelMessage>,
+) -> impl Stream<Item = Result<ServerSentEvent, RecvError>> + Send + 'static {
     // Keep a record of the IDs of the events delivered via the `initial_events` receiver.
     let initial_stream_ids = Arc::new(RwLock::new(HashSet::new()));
     let cloned_initial_ids = Arc::clone(&initial_stream_ids);
@@ -522,25 +629,6 @@ fn stream_to_client(
         .take_while(|result| future::ready(!matches!(result, Err(RecvError::Closed))))
         .boxed();
 
-    build_combined_events_stream(
-        initial_events,
-        initial_stream_ids,
-        ongoing_stream,
-        stream_filter,
-        event_filter,
-    )
-}
-
-// Builds stream that serves the initial events followed by the ongoing ones, filtering as dictated by the `event_filter`.
-fn build_combined_events_stream(
-    initial_events: mpsc::UnboundedReceiver<ServerSentEvent>,
-    initial_stream_ids: Arc<RwLock<HashSet<u32>>>,
-    ongoing_stream: std::pin::Pin<
-        Box<dyn Stream<Item = Result<ServerSentEvent, RecvError>> + Send>,
-    >,
-    stream_filter: &'static Endpoint,
-    event_filter: &'static [EventFilter],
-) -> impl Stream<Item = Result<WarpServerSentEvent, RecvError>> + 'static {
     UnboundedReceiverStream::new(initial_events)
         .map(move |event| {
             if let Some(id) = event.id {
@@ -549,27 +637,35 @@ fn build_combined_events_stream(
             Ok(event)
         })
         .chain(ongoing_stream)
-        .filter_map(move |result| {
+}
+
+// Builds stream that serves the `events` as warp events, filtering as dictated by the `event_filter`.
+fn build_combined_events_stream(
+    events: impl Stream<Item = Result<ServerSentEvent, RecvError>> + 'static,
+    stream_filter: &'static Endpoint,
+    event_filter: &'static [EventFilter],
+) -> impl Stream<Item = Result<WarpServerSentEvent, RecvError>> + 'static {
+    events.filter_map(move |result| {
+        #[cfg(feature = "additional-metrics")]
+        let metrics_sender = metrics_sender.clone();
+        async move {
             #[cfg(feature = "additional-metrics")]
-            let metrics_sender = metrics_sender.clone();
-            async move {
-                #[cfg(feature = "additional-metrics")]
-                let sender = metrics_sender;
-                match result {
-                    Ok(event) => {
-                        let fitlered_data =
-                            filter_map_server_sent_event(&event, stream_filter, event_filter).await;
-                        #[cfg(feature = "additional-metrics")]
-                        if let Some(_) = fitlered_data {
-                            let _ = sender.clone().send(()).await;
-                        }
-                        #[allow(clippy::let_and_return)]
-                        fitlered_data
+            let sender = metrics_sender;
+            match result {
+                Ok(event) => {
+                    let fitlered_data =
+                        filter_map_server_sent_event(&event, stream_filter, event_filter).await;
+                    #[cfg(feature = "additional-metrics")]
+                    if let Some(_) = fitlered_data {
+                        let _ = sender.clone().send(()).await;
                     }
-                    Err(error) => Some(Err(error)),
+                    #[allow(clippy::let_and_return)]
+                    fitlered_data
                 }
+                Err(error) => Some(Err(error)),
             }
-        })
+        }
+    })
 }
 
 fn handle_lagged(amount: u64) -> Option<Result<ServerSentEvent, RecvError>> {
@@ -272,7 +272,10 @@ impl TestFixture {
         self.first_event_id = server.event_indexer.current_index();
 
         let first_event_id = server.event_indexer.current_index();
-        let server_address = server.listening_address;
+        let server_address = match server.listening_on {
+            ListeningOn::Tcp(address) => address,
+            ListeningOn::UnixSocket(_) => unreachable!("test server should listen on TCP"),
+        };
         let events = self.events.clone();
         let server_stopper = self.server_stopper.clone();
         let protocol_version = self.protocol_version;
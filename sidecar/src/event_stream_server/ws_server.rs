@@ -0,0 +1,282 @@
+//! WebSocket transport of the event stream.
+//!
+//! A client upgrading its request to one of the event stream paths gets the same events as an SSE
+//! subscriber of that path, including the ones replayed from the buffer with `start_from`.  Every
+//! event is sent as a text message holding a JSON object with the `id` of the event, absent for
+//! `ApiVersion` and `SidecarVersion`, and its `data`, e.g.:
+//! `{"id":12,"data":{"BlockAdded":{..}}}`
+
+use super::{
+    auth::{SubscriptionAuthenticator, SubscriptionPermit},
+    endpoint::Endpoint,
+    sse_server::{
+        authorize_subscriber, deduplicated_events, optional_path_param, outbound_id,
+        parse_url_props, subscribe, validate, BroadcastChannelMessage, DeployAccepted, Id,
+        NewSubscriberInfo, ServerSentEvent,
+    },
+};
+use crate::api_key::API_KEY_HEADER;
+use casper_event_types::sse_data::{EventFilter, SseData};
+use futures::{future, SinkExt, Stream, StreamExt};
+use serde::Serialize;
+use serde_json::value::{to_raw_value, RawValue};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::{
+    select,
+    sync::{
+        broadcast::{self, error::RecvError},
+        mpsc::UnboundedSender,
+    },
+    time,
+};
+use tracing::warn;
+use warp::{
+    filters::BoxedFilter,
+    path,
+    reply::Response,
+    ws::{Message, WebSocket, Ws},
+    Filter, Reply,
+};
+
+/// How often the server pings the client, so that idle connections aren't closed by proxies.
+const PING_INTERVAL: Duration = Duration::from_secs(15);
+
+/// The message sent to the client for every event.
+#[derive(Serialize)]
+struct WebSocketEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<Id>,
+    data: Box<RawValue>,
+}
+
+/// Builds the filter upgrading requests to the event stream paths to WebSockets.  Requests which
+/// aren't WebSocket upgrades are rejected, so that they can be served over SSE.
+pub(super) fn build_filter(
+    authenticator: Option<Arc<SubscriptionAuthenticator>>,
+    broadcaster: broadcast::Sender<BroadcastChannelMessage>,
+    max_concurrent_subscribers: u32,
+    new_subscriber_info_sender: UnboundedSender<NewSubscriberInfo>,
+) -> BoxedFilter<(Response,)> {
+    warp::path!("events" / ..)
+        .and(optional_path_param())
+        .and(path::end())
+        .and(warp::ws())
+        .and(warp::query())
+        .and(warp::header::optional::<String>(API_KEY_HEADER))
+        .map(
+            move |maybe_path_param: Option<String>,
+                  ws: Ws,
+                  mut query: HashMap<String, String>,
+                  maybe_api_key_header: Option<String>| {
+                let permit =
+                    match authorize_subscriber(&authenticator, maybe_api_key_header, &mut query) {
+                        Ok(permit) => permit,
+                        Err(rejection_response) => return rejection_response,
+                    };
+                if let Some(response) = validate(&broadcaster, max_concurrent_subscribers) {
+                    return response;
+                }
+                let (event_filter, stream_filter, start_from) =
+                    match parse_url_props(maybe_path_param, query) {
+                        Ok(value) => value,
+                        Err(error_response) => return error_response,
+                    };
+                let (initial_events_receiver, ongoing_events_receiver) =
+                    subscribe(start_from, &broadcaster, &new_subscriber_info_sender);
+                let events = deduplicated_events(initial_events_receiver, ongoing_events_receiver);
+                ws.on_upgrade(move |socket| {
+                    serve_websocket(socket, events, stream_filter, event_filter, permit)
+                })
+                .into_response()
+            },
+        )
+        .boxed()
+}
+
+/// Sends the `events` to the client until it disconnects, lags behind or the server shuts down.
+async fn serve_websocket(
+    socket: WebSocket,
+    events: impl Stream<Item = Result<ServerSentEvent, RecvError>> + Send + 'static,
+    stream_filter: &'static Endpoint,
+    event_filter: &'static [EventFilter],
+    permit: SubscriptionPermit,
+) {
+    let (mut sink, mut incoming) = socket.split();
+    let messages = events.filter_map(move |result| {
+        future::ready(match result {
+            Ok(event) => websocket_message(&event, stream_filter, event_filter).map(Ok),
+            Err(error) => Some(Err(error)),
+        })
+    });
+    // The permit is held by the stream, so the subscription counts towards the limit of its API key
+    // until the client disconnects.
+    let mut messages = Box::pin(permit.attach_to(messages));
+    let mut ping_interval = time::interval(PING_INTERVAL);
+    // The first tick completes immediately.
+    ping_interval.tick().await;
+    loop {
+        select! {
+            maybe_message = messages.next() => {
+                let message = match maybe_message {
+                    Some(Ok(message)) => message,
+                    // The client lagged behind or the server is shutting down.
+                    Some(Err(_)) | None => break,
+                };
+                if sink.send(message).await.is_err() {
+                    return;
+                }
+            }
+            maybe_incoming = incoming.next() => {
+                // Clients aren't expected to send anything but pings, pongs and the closing frame.
+                match maybe_incoming {
+                    Some(Ok(message)) if !message.is_close() => {}
+                    _ => return,
+                }
+            }
+            _ = ping_interval.tick() => {
+                if sink.send(Message::ping(Vec::new())).await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+    let _ = sink.send(Message::close()).await;
+}
+
+/// Maps the `event` to the message sent to a client of `stream_filter`, or `None` if it should be
+/// filtered out.
+fn websocket_message(
+    event: &ServerSentEvent,
+    stream_filter: &Endpoint,
+    event_filter: &[EventFilter],
+) -> Option<Message> {
+    outbound_id(event, stream_filter, event_filter)?;
+    let data = match (&event.json_data, &event.data) {
+        (Some(json_data), _) => RawValue::from_string(json_data.clone()),
+        (None, SseData::DeployAccepted { deploy }) => to_raw_value(&DeployAccepted {
+            deploy_accepted: deploy.clone(),
+        }),
+        (None, data) => to_raw_value(data),
+    };
+    let text = data.and_then(|data| serde_json::to_string(&WebSocketEvent { id: event.id, data }));
+    match text {
+        Ok(text) => Some(Message::text(text)),
+        Err(error) => {
+            warn!(%error, ?event, "failed to jsonify websocket event");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_stream_server::sse_server::ChannelsAndFilter;
+    use casper_types::{testing::TestRng, ProtocolVersion};
+    use serde_json::Value;
+
+    fn parse_message(message: &Message) -> Value {
+        serde_json::from_str(message.to_str().expect("Message should be text"))
+            .expect("Message should be JSON")
+    }
+
+    #[tokio::test]
+    async fn should_stream_filtered_events_over_websocket() {
+        let mut rng = TestRng::new();
+        let ChannelsAndFilter {
+            event_broadcaster,
+            mut new_subscriber_info_receiver,
+            filter,
+        } = ChannelsAndFilter::new(10, 10, None);
+
+        let mut client = warp::test::ws()
+            .path("/events/main?start_from=0")
+            .handshake(filter)
+            .await
+            .expect("Handshake should succeed");
+        let subscriber = new_subscriber_info_receiver.recv().await.unwrap();
+        assert_eq!(subscriber.start_from, Some(0));
+        let api_version = ServerSentEvent::initial_event(ProtocolVersion::V1_0_0);
+        subscriber
+            .initial_events_sender
+            .send(api_version.clone())
+            .unwrap();
+        drop(subscriber);
+        let (deploy_accepted, _) = SseData::random_deploy_accepted(&mut rng);
+        let block_added = SseData::random_block_added(&mut rng);
+        for (id, data) in [(1, deploy_accepted), (2, block_added.clone())] {
+            let event = ServerSentEvent {
+                id: Some(id),
+                data,
+                json_data: None,
+                inbound_filter: None,
+            };
+            event_broadcaster
+                .send(BroadcastChannelMessage::ServerSentEvent(event))
+                .unwrap();
+        }
+
+        let message = parse_message(&client.recv().await.unwrap());
+        assert_eq!(
+            message,
+            serde_json::json!({ "data": serde_json::to_value(&api_version.data).unwrap() })
+        );
+        // The `DeployAccepted` isn't streamed on `/events/main`.
+        let message = parse_message(&client.recv().await.unwrap());
+        assert_eq!(message["id"], 2);
+        assert_eq!(message["data"], serde_json::to_value(&block_added).unwrap());
+
+        event_broadcaster
+            .send(BroadcastChannelMessage::Shutdown)
+            .unwrap();
+        // The connection is closed once the server shuts down.
+        assert!(client
+            .recv()
+            .await
+            .map_or(true, |message| message.is_close()));
+    }
+
+    #[tokio::test]
+    async fn should_reject_websocket_subscribers_over_limit() {
+        let ChannelsAndFilter {
+            mut new_subscriber_info_receiver,
+            filter,
+            ..
+        } = ChannelsAndFilter::new(10, 1, None);
+
+        let _client = warp::test::ws()
+            .path("/events/sigs")
+            .handshake(filter.clone())
+            .await
+            .expect("Handshake should succeed");
+        assert!(new_subscriber_info_receiver.recv().await.is_some());
+
+        assert!(warp::test::ws()
+            .path("/events/sigs")
+            .handshake(filter)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn should_wrap_deploy_accepted_in_websocket_message() {
+        let mut rng = TestRng::new();
+        let (data, deploy) = SseData::random_deploy_accepted(&mut rng);
+        let event = ServerSentEvent {
+            id: Some(7),
+            data,
+            json_data: None,
+            inbound_filter: None,
+        };
+
+        let message = websocket_message(&event, &Endpoint::Deploys, &[EventFilter::DeployAccepted])
+            .expect("Event should be streamed");
+
+        let message = parse_message(&message);
+        assert_eq!(message["id"], 7);
+        assert_eq!(
+            message["data"]["DeployAccepted"],
+            serde_json::to_value(&deploy).unwrap()
+        );
+    }
+}
@@ -4,7 +4,9 @@
 
 extern crate core;
 mod admin_server;
+mod api_key;
 mod api_version_manager;
+mod connections;
 mod database;
 mod event_stream_server;
 pub mod rest_server;
@@ -13,6 +15,7 @@ mod sql;
 pub(crate) mod testing;
 #[cfg(test)]
 pub(crate) mod tests;
+mod tls;
 mod types;
 mod utils;
 
@@ -22,19 +25,20 @@ use std::{
     net::IpAddr,
     path::{Path, PathBuf},
     str::FromStr,
-    time::Duration,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use crate::{
-    admin_server::run_server as start_admin_server,
+    admin_server::{run_server as start_admin_server, DatabaseHandle, SidecarState},
     database::sqlite_database::SqliteDatabase,
     event_stream_server::{Config as SseConfig, EventStreamServer},
-    rest_server::run_server as start_rest_server,
+    rest_server::{deploy_waiter, run_server as start_rest_server},
     types::{
         config::{read_config, Config},
         database::{DatabaseWriteError, DatabaseWriter},
         sse_events::*,
     },
+    utils::bind_address,
 };
 use anyhow::{Context, Error};
 use api_version_manager::{ApiVersionManager, GuardedApiVersionManager};
@@ -49,7 +53,10 @@ use hex_fmt::HexFmt;
 #[cfg(not(target_env = "msvc"))]
 use tikv_jemallocator::Jemalloc;
 use tokio::{
-    sync::mpsc::{channel as mpsc_channel, Receiver, Sender},
+    sync::{
+        mpsc::{channel as mpsc_channel, Receiver, Sender},
+        watch,
+    },
     task::JoinHandle,
     time::sleep,
 };
@@ -95,14 +102,30 @@ async fn main() -> Result<(), Error> {
 async fn run(config: Config) -> Result<(), Error> {
     validate_config(&config)?;
     let (event_listeners, sse_data_receivers) = build_event_listeners(&config)?;
-    let admin_server_handle = build_and_start_admin_server(&config);
     // This channel allows SseData to be sent from multiple connected nodes to the single EventStreamServer.
     let (outbound_sse_data_sender, outbound_sse_data_receiver) =
         mpsc_channel(config.outbound_channel_size.unwrap_or(DEFAULT_CHANNEL_SIZE));
     let connection_configs = config.connections.clone();
     let storage_config = config.storage.clone();
+    let nodes: Vec<(String, u16)> = event_listeners
+        .iter()
+        .map(|event_listener| {
+            let node = event_listener.get_node_interface();
+            (node.ip_address.to_string(), node.sse_port)
+        })
+        .collect();
+    // The admin server is started before the database is built, which can take long because of
+    // migrations, so that the sidecar can be probed and its metrics scraped meanwhile.
+    let (database_sender, database_handle) = watch::channel(None);
+    let admin_server_handle = build_and_start_admin_server(
+        &config,
+        database_handle,
+        nodes.clone(),
+        outbound_sse_data_sender.clone(),
+    );
     let database = build_database(&storage_config).await?;
-    let rest_server_handle = build_and_start_rest_server(&config, database.clone());
+    database_sender.send_replace(Some(database.clone()));
+    let rest_server_handle = build_and_start_rest_server(&config, database.clone(), nodes);
 
     // Task to manage incoming events from all three filters
     let listening_task_handle = start_sse_processors(
@@ -134,14 +157,27 @@ fn start_event_broadcasting(
     let event_stream_server_port = config.event_stream_server.port;
     let buffer_length = config.event_stream_server.event_stream_buffer_length;
     let max_concurrent_subscribers = config.event_stream_server.max_concurrent_subscribers;
+    let address = bind_address(
+        config.event_stream_server.address.as_deref(),
+        event_stream_server_port,
+    );
+    let unix_socket_path = config.event_stream_server.unix_socket_path.clone();
+    let api_keys = config.event_stream_server.api_keys.clone();
+    let tls = config.event_stream_server.tls.clone();
     tokio::spawn(async move {
         // Create new instance for the Sidecar's Event Stream Server
         let mut event_stream_server = EventStreamServer::new(
-            SseConfig::new(
-                event_stream_server_port,
-                Some(buffer_length),
-                Some(max_concurrent_subscribers),
-            ),
+            SseConfig {
+                address,
+                unix_socket_path,
+                api_keys,
+                tls,
+                ..SseConfig::new(
+                    event_stream_server_port,
+                    Some(buffer_length),
+                    Some(max_concurrent_subscribers),
+                )
+            },
             PathBuf::from(storage_path),
         )
         .context("Error starting EventStreamServer")?;
@@ -233,28 +269,39 @@ fn spawn_sse_processor(
 fn build_and_start_rest_server(
     config: &Config,
     database: Database,
+    nodes: Vec<(String, u16)>,
 ) -> JoinHandle<Result<(), Error>> {
     let rest_server_config = config.rest_server.clone();
     tokio::spawn(async move {
         match database {
             Database::SqliteDatabaseWrapper(db) => {
-                start_rest_server(rest_server_config, db.clone()).await
+                start_rest_server(rest_server_config, db.clone(), nodes).await
             }
             Database::PostgreSqlDatabaseWrapper(db) => {
-                start_rest_server(rest_server_config, db.clone()).await
+                start_rest_server(rest_server_config, db.clone(), nodes).await
             }
         }
     })
 }
 
-fn build_and_start_admin_server(config: &Config) -> JoinHandle<Result<(), Error>> {
+fn build_and_start_admin_server(
+    config: &Config,
+    database: DatabaseHandle,
+    nodes: Vec<(String, u16)>,
+    outbound_sse_data_sender: Sender<(SseData, Option<Filter>, Option<String>)>,
+) -> JoinHandle<Result<(), Error>> {
     let admin_server_config = config.admin_server.clone();
     tokio::spawn(async move {
-        if let Some(config) = admin_server_config {
-            start_admin_server(config).await
-        } else {
-            Ok(())
-        }
+        let config = match admin_server_config {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        let sidecar_state = SidecarState {
+            database,
+            nodes,
+            outbound_sender: outbound_sse_data_sender,
+        };
+        start_admin_server(config, sidecar_state).await
     })
 }
 
@@ -363,9 +410,11 @@ async fn handle_database_save_result<F>(
     match res {
         Ok(_) => {
             count_internal_event("main_inbound_sse_data", "db_save_end");
+            let sse_data = build_sse_data();
+            deploy_waiter::announce_stored_event(&sse_data);
             count_internal_event("main_inbound_sse_data", "outbound_sse_data_send_start");
             if let Err(error) = outbound_sse_data_sender
-                .send((build_sse_data(), Some(inbound_filter), json_data))
+                .send((sse_data, Some(inbound_filter), json_data))
                 .await
             {
                 count_internal_event("main_inbound_sse_data", "outbound_sse_data_send_end");
@@ -411,6 +460,7 @@ async fn handle_single_event<Db: DatabaseReader + DatabaseWriter + Clone + Send
         }
         _ => {
             count_internal_event("main_inbound_sse_data", "event_received_start");
+            record_event_ingested();
         }
     }
     match sse_event.data {
@@ -843,3 +893,12 @@ fn count_internal_event(category: &str, reason: &str) {
         .with_label_values(&[category, reason])
         .inc();
 }
+
+/// Records when an event was last received, which the readiness check of the admin server
+/// compares to the current time.
+fn record_event_ingested() {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |since_epoch| since_epoch.as_secs_f64());
+    metrics::LAST_INGESTED_EVENT_TIMESTAMP.set(now);
+}
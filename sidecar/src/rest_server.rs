@@ -1,47 +1,162 @@
+mod auth;
+mod caching;
+mod chain_stats;
+pub(crate) mod deploy_waiter;
 mod errors;
 pub mod filters;
+#[cfg(feature = "graphql")]
+mod graphql;
 mod handlers;
+mod json_rpc;
 mod openapi;
 #[cfg(test)]
 mod tests;
 
-use std::net::TcpListener;
-use std::time::Duration;
+use std::{
+    convert::Infallible,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use anyhow::Error;
-use hyper::Server;
-use tower::{buffer::Buffer, make::Shared, ServiceBuilder};
+use futures::future;
+use hyper::{server::accept, Body, Request, Response, Server};
+use tower::{
+    buffer::Buffer,
+    limit::{ConcurrencyLimit, RateLimit},
+    make::Shared,
+    BoxError, Service, ServiceBuilder,
+};
 use warp::Filter;
 
 use crate::{
+    api_key::API_KEY_HEADER,
+    connections,
     types::{config::RestServerConfig, database::DatabaseReader},
-    utils::resolve_address,
+    utils::{bind_address, resolve_address},
 };
 
-const BIND_ALL_INTERFACES: &str = "0.0.0.0";
-
+/// Runs the REST server until it fails.
+///
+/// * `nodes` - IP address and SSE port of every node the sidecar connects to
 pub async fn run_server<Db: DatabaseReader + Clone + Send + Sync + 'static>(
     config: RestServerConfig,
     database: Db,
+    nodes: Vec<(String, u16)>,
 ) -> Result<(), Error> {
-    let api = filters::combined_filters(database);
-    let address = format!("{}:{}", BIND_ALL_INTERFACES, config.port);
+    let api = filters::combined_filters(database, &config, nodes);
+    let address = bind_address(config.address.as_deref(), config.port);
     let socket_address = resolve_address(&address)?;
 
-    let listener = TcpListener::bind(socket_address)?;
+    let (_, connections) = connections::listen(
+        socket_address,
+        config.unix_socket_path.as_deref(),
+        config.tls.as_ref(),
+    )?;
 
-    let warp_service = warp::service(api.with(warp::cors().allow_any_origin()));
-    let tower_service = ServiceBuilder::new()
-        .concurrency_limit(config.max_concurrent_requests as usize)
-        .rate_limit(
-            config.max_requests_per_second as u64,
-            Duration::from_secs(1),
-        )
-        .service(warp_service);
+    let warp_service = warp::service(api.with(cors()));
 
-    Server::from_tcp(listener)?
-        .serve(Shared::new(Buffer::new(tower_service, 50)))
+    Server::builder(accept::from_stream(connections))
+        .serve(Shared::new(limit_requests(warp_service, &config)))
         .await?;
 
     Err(Error::msg("REST server shutting down"))
 }
+
+type LimitedService<S> = Buffer<
+    RateLimit<DeployWaitRouter<Buffer<ConcurrencyLimit<S>, Request<Body>>, S>>,
+    Request<Body>,
+>;
+
+/// Limits the number of requests served per second and at the same time. Requests waiting for a
+/// deploy don't count towards `max_concurrent_requests`, as they can be parked for minutes. Their
+/// number is capped by `max_concurrent_deploy_waits` instead.
+fn limit_requests<S>(service: S, config: &RestServerConfig) -> LimitedService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+{
+    let concurrency_limited = Buffer::new(
+        ServiceBuilder::new()
+            .concurrency_limit(config.max_concurrent_requests as usize)
+            .service(service.clone()),
+        50,
+    );
+    let router = DeployWaitRouter {
+        concurrency_limited,
+        deploy_waits: service,
+    };
+    Buffer::new(
+        ServiceBuilder::new()
+            .rate_limit(
+                config.max_requests_per_second as u64,
+                Duration::from_secs(1),
+            )
+            .service(router),
+        50,
+    )
+}
+
+/// Passes the requests waiting for a deploy to `deploy_waits` and all others to
+/// `concurrency_limited`.
+#[derive(Clone)]
+struct DeployWaitRouter<L, W> {
+    concurrency_limited: L,
+    deploy_waits: W,
+}
+
+impl<L, W> Service<Request<Body>> for DeployWaitRouter<L, W>
+where
+    L: Service<Request<Body>, Response = Response<Body>, Error = BoxError> + Clone + Send + 'static,
+    L::Future: Send,
+    W: Service<Request<Body>, Response = Response<Body>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    W::Future: Send,
+{
+    type Response = Response<Body>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Response<Body>, BoxError>> + Send>>;
+
+    // Readiness is awaited for each request, once it's known which service serves it.
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), BoxError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        if is_deploy_wait(request.uri().path()) {
+            let mut deploy_waits = self.deploy_waits.clone();
+            Box::pin(async move {
+                future::poll_fn(|cx| deploy_waits.poll_ready(cx)).await?;
+                deploy_waits.call(request).await.map_err(BoxError::from)
+            })
+        } else {
+            let mut concurrency_limited = self.concurrency_limited.clone();
+            Box::pin(async move {
+                future::poll_fn(|cx| concurrency_limited.poll_ready(cx)).await?;
+                concurrency_limited.call(request).await
+            })
+        }
+    }
+}
+
+/// Whether the request `path` is the one of the deploy wait endpoint, `/deploy/<hash>/wait`.
+fn is_deploy_wait(path: &str) -> bool {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    matches!(segments.as_slice(), ["deploy", _, "wait"])
+}
+
+/// Allows browsers to call the REST API from any origin, including with an API key and with the
+/// JSON bodies of the GraphQL, JSON-RPC and batch endpoints.
+fn cors() -> warp::cors::Builder {
+    warp::cors()
+        .allow_any_origin()
+        .allow_methods(["GET", "HEAD", "POST"])
+        .allow_headers([API_KEY_HEADER, "content-type", "if-none-match"])
+}
@@ -0,0 +1,118 @@
+use super::errors::ApiKeyRejected;
+use crate::{
+    api_key::{find_api_key, provided_api_key, ApiKeyRejection, API_KEY_HEADER},
+    types::config::RestApiKey,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::debug;
+use warp::{Filter, Rejection};
+
+/// Name of the server in the rejection metric.
+const SERVER_NAME: &str = "rest";
+/// Length of the window in which the requests of a key are counted.
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(1);
+
+/// Checks the API keys of requests and enforces the request rate of each key.
+pub(super) struct ApiKeyAuthenticator {
+    keys: Vec<(String, KeyQuota)>,
+}
+
+struct KeyQuota {
+    name: String,
+    max_requests_per_second: Option<u32>,
+    window: Mutex<RequestWindow>,
+}
+
+/// Requests made with a key since the start of the current rate limit window.
+struct RequestWindow {
+    started_at: Instant,
+    request_count: u32,
+}
+
+impl ApiKeyAuthenticator {
+    /// Builds an authenticator accepting the given keys, `None` if there are no keys, in which case
+    /// requests don't need to be authenticated.
+    pub(super) fn new(api_keys: &[RestApiKey]) -> Option<Arc<Self>> {
+        if api_keys.is_empty() {
+            return None;
+        }
+        let keys = api_keys
+            .iter()
+            .map(|api_key| {
+                let quota = KeyQuota {
+                    name: api_key.name.clone(),
+                    max_requests_per_second: api_key.max_requests_per_second,
+                    window: Mutex::new(RequestWindow {
+                        started_at: Instant::now(),
+                        request_count: 0,
+                    }),
+                };
+                (api_key.key.clone(), quota)
+            })
+            .collect();
+        Some(Arc::new(ApiKeyAuthenticator { keys }))
+    }
+
+    fn authenticate(&self, maybe_api_key: Option<String>) -> Result<(), ApiKeyRejection> {
+        let api_key = maybe_api_key.ok_or(ApiKeyRejection::Missing)?;
+        let quota = find_api_key(&self.keys, &api_key).ok_or(ApiKeyRejection::Unknown)?;
+        let max_requests_per_second = match quota.max_requests_per_second {
+            Some(max_requests_per_second) => max_requests_per_second,
+            None => return Ok(()),
+        };
+        let mut window = quota
+            .window
+            .lock()
+            .expect("Error acquiring lock on request window");
+        let now = Instant::now();
+        if now.duration_since(window.started_at) >= RATE_LIMIT_WINDOW {
+            window.started_at = now;
+            window.request_count = 0;
+        }
+        if window.request_count >= max_requests_per_second {
+            return Err(ApiKeyRejection::RateLimited);
+        }
+        window.request_count += 1;
+        Ok(())
+    }
+
+    fn name_of(&self, maybe_api_key: Option<&String>) -> &str {
+        maybe_api_key
+            .and_then(|api_key| find_api_key(&self.keys, api_key))
+            .map_or("", |quota| quota.name.as_str())
+    }
+}
+
+/// Rejects requests which don't carry a known API key or whose key exceeded its request rate.
+/// Every request passes if `authenticator` is `None`.
+pub(super) fn with_api_key(
+    authenticator: Option<Arc<ApiKeyAuthenticator>>,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::header::optional::<String>(API_KEY_HEADER)
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(
+            move |maybe_header: Option<String>, query: HashMap<String, String>| {
+                let authenticator = authenticator.clone();
+                async move {
+                    let authenticator = match authenticator {
+                        Some(authenticator) => authenticator,
+                        None => return Ok(()),
+                    };
+                    let maybe_api_key = provided_api_key(maybe_header, &query);
+                    authenticator
+                        .authenticate(maybe_api_key.clone())
+                        .map_err(|rejection| {
+                            let key_name = authenticator.name_of(maybe_api_key.as_ref());
+                            debug!(?rejection, %key_name, "rejecting REST request");
+                            rejection.record(SERVER_NAME, key_name);
+                            warp::reject::custom(ApiKeyRejected(rejection))
+                        })
+                }
+            },
+        )
+        .untuple_one()
+}
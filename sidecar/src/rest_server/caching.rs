@@ -0,0 +1,128 @@
+use http::{
+    header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH},
+    HeaderMap, HeaderValue, Method, StatusCode,
+};
+use hyper::Body;
+use sha2::{Digest, Sha256};
+use std::convert::Infallible;
+use tracing::error;
+use warp::{reply::Response, Filter, Reply};
+
+/// Describes for how long clients and shared caches may reuse a successful response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum CachePolicy {
+    /// The resource can never change, e.g. a block addressed by its hash.
+    Immutable,
+    /// The resource may change, caches have to revalidate it (using its ETag) before reusing it.
+    Revalidate,
+}
+
+impl CachePolicy {
+    /// Value of the `Cache-Control` header of responses following this policy.
+    pub(super) fn header_value(&self) -> &'static str {
+        match self {
+            CachePolicy::Immutable => "public, max-age=31536000, immutable",
+            CachePolicy::Revalidate => "no-cache",
+        }
+    }
+
+    /// Value of the `Cache-Control` header of responses following this policy which shared caches
+    /// must not store, as they answer authenticated requests.
+    fn private_header_value(&self) -> &'static str {
+        match self {
+            CachePolicy::Immutable => "private, max-age=31536000, immutable",
+            CachePolicy::Revalidate => "no-cache",
+        }
+    }
+}
+
+/// Adds a strong ETag to the successful responses to GET and HEAD requests of `filter` which carry
+/// a `Cache-Control` header, and answers with 304 Not Modified if the request's `If-None-Match`
+/// matches it. If the requests are `authenticated`, the responses are marked as private, so that a
+/// shared cache doesn't serve them to clients which weren't authenticated.
+pub(super) fn with_etag<F, R>(
+    filter: F,
+    authenticated: bool,
+) -> impl Filter<Extract = (Response,), Error = Infallible> + Clone
+where
+    F: Filter<Extract = (R,), Error = Infallible> + Clone + Send + Sync + 'static,
+    R: Reply + Send + 'static,
+{
+    warp::method()
+        .and(warp::header::headers_cloned())
+        .and(filter)
+        .then(
+            move |method: Method, request_headers: HeaderMap, reply: R| async move {
+                let mut response = reply.into_response();
+                if authenticated {
+                    make_private(response.headers_mut());
+                }
+                if method != Method::GET && method != Method::HEAD {
+                    return response;
+                }
+                apply_etag(response, &request_headers).await
+            },
+        )
+}
+
+fn make_private(headers: &mut HeaderMap) {
+    let cache_control = match headers.get(CACHE_CONTROL) {
+        Some(cache_control) => cache_control,
+        None => return,
+    };
+    let private_header_value = [CachePolicy::Immutable, CachePolicy::Revalidate]
+        .iter()
+        .find(|policy| cache_control.as_bytes() == policy.header_value().as_bytes())
+        .map(CachePolicy::private_header_value);
+    if let Some(private_header_value) = private_header_value {
+        headers.insert(
+            CACHE_CONTROL,
+            HeaderValue::from_static(private_header_value),
+        );
+    }
+}
+
+async fn apply_etag(response: Response, request_headers: &HeaderMap) -> Response {
+    if response.status() != StatusCode::OK || !response.headers().contains_key(CACHE_CONTROL) {
+        return response;
+    }
+    let (mut parts, body) = response.into_parts();
+    let body = match hyper::body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(err) => {
+            error!("Error reading response body to compute its ETag: {}", err);
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+            return response;
+        }
+    };
+    let etag = format!("\"{}\"", hex::encode(Sha256::digest(&body)));
+    let etag = HeaderValue::from_str(&etag).expect("hex-encoded ETag should be a valid header");
+
+    if if_none_match_matches(request_headers, &etag) {
+        let mut not_modified = Response::new(Body::empty());
+        *not_modified.status_mut() = StatusCode::NOT_MODIFIED;
+        if let Some(cache_control) = parts.headers.get(CACHE_CONTROL) {
+            not_modified
+                .headers_mut()
+                .insert(CACHE_CONTROL, cache_control.clone());
+        }
+        not_modified.headers_mut().insert(ETAG, etag);
+        return not_modified;
+    }
+    parts.headers.insert(ETAG, etag);
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// `If-None-Match` uses the weak comparison, so `W/` prefixes of the listed ETags are ignored.
+fn if_none_match_matches(request_headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    request_headers
+        .get_all(IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|candidate| {
+            candidate == "*" || candidate.trim_start_matches("W/").as_bytes() == etag.as_bytes()
+        })
+}
@@ -0,0 +1,234 @@
+//! Statistics of the blocks at the tip of the chain.
+//!
+//! The compact headers of the latest blocks and the statistics computed from them are cached
+//! until a new block is stored, so that dashboards polling the same window don't have the blocks
+//! fetched and deserialized over and over.
+
+use crate::types::database::{BlockHeaderSummary, DatabaseReadError, DatabaseReader};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, MutexGuard},
+};
+use utoipa::ToSchema;
+
+/// Statistics of the latest stored blocks.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, ToSchema)]
+pub struct ChainStats {
+    /// Number of blocks the statistics were computed from, lower than the requested window if
+    /// fewer blocks are stored.
+    pub(crate) block_count: u64,
+    pub(crate) from_height: u64,
+    pub(crate) to_height: u64,
+    /// Intervals between the timestamps of consecutive blocks, `null` if there are no two
+    /// consecutive blocks in the window.
+    pub(crate) block_interval: Option<BlockIntervalStats>,
+    pub(crate) deploy_count: u64,
+    pub(crate) transfer_count: u64,
+    /// Deploys and transfers included per block, on average.
+    pub(crate) average_deploys_per_block: f64,
+    /// Number of blocks proposed by each validator, keyed by hex-encoded public key.
+    pub(crate) proposers: BTreeMap<String, u64>,
+}
+
+/// Distribution of the intervals between consecutive blocks, in milliseconds.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, ToSchema)]
+pub struct BlockIntervalStats {
+    pub(crate) average_ms: f64,
+    pub(crate) min_ms: u64,
+    pub(crate) p50_ms: u64,
+    pub(crate) p90_ms: u64,
+    pub(crate) p99_ms: u64,
+    pub(crate) max_ms: u64,
+}
+
+impl ChainStats {
+    /// Computes the statistics of the given headers, which are ordered by height descending.
+    fn new(headers: &[BlockHeaderSummary]) -> Self {
+        let block_count = headers.len() as u64;
+        let deploy_count: u64 = headers.iter().map(|header| header.deploy_count).sum();
+        let transfer_count: u64 = headers.iter().map(|header| header.transfer_count).sum();
+        let average_deploys_per_block = if block_count == 0 {
+            0.0
+        } else {
+            (deploy_count + transfer_count) as f64 / block_count as f64
+        };
+        let mut proposers: BTreeMap<String, u64> = BTreeMap::new();
+        for header in headers {
+            *proposers.entry(header.proposer.clone()).or_default() += 1;
+        }
+        // Blocks missing from the database would make the interval around them look too long.
+        let intervals: Vec<u64> = headers
+            .windows(2)
+            .filter(|pair| pair[0].height == pair[1].height + 1)
+            .map(|pair| {
+                pair[0]
+                    .timestamp
+                    .millis()
+                    .saturating_sub(pair[1].timestamp.millis())
+            })
+            .collect();
+
+        ChainStats {
+            block_count,
+            from_height: headers.last().map_or(0, |header| header.height),
+            to_height: headers.first().map_or(0, |header| header.height),
+            block_interval: BlockIntervalStats::new(intervals),
+            deploy_count,
+            transfer_count,
+            average_deploys_per_block,
+            proposers,
+        }
+    }
+}
+
+impl BlockIntervalStats {
+    fn new(mut intervals: Vec<u64>) -> Option<Self> {
+        if intervals.is_empty() {
+            return None;
+        }
+        intervals.sort_unstable();
+        let average_ms = intervals.iter().sum::<u64>() as f64 / intervals.len() as f64;
+        Some(BlockIntervalStats {
+            average_ms,
+            min_ms: intervals[0],
+            p50_ms: percentile(&intervals, 50),
+            p90_ms: percentile(&intervals, 90),
+            p99_ms: percentile(&intervals, 99),
+            max_ms: intervals[intervals.len() - 1],
+        })
+    }
+}
+
+/// Nearest-rank percentile of the non-empty, sorted `values`.
+fn percentile(values: &[u64], percent: usize) -> u64 {
+    let rank = (values.len() * percent + 99) / 100;
+    values[rank.max(1) - 1]
+}
+
+/// Caches the headers of the latest blocks and their statistics until a new block is stored.
+#[derive(Default)]
+pub(super) struct ChainTipCache {
+    cached: Mutex<CachedChainTip>,
+}
+
+#[derive(Default)]
+struct CachedChainTip {
+    /// Event log id of the last stored block when the values were cached, `None` if nothing is
+    /// cached yet.
+    last_block_event_log_id: Option<u64>,
+    /// Number of headers which were requested when `headers` were fetched. There are fewer
+    /// headers only if fewer blocks are stored.
+    requested_count: u32,
+    /// Headers of the latest blocks, highest first.
+    headers: Vec<BlockHeaderSummary>,
+    stats_by_window: HashMap<u32, ChainStats>,
+}
+
+impl ChainTipCache {
+    /// Returns the headers of the `count` latest blocks, highest first.
+    pub(super) async fn latest_headers<Db: DatabaseReader>(
+        &self,
+        db: &Db,
+        count: u32,
+    ) -> Result<Vec<BlockHeaderSummary>, DatabaseReadError> {
+        let last_block_event_log_id = db.get_last_block_added_event_log_id().await?;
+        self.latest_headers_at(db, last_block_event_log_id, count)
+            .await
+    }
+
+    /// Returns the statistics of the `window` latest blocks.
+    pub(super) async fn stats<Db: DatabaseReader>(
+        &self,
+        db: &Db,
+        window: u32,
+    ) -> Result<ChainStats, DatabaseReadError> {
+        let last_block_event_log_id = db.get_last_block_added_event_log_id().await?;
+        {
+            let cached = self.lock();
+            if cached.last_block_event_log_id == Some(last_block_event_log_id) {
+                if let Some(stats) = cached.stats_by_window.get(&window) {
+                    return Ok(stats.clone());
+                }
+            }
+        }
+
+        let headers = self
+            .latest_headers_at(db, last_block_event_log_id, window)
+            .await?;
+        let stats = ChainStats::new(&headers);
+
+        let mut cached = self.lock();
+        if cached.last_block_event_log_id == Some(last_block_event_log_id) {
+            cached.stats_by_window.insert(window, stats.clone());
+        }
+        Ok(stats)
+    }
+
+    async fn latest_headers_at<Db: DatabaseReader>(
+        &self,
+        db: &Db,
+        last_block_event_log_id: u64,
+        count: u32,
+    ) -> Result<Vec<BlockHeaderSummary>, DatabaseReadError> {
+        {
+            let cached = self.lock();
+            if cached.last_block_event_log_id == Some(last_block_event_log_id)
+                && cached.requested_count >= count
+            {
+                return Ok(cached
+                    .headers
+                    .iter()
+                    .take(count as usize)
+                    .cloned()
+                    .collect());
+            }
+        }
+
+        let headers: Vec<BlockHeaderSummary> = db
+            .get_latest_blocks(count)
+            .await?
+            .iter()
+            .map(BlockHeaderSummary::from)
+            .collect();
+
+        let mut cached = self.lock();
+        match cached.last_block_event_log_id {
+            // Another request already cached the headers of a newer chain tip.
+            Some(cached_id) if cached_id > last_block_event_log_id => return Ok(headers),
+            Some(cached_id) if cached_id == last_block_event_log_id => {}
+            _ => {
+                *cached = CachedChainTip {
+                    last_block_event_log_id: Some(last_block_event_log_id),
+                    ..CachedChainTip::default()
+                }
+            }
+        }
+        if count > cached.requested_count {
+            cached.requested_count = count;
+            cached.headers = headers.clone();
+        }
+        Ok(headers)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CachedChainTip> {
+        self.cached
+            .lock()
+            .expect("Error acquiring lock on chain tip cache")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_nearest_rank_percentiles() {
+        let values: Vec<u64> = (1..=10).collect();
+
+        assert_eq!(percentile(&values, 50), 5);
+        assert_eq!(percentile(&values, 90), 9);
+        assert_eq!(percentile(&values, 99), 10);
+        assert_eq!(percentile(&[7], 50), 7);
+    }
+}
@@ -0,0 +1,96 @@
+//! Wakes up the requests waiting for a deploy to be processed or to expire.
+//!
+//! Every `DeployProcessed` and `DeployExpired` event is announced once it's stored, and each
+//! waiting request checks whether the announced deploy is the one it waits for.
+
+use casper_event_types::sse_data::SseData;
+use futures::future;
+use hex_fmt::HexFmt;
+use once_cell::sync::Lazy;
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    time::{self, Instant},
+};
+
+/// Number of announcements buffered for each waiting request.  A request falling further behind
+/// checks the database again, as the deploy might have been among the missed announcements.
+const ANNOUNCEMENTS_CHANNEL_SIZE: usize = 1024;
+
+/// Hex-encoded hashes of the deploys which were processed or expired.
+static FINISHED_DEPLOYS: Lazy<broadcast::Sender<String>> =
+    Lazy::new(|| broadcast::channel(ANNOUNCEMENTS_CHANNEL_SIZE).0);
+
+/// Announces to the waiting requests that a deploy was processed or expired, if the stored
+/// `sse_data` says so.
+pub(crate) fn announce_stored_event(sse_data: &SseData) {
+    let deploy_hash = match sse_data {
+        SseData::DeployProcessed { deploy_hash, .. } => deploy_hash.as_ref(),
+        SseData::DeployExpired { deploy_hash } => deploy_hash,
+        _ => return,
+    };
+    // This fails if no request is waiting, which is fine.
+    let _ = FINISHED_DEPLOYS.send(HexFmt(deploy_hash.inner()).to_string());
+}
+
+/// Subscription of a request to the announcements.  It should be created before the database is
+/// checked, so that the deploy can't finish unnoticed in between.
+pub(super) struct DeployWaiter {
+    deploy_hash: String,
+    announcements: broadcast::Receiver<String>,
+}
+
+impl DeployWaiter {
+    pub(super) fn new(deploy_hash: &str) -> Self {
+        DeployWaiter {
+            deploy_hash: deploy_hash.to_string(),
+            announcements: FINISHED_DEPLOYS.subscribe(),
+        }
+    }
+
+    /// Waits until the deploy is announced or the `deadline` passes.  Also returns early if
+    /// announcements were missed.
+    pub(super) async fn wait_until(&mut self, deadline: Instant) {
+        let _ = time::timeout_at(deadline, async {
+            loop {
+                match self.announcements.recv().await {
+                    Ok(deploy_hash) if deploy_hash.eq_ignore_ascii_case(&self.deploy_hash) => {
+                        return
+                    }
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(_)) => return,
+                    // The sender is static, so the channel is never closed.
+                    Err(RecvError::Closed) => future::pending::<()>().await,
+                }
+            }
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use casper_event_types::{DeployHash, Digest};
+    use casper_types::testing::TestRng;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn should_wake_up_waiter_of_announced_deploy_only() {
+        let mut rng = TestRng::new();
+        let deploy_hash = DeployHash::new(Digest::random(&mut rng));
+        let hex_deploy_hash = HexFmt(deploy_hash.inner()).to_string();
+        let mut waiter = DeployWaiter::new(&hex_deploy_hash.to_uppercase());
+
+        announce_stored_event(&SseData::DeployExpired {
+            deploy_hash: DeployHash::new(Digest::random(&mut rng)),
+        });
+        let deadline = Instant::now() + Duration::from_millis(100);
+        waiter.wait_until(deadline).await;
+        assert!(Instant::now() >= deadline);
+
+        announce_stored_event(&SseData::DeployExpired { deploy_hash });
+        let deadline = Instant::now() + Duration::from_secs(10);
+        waiter.wait_until(deadline).await;
+        assert!(Instant::now() < deadline);
+    }
+}
@@ -1,6 +1,7 @@
 use std::convert::Infallible;
 
 use crate::{
+    api_key::{ApiKeyRejection, API_KEY_HEADER, API_KEY_QUERY_PARAM},
     types::database::DatabaseReadError,
     utils::{InvalidPath, Unexpected},
 };
@@ -9,7 +10,7 @@ use http::StatusCode;
 use hyper::body::HttpBody;
 use serde::{Deserialize, Serialize};
 use tracing::error;
-use warp::{reject, Rejection, Reply};
+use warp::{filters::body::BodyDeserializeError, reject, Rejection, Reply};
 
 #[derive(Deserialize, Serialize)]
 struct ApiError {
@@ -25,6 +26,14 @@ impl reject::Reject for InvalidParam {}
 pub(super) struct StorageError(pub(super) DatabaseReadError);
 impl reject::Reject for StorageError {}
 
+#[derive(Debug)]
+pub(super) struct ApiKeyRejected(pub(super) ApiKeyRejection);
+impl reject::Reject for ApiKeyRejected {}
+
+#[derive(Debug)]
+pub(super) struct TooManyDeployWaits;
+impl reject::Reject for TooManyDeployWaits {}
+
 /// Handle various REST server errors:
 /// - Unexpected internal server errors
 /// - Queries returning empty result sets
@@ -32,6 +41,10 @@ impl reject::Reject for StorageError {}
 /// - Database-related errors
 /// - Invalid request path errors
 /// - Invalid parameters in the request query
+/// - Request bodies which can't be deserialized
+/// - Request bodies which are too large or whose length isn't given
+/// - Requests with a missing or unknown API key, or whose key exceeded its request rate
+/// - Requests waiting for a deploy beyond the configured number of concurrent waits
 pub(super) async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible> {
     let code;
     let message;
@@ -46,12 +59,32 @@ pub(super) async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infal
         message = err_msg;
     } else if let Some(StorageError(err)) = err.find() {
         (code, message) = status_code_and_err_message_for_read_error(err);
+    } else if let Some(ApiKeyRejected(rejection)) = err.find() {
+        (code, message) = status_code_and_err_message_for_api_key_rejection(rejection);
+    } else if let Some(err) = find_graphql_bad_request(&err) {
+        code = StatusCode::BAD_REQUEST;
+        message = format!("Invalid GraphQL request: {}", err);
+    } else if let Some(err) = err.find::<BodyDeserializeError>() {
+        code = StatusCode::BAD_REQUEST;
+        message = format!("Invalid request body: {}", err);
+    } else if let Some(err) = err.find::<reject::PayloadTooLarge>() {
+        code = StatusCode::PAYLOAD_TOO_LARGE;
+        message = err.to_string();
+    } else if let Some(err) = err.find::<reject::LengthRequired>() {
+        code = StatusCode::LENGTH_REQUIRED;
+        message = err.to_string();
+    } else if let Some(TooManyDeployWaits) = err.find() {
+        code = StatusCode::SERVICE_UNAVAILABLE;
+        message = "Too many requests are waiting for deploys, try again later".to_string();
     } else if let Some(InvalidPath) = err.find() {
         code = StatusCode::BAD_REQUEST;
         message = "Invalid request path provided".to_string();
     } else if let Some(InvalidParam(err)) = err.find() {
         code = StatusCode::BAD_REQUEST;
         message = format!("Invalid parameter in query: {}", err);
+    } else if let Some(err) = err.find::<reject::InvalidQuery>() {
+        code = StatusCode::BAD_REQUEST;
+        message = format!("Invalid query string: {}", err);
     } else {
         (code, message) = fallback_status_code_and_message(err)
     }
@@ -64,6 +97,17 @@ pub(super) async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infal
     Ok(warp::reply::with_status(json, code))
 }
 
+#[cfg(feature = "graphql")]
+fn find_graphql_bad_request(err: &Rejection) -> Option<String> {
+    err.find::<async_graphql_warp::GraphQLBadRequest>()
+        .map(|bad_request| bad_request.0.to_string())
+}
+
+#[cfg(not(feature = "graphql"))]
+fn find_graphql_bad_request(_err: &Rejection) -> Option<String> {
+    None
+}
+
 fn fallback_status_code_and_message(err: Rejection) -> (StatusCode, String) {
     let err_msg = format!(
         "Unexpected error in REST server - please file a bug report!\n{:?}",
@@ -90,6 +134,25 @@ fn status_code_and_err_message_for_read_error(err: &DatabaseReadError) -> (Statu
     }
 }
 
+fn status_code_and_err_message_for_api_key_rejection(
+    rejection: &ApiKeyRejection,
+) -> (StatusCode, String) {
+    match rejection {
+        ApiKeyRejection::Missing => (
+            StatusCode::UNAUTHORIZED,
+            format!(
+                "Missing API key, expected in the '{}' header or the '{}' query parameter",
+                API_KEY_HEADER, API_KEY_QUERY_PARAM
+            ),
+        ),
+        ApiKeyRejection::Unknown => (StatusCode::UNAUTHORIZED, "Unknown API key".to_string()),
+        ApiKeyRejection::RateLimited | ApiKeyRejection::TooManySubscriptions => (
+            StatusCode::TOO_MANY_REQUESTS,
+            "API key exceeded its request rate".to_string(),
+        ),
+    }
+}
+
 #[cfg(test)]
 async fn get_api_error_from_rejection(rejection: Rejection) -> ApiError {
     let response = handle_rejection(rejection)
@@ -138,6 +201,22 @@ async fn should_handle_not_found() {
     assert_eq!(api_error.message, "Query returned no results");
 }
 
+#[tokio::test]
+async fn should_handle_api_key_rejections() {
+    let rejection = reject::custom(ApiKeyRejected(ApiKeyRejection::Unknown));
+
+    let api_error = get_api_error_from_rejection(rejection).await;
+
+    assert_eq!(api_error.code, 401);
+    assert_eq!(api_error.message, "Unknown API key");
+
+    let rejection = reject::custom(ApiKeyRejected(ApiKeyRejection::RateLimited));
+
+    let api_error = get_api_error_from_rejection(rejection).await;
+
+    assert_eq!(api_error.code, 429);
+}
+
 #[tokio::test]
 async fn should_handle_serialisation_error() {
     let rejection = serde_json::from_str::<i32>("")
@@ -1,27 +1,89 @@
-use super::{errors::handle_rejection, handlers, openapi::build_open_api_filters};
+#[cfg(feature = "graphql")]
+use super::graphql::build_graphql_filters;
+use super::{
+    auth::{with_api_key, ApiKeyAuthenticator},
+    caching::with_etag,
+    chain_stats::ChainTipCache,
+    errors::handle_rejection,
+    handlers::{
+        self, BlockRangeQuery, ChainStatsQuery, ContractDeploysQuery, DeployBatchRequest,
+        DeployQuery, DeployWaitQuery, EraRangeQuery, EventLogQuery, FailedDeploysQuery,
+        LatestBlocksQuery, PaginationQuery, TransfersQuery, ValidatorSignaturesQuery,
+        DEFAULT_MAX_CONCURRENT_DEPLOY_WAITS, DEFAULT_MAX_DEPLOYS_IN_BATCH,
+    },
+    json_rpc::handle_json_rpc_request,
+    openapi::build_open_api_filters,
+};
 use crate::{
-    types::database::DatabaseReader,
+    types::{config::RestServerConfig, database::DatabaseReader},
     utils::{root_filter, InvalidPath},
 };
-use std::convert::Infallible;
+use std::{convert::Infallible, sync::Arc};
+use tokio::sync::Semaphore;
 use warp::Filter;
 
-/// Helper function to specify available filters.
-/// Input: the database with data to be filtered.
+/// Upper bound of the size of a JSON-RPC request body, which only carries a block or deploy
+/// identifier.
+const MAX_JSON_RPC_REQUEST_BYTES: u64 = 16 * 1024;
+/// Upper bound of the size of a batch request body per requested deploy hash: 64 hex characters
+/// with room for quotes, separators and whitespace.
+const MAX_BATCH_REQUEST_BYTES_PER_DEPLOY: u64 = 128;
+/// Upper bound of the size of a batch request body besides its deploy hashes.
+const MAX_BATCH_REQUEST_BASE_BYTES: u64 = 1024;
+
+/// Helper function to specify available filters. If API keys are configured, all requests must
+/// carry one of them.
+/// Input: the database with data to be filtered, the configuration of the REST server and the IP
+/// address and SSE port of every node the sidecar connects to.
 /// Return: the filtered data.
-pub(super) fn combined_filters<Db: DatabaseReader + Clone + Send + Sync>(
+pub(super) fn combined_filters<Db: DatabaseReader + Clone + Send + Sync + 'static>(
     db: Db,
+    config: &RestServerConfig,
+    nodes: Vec<(String, u16)>,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = Infallible> + Clone {
-    root_filter()
+    let max_deploys_in_batch = config
+        .max_deploys_in_batch
+        .unwrap_or(DEFAULT_MAX_DEPLOYS_IN_BATCH);
+    let deploy_wait_permits = Arc::new(Semaphore::new(
+        config
+            .max_concurrent_deploy_waits
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_DEPLOY_WAITS) as usize,
+    ));
+    let filters = root_filter()
         .or(root_and_invalid_path())
         .or(block_filters(db.clone()))
-        .or(deploy_filters(db.clone()))
+        .or(chain_filters(
+            db.clone(),
+            Arc::new(ChainTipCache::default()),
+        ))
+        .or(deploy_filters(
+            db.clone(),
+            max_deploys_in_batch,
+            deploy_wait_permits,
+        ))
+        .or(transfers_by_account(db.clone()))
+        .or(deploys_by_contract(db.clone()))
+        .or(event_log(db.clone()))
+        .or(node_filters(db.clone(), nodes))
+        .or(search(db.clone()))
         .or(step_by_era(db.clone()))
+        .or(era_summary(db.clone()))
         .or(faults_by_public_key(db.clone()))
         .or(faults_by_era(db.clone()))
-        .or(finality_signatures_by_block(db))
-        .or(build_open_api_filters())
-        .recover(handle_rejection)
+        .or(validator_filters(db.clone()))
+        .or(finality_signatures_by_block(db.clone()))
+        .or(json_rpc(db.clone()))
+        .or(build_open_api_filters());
+    #[cfg(feature = "graphql")]
+    let filters = filters.or(build_graphql_filters(db));
+    let authenticator = ApiKeyAuthenticator::new(&config.api_keys);
+    let authenticated = authenticator.is_some();
+    with_etag(
+        with_api_key(authenticator)
+            .and(filters)
+            .recover(handle_rejection),
+        authenticated,
+    )
 }
 
 /// Handle the case where an invalid path was provided.
@@ -43,19 +105,260 @@ fn block_filters<Db: DatabaseReader + Clone + Send + Sync>(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     latest_block(db.clone())
         .or(block_by_hash(db.clone()))
-        .or(block_by_height(db))
+        .or(block_by_height(db.clone()))
+        .or(blocks_by_height_range(db.clone()))
+        .or(deploys_by_block(db.clone()))
+        .or(block_finality(db))
+}
+
+/// Helper function to specify available filters for the blocks at the tip of the chain.
+/// Input: the database with data to be filtered and the cache of the latest blocks.
+/// Return: the filtered data.
+fn chain_filters<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+    cache: Arc<ChainTipCache>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    latest_blocks(db.clone(), cache.clone()).or(chain_stats(db, cache))
+}
+
+/// Return the compact headers of the latest blocks, highest first. The headers are cached until
+/// a new block is stored.
+/// Input: the database with data to be filtered and the cache of the latest blocks.
+/// Return: headers of the latest blocks.
+/// Path URL: blocks/latest?count=<count>
+/// Example: curl "http://127.0.0.1:18888/blocks/latest?count=20"
+#[utoipa::path(
+    get,
+    path = "/blocks/latest",
+    params(LatestBlocksQuery),
+    responses(
+        (status = 200, description = "headers of the latest blocks ordered by height descending", body = [BlockHeaderSummary])
+    )
+)]
+fn latest_blocks<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+    cache: Arc<ChainTipCache>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("blocks" / "latest")
+        .and(warp::get())
+        .and(warp::query::<LatestBlocksQuery>())
+        .and(with_db(db))
+        .and(with_chain_tip_cache(cache))
+        .and_then(handlers::get_latest_blocks)
+}
+
+/// Return statistics of the latest blocks: the distribution of the intervals between them, the
+/// number of deploys and transfers they include and the number of blocks proposed by each
+/// validator. The statistics are cached until a new block is stored.
+/// Input: the database with data to be filtered and the cache of the latest blocks.
+/// Return: statistics of the latest blocks.
+/// Path URL: chain/stats?window=<count>
+/// Example: curl "http://127.0.0.1:18888/chain/stats?window=500"
+#[utoipa::path(
+    get,
+    path = "/chain/stats",
+    params(ChainStatsQuery),
+    responses(
+        (status = 200, description = "statistics of the latest blocks", body = ChainStats)
+    )
+)]
+fn chain_stats<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+    cache: Arc<ChainTipCache>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("chain" / "stats")
+        .and(warp::get())
+        .and(warp::query::<ChainStatsQuery>())
+        .and(with_db(db))
+        .and(with_chain_tip_cache(cache))
+        .and_then(handlers::get_chain_stats)
 }
 
 /// Helper function to specify available filters for deploy information.
-/// Input: the database with data to be filtered.
+/// Input: the database with data to be filtered, the maximum size of deploy batches and the permits
+/// of the requests waiting for a deploy.
 /// Return: the filtered data.
 fn deploy_filters<Db: DatabaseReader + Clone + Send + Sync>(
     db: Db,
+    max_deploys_in_batch: u32,
+    deploy_wait_permits: Arc<Semaphore>,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     deploy_by_hash(db.clone())
+        .or(deploy_wait(db.clone(), deploy_wait_permits))
         .or(deploy_accepted_by_hash(db.clone()))
         .or(deploy_processed_by_hash(db.clone()))
-        .or(deploy_expired_by_hash(db))
+        .or(deploy_expired_by_hash(db.clone()))
+        .or(deploys_by_account(db.clone()))
+        .or(failed_deploys(db.clone()))
+        .or(deploy_transfers(db.clone()))
+        .or(deploys_batch(db, max_deploys_in_batch))
+}
+
+/// Return a page of the native transfers sent or received by the given account, newest first,
+/// optionally only those executed in a block within a height range.
+/// The `next_cursor` of the response should be passed as `cursor` to fetch the following page.
+/// Input: the database with data to be filtered.
+/// Return: transfers of the account together with the cursor of the next page.
+/// Path URL: transfers?account=<account-hash>&from_height=<height>&to_height=<height>&limit=<count>&cursor=<cursor>
+/// Example: curl "http://127.0.0.1:18888/transfers?account=eb1dd0668899cf6b35cf99f5d4a7d3ea05acf352f75d14075982e0aebc099776&from_height=630100"
+#[utoipa::path(
+    get,
+    path = "/transfers",
+    params(TransfersQuery),
+    responses(
+        (status = 200, description = "page of transfers of the account, newest first", body = TransfersPage)
+    )
+)]
+fn transfers_by_account<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("transfers")
+        .and(warp::get())
+        .and(warp::query::<TransfersQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_transfers_by_account)
+}
+
+/// Return a page of the deploys whose session code called the given contract, newest first,
+/// optionally only those calling a specific entry point. The hash may be either the hash of the
+/// contract or the hash of its package.
+/// The `next_cursor` of the response should be passed as `cursor` to fetch the following page.
+/// Input: the database with data to be filtered.
+/// Return: deploys calling the contract, with their status, together with the cursor of the next page.
+/// Path URL: contracts/<contract-hash>/deploys?entry_point=<name>&limit=<count>&cursor=<cursor>
+/// Example: curl "http://127.0.0.1:18888/contracts/ccb576d6ce6dec84a551e48f0d0b7af89ddba44c7390b690036257a04a3ae9ea/deploys?entry_point=transfer"
+#[utoipa::path(
+    get,
+    path = "/contracts/{contract_hash}/deploys",
+    params(
+        ("contract_hash" = String, Path, description = "Hex-encoded hash of the contract or of its package"),
+        ContractDeploysQuery
+    ),
+    responses(
+        (status = 200, description = "page of deploys calling the contract, newest first", body = ContractDeploysPage)
+    )
+)]
+fn deploys_by_contract<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("contracts" / String / "deploys")
+        .and(warp::get())
+        .and(warp::query::<ContractDeploysQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_deploys_by_contract)
+}
+
+/// Return a page of the events received from the nodes, oldest first, optionally only those of a
+/// given type, delivered by a given node or stored within a time range. Every event is returned
+/// with its payload as stored.
+/// The `next_cursor` of the response should be passed as `cursor` to fetch the following page.
+/// Input: the database with data to be filtered.
+/// Return: event log entries together with the cursor of the next page.
+/// Path URL: events/log?type=<event-type>&source=<address>&since=<time>&until=<time>&limit=<count>&cursor=<cursor>
+/// Example: curl "http://127.0.0.1:18888/events/log?type=BlockAdded&source=127.0.0.1&since=2023-03-01T00:00:00Z"
+#[utoipa::path(
+    get,
+    path = "/events/log",
+    params(EventLogQuery),
+    responses(
+        (status = 200, description = "page of event log entries, oldest first", body = EventLogPage)
+    )
+)]
+fn event_log<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("events" / "log")
+        .and(warp::get())
+        .and(warp::query::<EventLogQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_event_log)
+}
+
+/// Return what a hex string identifies among the stored blocks, deploys, validators and eras,
+/// with the paths of the REST resources describing each match.
+/// Input: the database with data to be filtered.
+/// Return: the matches of the searched string, which are empty if nothing matched.
+/// Path URL: search/<hex-string>
+/// Example: curl http://127.0.0.1:18888/search/c6fd8c4a5ae5bb3e0dfe1ff31a60ab8ec3be2cbeeb44bc0bba2d61ffb0c5bb5e
+#[utoipa::path(
+    get,
+    path = "/search/{query}",
+    params(
+        ("query" = String, Path, description = "Hex-encoded block hash, deploy hash or public key, or era id")
+    ),
+    responses(
+        (status = 200, description = "everything the searched string identifies", body = SearchResult)
+    )
+)]
+fn search<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("search" / String)
+        .and(warp::get())
+        .and(with_db(db))
+        .and_then(handlers::search)
+}
+
+/// Helper function to specify available filters for node information.
+/// Input: the database with data to be filtered and the nodes the sidecar connects to.
+/// Return: the filtered data.
+fn node_filters<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+    nodes: Vec<(String, u16)>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    nodes_list(db.clone(), nodes.clone()).or(node_by_address(db, nodes))
+}
+
+/// Return the state of the connection to every node the sidecar connects to, together with what
+/// the sidecar stored about the events the node delivered.
+/// Input: the database with data to be filtered and the nodes the sidecar connects to.
+/// Return: the reports of the nodes.
+/// Path URL: nodes
+/// Example: curl http://127.0.0.1:18888/nodes
+#[utoipa::path(
+    get,
+    path = "/nodes",
+    responses(
+        (status = 200, description = "reports of all the nodes the sidecar connects to", body = [NodeReport])
+    )
+)]
+fn nodes_list<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+    nodes: Vec<(String, u16)>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("nodes")
+        .and(warp::get())
+        .and(with_nodes(nodes))
+        .and(with_db(db))
+        .and_then(handlers::get_nodes)
+}
+
+/// Return the state of the connection to a node, together with what the sidecar stored about the
+/// events the node delivered.
+/// Input: the database with data to be filtered and the nodes the sidecar connects to.
+/// Return: the report of the node.
+/// Path URL: nodes/<ip-address>:<sse-port>
+/// Example: curl http://127.0.0.1:18888/nodes/127.0.0.1:18101
+#[utoipa::path(
+    get,
+    path = "/nodes/{address}",
+    params(
+        ("address" = String, Path, description = "IP address and SSE port of the node, e.g. `127.0.0.1:18101`")
+    ),
+    responses(
+        (status = 200, description = "report of the node", body = NodeReport),
+        (status = 404, description = "the sidecar doesn't connect to the node")
+    )
+)]
+fn node_by_address<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+    nodes: Vec<(String, u16)>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("nodes" / String)
+        .and(warp::get())
+        .and(with_nodes(nodes))
+        .and(with_db(db))
+        .and_then(handlers::get_node_by_address)
 }
 
 /// Return information about the last block added to the linear chain.
@@ -127,17 +430,149 @@ fn block_by_height<Db: DatabaseReader + Clone + Send + Sync>(
         .and_then(handlers::get_block_by_height)
 }
 
+/// Return a page of the stored blocks within a height range which a validator was expected to sign,
+/// i.e. the blocks of the eras it had a weight in, each flagged with whether the validator signed
+/// it, ordered by block height ascending.
+/// The `next_cursor` of the response should be passed as `cursor` to fetch the following page.
+/// Input: the database with data to be filtered.
+/// Return: signed and missed blocks of the validator together with the cursor of the next page.
+/// Path URL: validators/<public-key>/signatures?from_height=<height>&to_height=<height>&limit=<count>&cursor=<cursor>
+/// Example: curl "http://127.0.0.1:18888/validators/01a601840126a0363a6048bfcbb0492ab5a313a1a19dc4c695650d8f3b51302703/signatures?from_height=630100&limit=20"
+#[utoipa::path(
+    get,
+    path = "/validators/{public_key}/signatures",
+    params(
+        ("public_key" = String, Path, description = "Hex encoded validator's public key"),
+        ValidatorSignaturesQuery
+    ),
+    responses(
+        (status = 200, description = "page of signed and missed blocks of the validator ordered by block height", body = ValidatorSignaturesPage)
+    )
+)]
+fn validator_signatures<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("validators" / String / "signatures")
+        .and(warp::get())
+        .and(warp::query::<ValidatorSignaturesQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_validator_signatures)
+}
+
+/// Return, for every era in which a validator had a weight, how many of the stored blocks of the
+/// era it signed, lowest era first.
+/// Input: the database with data to be filtered.
+/// Return: participation of the validator within the era range.
+/// Path URL: validators/<public-key>/participation?from_era=<era-id>&to_era=<era-id>
+/// Example: curl "http://127.0.0.1:18888/validators/01a601840126a0363a6048bfcbb0492ab5a313a1a19dc4c695650d8f3b51302703/participation?from_era=2300"
+#[utoipa::path(
+    get,
+    path = "/validators/{public_key}/participation",
+    params(
+        ("public_key" = String, Path, description = "Hex encoded validator's public key"),
+        EraRangeQuery
+    ),
+    responses(
+        (status = 200, description = "participation of the validator ordered by era", body = [ValidatorEraParticipation])
+    )
+)]
+fn validator_participation<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("validators" / String / "participation")
+        .and(warp::get())
+        .and(warp::query::<EraRangeQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_validator_participation)
+}
+
+/// Return a page of blocks within a height range, ordered by height ascending.
+/// The `next_cursor` of the response should be passed as `cursor` to fetch the following page.
+/// Input: the database with data to be filtered.
+/// Return: blocks within the range together with the cursor of the next page.
+/// Path URL: blocks?from_height=<height>&to_height=<height>&limit=<count>&cursor=<cursor>
+/// Example: curl "http://127.0.0.1:18888/blocks?from_height=630100&to_height=630151&limit=20"
+#[utoipa::path(
+    get,
+    path = "/blocks",
+    params(BlockRangeQuery),
+    responses(
+        (status = 200, description = "page of stored blocks ordered by height", body = BlocksPage)
+    )
+)]
+fn blocks_by_height_range<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("blocks")
+        .and(warp::get())
+        .and(warp::query::<BlockRangeQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_blocks_by_height_range)
+}
+
+/// Return aggregates of the deploys processed in a block, given its block hash or height.
+/// Hashes listed in the block body which weren't stored as processed yet are reported separately.
+/// Input: the database with data to be filtered.
+/// Return: deploy aggregates of the block and the hashes of its missing deploys.
+/// Path URL: block/<block-hash-or-height>/deploys
+/// Example: curl http://127.0.0.1:18888/block/630151/deploys
+#[utoipa::path(
+    get,
+    path = "/block/{hash_or_height}/deploys",
+    params(
+        ("hash_or_height" = String, Path, description = "Hex encoded hash or height of the block")
+    ),
+    responses(
+        (status = 200, description = "aggregates of the deploys processed in the block", body = BlockDeploys)
+    )
+)]
+fn deploys_by_block<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("block" / String / "deploys")
+        .and(warp::get())
+        .and(with_db(db))
+        .and_then(handlers::get_deploys_by_block)
+}
+
+/// Return the finality of a block: the weight of the validators which signed it, compared to the
+/// total weight of the validators of its era as announced by the preceding switch block.
+/// Input: the database with data to be filtered.
+/// Return: signed and total weight, the validators which haven't signed and whether the block is finalized.
+/// Path URL: block/<block-hash>/finality
+/// Example: curl http://127.0.0.1:18888/block/c0292d8408e9d83d1aaceadfbeb25dc38cda36bcb91c3d403a0deb5eb0fc2a70/finality
+#[utoipa::path(
+    get,
+    path = "/block/{block_hash}/finality",
+    params(
+        ("block_hash" = String, Path, description = "Hex encoded block hash of requested block finality")
+    ),
+    responses(
+        (status = 200, description = "finality of the block", body = BlockFinality)
+    )
+)]
+fn block_finality<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("block" / String / "finality")
+        .and(warp::get())
+        .and(with_db(db))
+        .and_then(handlers::get_block_finality)
+}
+
 /// Return an aggregate of the different states for the given deploy. This is a synthetic JSON not emitted by the node.
 /// The output differs depending on the deploy's status, which changes over time as the deploy goes through its lifecycle.
+/// With `timeline=true` the aggregate also tells when and from which node each event was received.
 /// Input: the database with data to be filtered.
 /// Return: data about the deploy specified.
-/// Path URL: deploy/<deploy-hash>
-/// Example: curl http://127.0.0.1:18888/deploy/f01544d37354c5f9b2c4956826d32f8e44198f94fb6752e87f422fe3071ab58a
+/// Path URL: deploy/<deploy-hash>?timeline=<bool>
+/// Example: curl "http://127.0.0.1:18888/deploy/f01544d37354c5f9b2c4956826d32f8e44198f94fb6752e87f422fe3071ab58a?timeline=true"
 #[utoipa::path(
     get,
     path = "/deploy/{deploy_hash}",
     params(
-        ("deploy_hash" = String, Path, description = "Base64 encoded deploy hash of requested deploy")
+        ("deploy_hash" = String, Path, description = "Base64 encoded deploy hash of requested deploy"),
+        DeployQuery
     ),
     responses(
         (status = 200, description = "fetch aggregate data for deploy events", body = DeployAggregate)
@@ -148,10 +583,43 @@ fn deploy_by_hash<Db: DatabaseReader + Clone + Send + Sync>(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("deploy" / String)
         .and(warp::get())
+        .and(warp::query::<DeployQuery>())
         .and(with_db(db))
         .and_then(handlers::get_deploy_by_hash)
 }
 
+/// Return the aggregate of the given deploy once it's processed or expired, waiting for the
+/// sidecar to receive the corresponding event for at most `timeout_secs`. Once the timeout
+/// elapses, the aggregate is returned as it is, or 404 if the deploy isn't stored. If too many
+/// requests are already waiting, 503 is returned right away.
+/// Input: the database with data to be filtered and the permits of the waiting requests.
+/// Return: data about the deploy specified.
+/// Path URL: deploy/<deploy-hash>/wait?timeout_secs=<seconds>
+/// Example: curl "http://127.0.0.1:18888/deploy/f01544d37354c5f9b2c4956826d32f8e44198f94fb6752e87f422fe3071ab58a/wait?timeout_secs=60"
+#[utoipa::path(
+    get,
+    path = "/deploy/{deploy_hash}/wait",
+    params(
+        ("deploy_hash" = String, Path, description = "Hex encoded deploy hash of the deploy"),
+        DeployWaitQuery
+    ),
+    responses(
+        (status = 200, description = "aggregate data of the deploy, processed or expired unless the timeout elapsed", body = DeployAggregate),
+        (status = 503, description = "too many requests are already waiting for deploys")
+    )
+)]
+fn deploy_wait<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+    wait_permits: Arc<Semaphore>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("deploy" / String / "wait")
+        .and(warp::get())
+        .and(warp::query::<DeployWaitQuery>())
+        .and(with_deploy_wait_permits(wait_permits))
+        .and(with_db(db))
+        .and_then(handlers::wait_for_deploy)
+}
+
 /// Return information about an accepted deploy given its deploy hash.
 /// Input: the database with data to be filtered.
 /// Return: data about the accepted deploy.
@@ -224,6 +692,112 @@ fn deploy_processed_by_hash<Db: DatabaseReader + Clone + Send + Sync>(
         .and_then(handlers::get_deploy_processed_by_hash)
 }
 
+/// Return a page of deploys sent by the given account, newest first, each with its current status.
+/// The `next_cursor` of the response should be passed as `cursor` to fetch the following page.
+/// Input: the database with data to be filtered.
+/// Return: hashes and statuses of the account's deploys together with the cursor of the next page.
+/// Path URL: deploys/by-account/<public-key>?limit=<count>&cursor=<cursor>
+/// Example: curl "http://127.0.0.1:18888/deploys/by-account/01a601840126a0363a6048bfcbb0492ab5a313a1a19dc4c695650d8f3b51302703?limit=20"
+#[utoipa::path(
+    get,
+    path = "/deploys/by-account/{public_key}",
+    params(
+        ("public_key" = String, Path, description = "Hex encoded public key of the account which sent the deploys"),
+        PaginationQuery
+    ),
+    responses(
+        (status = 200, description = "page of deploy summaries, newest first", body = DeploySummariesPage)
+    )
+)]
+fn deploys_by_account<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("deploys" / "by-account" / String)
+        .and(warp::get())
+        .and(warp::query::<PaginationQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_deploys_by_account)
+}
+
+/// Return a page of the deploys whose execution failed, newest first, optionally only those whose
+/// error message contains the given text and which were executed in a block within a height range.
+/// The `next_cursor` of the response should be passed as `cursor` to fetch the following page.
+/// Input: the database with data to be filtered.
+/// Return: failed deploys with their cost and error message together with the cursor of the next page.
+/// Path URL: deploys/failed?error_contains=<text>&from_height=<height>&to_height=<height>&limit=<count>&cursor=<cursor>
+/// Example: curl "http://127.0.0.1:18888/deploys/failed?error_contains=out%20of%20gas&from_height=630100"
+#[utoipa::path(
+    get,
+    path = "/deploys/failed",
+    params(FailedDeploysQuery),
+    responses(
+        (status = 200, description = "page of failed deploys, newest first", body = FailedDeploysPage)
+    )
+)]
+fn failed_deploys<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("deploys" / "failed")
+        .and(warp::get())
+        .and(warp::query::<FailedDeploysQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_failed_deploys)
+}
+
+/// Return the native transfers made by the given deploy, in the order they were made. The result
+/// is empty if the deploy made no transfer or wasn't processed yet.
+/// Input: the database with data to be filtered.
+/// Return: transfers of the deploy.
+/// Path URL: deploy/<deploy-hash>/transfers
+/// Example: curl http://127.0.0.1:18888/deploy/f01544d37354c5f9b2c4956826d32f8e44198f94fb6752e87f422fe3071ab58a/transfers
+#[utoipa::path(
+    get,
+    path = "/deploy/{deploy_hash}/transfers",
+    params(
+        ("deploy_hash" = String, Path, description = "Hex encoded deploy hash of the deploy")
+    ),
+    responses(
+        (status = 200, description = "transfers made by the deploy", body = [NativeTransfer])
+    )
+)]
+fn deploy_transfers<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("deploy" / String / "transfers")
+        .and(warp::get())
+        .and(with_db(db))
+        .and_then(handlers::get_transfers_by_deploy)
+}
+
+/// Return the aggregate data of multiple deploys given their deploy hashes, using a single query.
+/// Input: the database with data to be filtered and the maximum number of hashes in a request.
+/// Return: aggregates of the stored deploys, in the order of the request, and the hashes of the
+/// deploys which aren't stored.
+/// Path URL: deploys/batch
+/// Example: curl -X POST -H "Content-Type: application/json" -d '{"deploy_hashes":["f01544d37354c5f9b2c4956826d32f8e44198f94fb6752e87f422fe3071ab58a"]}' http://127.0.0.1:18888/deploys/batch
+#[utoipa::path(
+    post,
+    path = "/deploys/batch",
+    request_body = DeployBatchRequest,
+    responses(
+        (status = 200, description = "aggregate data of the requested deploys", body = DeployAggregatesBatch)
+    )
+)]
+fn deploys_batch<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+    max_deploys_in_batch: u32,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let max_request_bytes = MAX_BATCH_REQUEST_BASE_BYTES
+        + u64::from(max_deploys_in_batch) * MAX_BATCH_REQUEST_BYTES_PER_DEPLOY;
+    warp::path!("deploys" / "batch")
+        .and(warp::post())
+        .and(warp::body::content_length_limit(max_request_bytes))
+        .and(warp::body::json::<DeployBatchRequest>())
+        .and(warp::any().map(move || max_deploys_in_batch))
+        .and(with_db(db))
+        .and_then(handlers::get_deploys_batch)
+}
+
 #[utoipa::path(
     get,
     path = "/faults/{public_key}",
@@ -320,6 +894,135 @@ fn step_by_era<Db: DatabaseReader + Clone + Send + Sync>(
         .and_then(handlers::get_step_by_era)
 }
 
+/// Return a summary of an era: the heights and number of its stored blocks, its switch block
+/// and era report, its step and its faults.
+/// Input: the database with data to be filtered.
+/// Return: summary of the era specified.
+/// Path URL: era/<era-id>
+/// Example: curl http://127.0.0.1:18888/era/2304
+#[utoipa::path(
+    get,
+    path = "/era/{era_id}",
+    params(
+        ("era_id" = u64, Path, description = "Id of the era to summarize")
+    ),
+    responses(
+        (status = 200, description = "summary of the era", body = EraSummary)
+    )
+)]
+fn era_summary<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("era" / u64)
+        .and(warp::get())
+        .and(with_db(db))
+        .and_then(handlers::get_era_summary)
+}
+
+/// Helper function to specify available filters for validator history.
+/// Input: the database with data to be filtered.
+/// Return: the filtered data.
+fn validator_filters<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    validator_rewards(db.clone())
+        .or(validator_weights(db.clone()))
+        .or(validator_signatures(db.clone()))
+        .or(validator_participation(db))
+}
+
+/// Return the rewards paid to a validator per era, as reported by the switch blocks, lowest era
+/// first.
+/// Input: the database with data to be filtered.
+/// Return: rewards of the validator within the era range.
+/// Path URL: validators/<public-key>/rewards?from_era=<era-id>&to_era=<era-id>
+/// Example: curl "http://127.0.0.1:18888/validators/01a601840126a0363a6048bfcbb0492ab5a313a1a19dc4c695650d8f3b51302703/rewards?from_era=2300&to_era=2304"
+#[utoipa::path(
+    get,
+    path = "/validators/{public_key}/rewards",
+    params(
+        ("public_key" = String, Path, description = "Hex encoded validator's public key"),
+        EraRangeQuery
+    ),
+    responses(
+        (status = 200, description = "rewards of the validator ordered by era", body = [ValidatorEraReward])
+    )
+)]
+fn validator_rewards<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("validators" / String / "rewards")
+        .and(warp::get())
+        .and(warp::query::<EraRangeQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_validator_rewards)
+}
+
+/// Return the weights of a validator per era, as announced by the switch blocks of the preceding
+/// eras, lowest era first.
+/// Input: the database with data to be filtered.
+/// Return: weights of the validator within the era range.
+/// Path URL: validators/<public-key>/weights?from_era=<era-id>&to_era=<era-id>
+/// Example: curl "http://127.0.0.1:18888/validators/01a601840126a0363a6048bfcbb0492ab5a313a1a19dc4c695650d8f3b51302703/weights?from_era=2300"
+#[utoipa::path(
+    get,
+    path = "/validators/{public_key}/weights",
+    params(
+        ("public_key" = String, Path, description = "Hex encoded validator's public key"),
+        EraRangeQuery
+    ),
+    responses(
+        (status = 200, description = "weights of the validator ordered by era", body = [ValidatorEraWeight])
+    )
+)]
+fn validator_weights<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("validators" / String / "weights")
+        .and(warp::get())
+        .and(warp::query::<EraRangeQuery>())
+        .and(with_db(db))
+        .and_then(handlers::get_validator_weights)
+}
+
+/// Answer a JSON-RPC 2.0 request from the stored events, with the same result shape as the node.
+/// Supported methods: chain_get_block and info_get_deploy.
+/// Input: the database with data to be filtered.
+/// Return: a JSON-RPC response holding either the result or an error.
+/// Path URL: rpc
+/// Example: curl -X POST -H "Content-Type: application/json" -d '{"jsonrpc":"2.0","id":1,"method":"chain_get_block","params":{"block_identifier":{"Height":1278485}}}' http://127.0.0.1:18888/rpc
+fn json_rpc<Db: DatabaseReader + Clone + Send + Sync>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("rpc")
+        .and(warp::post())
+        .and(warp::body::content_length_limit(MAX_JSON_RPC_REQUEST_BYTES))
+        .and(warp::body::bytes())
+        .and(with_db(db))
+        .and_then(handle_json_rpc_request)
+}
+
+/// Helper function to pass the nodes the sidecar connects to
+fn with_nodes(
+    nodes: Vec<(String, u16)>,
+) -> impl Filter<Extract = (Vec<(String, u16)>,), Error = Infallible> + Clone {
+    warp::any().map(move || nodes.clone())
+}
+
+/// Helper function to pass the permits of the requests waiting for a deploy
+fn with_deploy_wait_permits(
+    permits: Arc<Semaphore>,
+) -> impl Filter<Extract = (Arc<Semaphore>,), Error = Infallible> + Clone {
+    warp::any().map(move || permits.clone())
+}
+
+/// Helper function to pass the cache of the chain tip statistics
+fn with_chain_tip_cache(
+    cache: Arc<ChainTipCache>,
+) -> impl Filter<Extract = (Arc<ChainTipCache>,), Error = Infallible> + Clone {
+    warp::any().map(move || cache.clone())
+}
+
 /// Helper function to extract data from a database
 fn with_db<Db: DatabaseReader + Clone + Send>(
     db: Db,
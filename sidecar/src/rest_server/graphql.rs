@@ -0,0 +1,579 @@
+#[cfg(test)]
+mod tests;
+
+use super::handlers::{
+    lowest_height_of_page, DEFAULT_PAGE_LIMIT, HASH_REGEX, MAX_PAGE_LIMIT, PUBLIC_KEY_REGEX,
+};
+use crate::types::{
+    database::{self, BlockDeploys, DatabaseReadError, DatabaseReader, DeployAggregate, Page},
+    sse_events::{self, BlockAdded},
+};
+use async_graphql::{
+    http::GraphiQLSource, Context, EmptyMutation, EmptySubscription, Json, Object, OutputType,
+    Result as GraphQLResult, Schema, SimpleObject,
+};
+use async_graphql_warp::GraphQLResponse;
+use casper_event_types::FinalitySignature as FinSig;
+use casper_types::AsymmetricType;
+use serde::Serialize;
+use std::{convert::Infallible, sync::Arc};
+use tokio::sync::OnceCell;
+use warp::Filter;
+
+/// Maximum nesting of the fields of a query, guards the database against deeply nested relations.
+const MAX_QUERY_DEPTH: usize = 10;
+/// Maximum complexity of a query, guards the database against queries fanning out over many
+/// lists. Every field counts 1, multiplied by the maximum length of the lists it's nested in.
+const MAX_QUERY_COMPLEXITY: usize = 10_000;
+/// Number of items returned by the lists nested in blocks and steps if the client doesn't specify
+/// a `limit`.
+const DEFAULT_NESTED_LIST_LIMIT: u32 = 20;
+/// Upper bound for the `limit` of the lists nested in blocks and steps.
+const MAX_NESTED_LIST_LIMIT: u32 = 500;
+
+pub(super) type SidecarSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+type SharedDatabaseReader = Arc<dyn DatabaseReader + Send + Sync>;
+
+/// Builds the GraphQL schema whose resolvers read from the given database.
+pub(super) fn build_schema<Db: DatabaseReader + Send + Sync + 'static>(db: Db) -> SidecarSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(Arc::new(db) as SharedDatabaseReader)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .finish()
+}
+
+/// Helper function to specify the GraphQL filters.
+/// Input: the database with data to be queried.
+/// Return: the GraphQL endpoint and the GraphiQL page.
+/// Path URL: graphql (GET and POST), graphiql
+/// Example: curl -X POST -H "Content-Type: application/json" -d '{"query":"{ latestBlock { hash height } }"}' http://127.0.0.1:18888/graphql
+pub(super) fn build_graphql_filters<Db: DatabaseReader + Clone + Send + Sync + 'static>(
+    db: Db,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let graphql = warp::path!("graphql")
+        .and(async_graphql_warp::graphql(build_schema(db)))
+        .and_then(
+            |(schema, request): (SidecarSchema, async_graphql::Request)| async move {
+                Ok::<_, Infallible>(GraphQLResponse::from(schema.execute(request).await))
+            },
+        );
+    let graphiql = warp::path!("graphiql")
+        .and(warp::get())
+        .map(|| warp::reply::html(GraphiQLSource::build().endpoint("/graphql").finish()));
+    graphql.or(graphiql)
+}
+
+/// Root of the GraphQL queries.
+pub(super) struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// The block with the greatest height.
+    async fn latest_block(&self, ctx: &Context<'_>) -> GraphQLResult<Option<Block>> {
+        let block = reader(ctx).get_latest_block().await;
+        optional(block).map(|block| block.map(Block::from))
+    }
+
+    /// The block with the given hex-encoded hash or height, exactly one of them has to be given.
+    async fn block(
+        &self,
+        ctx: &Context<'_>,
+        hash: Option<String>,
+        height: Option<u64>,
+    ) -> GraphQLResult<Option<Block>> {
+        let block = match (hash, height) {
+            (Some(hash), None) => {
+                check_hash_is_correct_format(&hash)?;
+                reader(ctx).get_block_by_hash(&hash.to_lowercase()).await
+            }
+            (None, Some(height)) => reader(ctx).get_block_by_height(height).await,
+            _ => return Err("Exactly one of hash and height has to be provided".into()),
+        };
+        optional(block).map(|block| block.map(Block::from))
+    }
+
+    /// Blocks with heights between `fromHeight` and `toHeight` (both inclusive), lowest first.
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn blocks(
+        &self,
+        ctx: &Context<'_>,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+        limit: Option<u32>,
+        cursor: Option<u64>,
+    ) -> GraphQLResult<GraphQLPage<Block>> {
+        let limit = check_limit_is_in_range(limit)?;
+        let from_height = from_height.unwrap_or(0);
+        if let Some(to_height) = to_height {
+            if from_height > to_height {
+                return Err(format!(
+                    "fromHeight ({}) can't be greater than toHeight ({})",
+                    from_height, to_height
+                )
+                .into());
+            }
+        }
+        let page = match lowest_height_of_page(from_height, cursor) {
+            Some(from_height) => {
+                let blocks = reader(ctx)
+                    .get_blocks_by_height_range(from_height, to_height, limit + 1)
+                    .await;
+                Page::from_overfetched(list(blocks)?, limit, BlockAdded::get_height)
+            }
+            None => Page::empty(),
+        };
+        Ok(page.map(Block::from).into())
+    }
+
+    /// The aggregate of the stored events of the deploy with the given hex-encoded hash.
+    async fn deploy(&self, ctx: &Context<'_>, hash: String) -> GraphQLResult<Option<Deploy>> {
+        check_hash_is_correct_format(&hash)?;
+        let deploy = reader(ctx)
+            .get_deploy_aggregate_by_hash(&hash.to_lowercase())
+            .await;
+        optional(deploy).map(|deploy| deploy.map(Deploy))
+    }
+
+    /// Deploys sent from the account with the given hex-encoded public key, most recent first.
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn deploys_by_account(
+        &self,
+        ctx: &Context<'_>,
+        public_key: String,
+        limit: Option<u32>,
+        cursor: Option<u64>,
+    ) -> GraphQLResult<GraphQLPage<DeploySummary>> {
+        check_public_key_is_correct_format(&public_key)?;
+        let limit = check_limit_is_in_range(limit)?;
+        let page = reader(ctx)
+            .get_deploy_summaries_by_account(&public_key.to_lowercase(), cursor, limit)
+            .await
+            .map_err(to_graphql_error)?;
+        Ok(page.map(DeploySummary).into())
+    }
+
+    /// The step of the given era.
+    async fn step(&self, ctx: &Context<'_>, era_id: u64) -> GraphQLResult<Option<Step>> {
+        let step = reader(ctx).get_step_by_era(era_id).await;
+        optional(step).map(|step| step.map(Step))
+    }
+
+    /// Faults reported in the given era.
+    #[graphql(complexity = "nested_list_complexity(limit, child_complexity)")]
+    async fn faults_by_era(
+        &self,
+        ctx: &Context<'_>,
+        era_id: u64,
+        limit: Option<u32>,
+    ) -> GraphQLResult<Vec<Fault>> {
+        faults_by_era(ctx, era_id, limit).await
+    }
+
+    /// Faults of the validator with the given hex-encoded public key.
+    #[graphql(complexity = "nested_list_complexity(limit, child_complexity)")]
+    async fn faults_by_public_key(
+        &self,
+        ctx: &Context<'_>,
+        public_key: String,
+        limit: Option<u32>,
+    ) -> GraphQLResult<Vec<Fault>> {
+        check_public_key_is_correct_format(&public_key)?;
+        let limit = check_nested_list_limit_is_in_range(limit)?;
+        let faults = reader(ctx)
+            .get_faults_by_public_key(&public_key.to_lowercase())
+            .await;
+        Ok(truncate(list(faults)?, limit)
+            .into_iter()
+            .map(Fault)
+            .collect())
+    }
+
+    /// Finality signatures of the block with the given hex-encoded hash.
+    #[graphql(complexity = "nested_list_complexity(limit, child_complexity)")]
+    async fn finality_signatures(
+        &self,
+        ctx: &Context<'_>,
+        block_hash: String,
+        limit: Option<u32>,
+    ) -> GraphQLResult<Vec<FinalitySignature>> {
+        check_hash_is_correct_format(&block_hash)?;
+        finality_signatures_by_block(ctx, &block_hash.to_lowercase(), limit).await
+    }
+}
+
+/// A block added to the linear chain.
+pub(super) struct Block {
+    block_added: BlockAdded,
+    /// Loaded on first use, so that `deploys` and `missingDeployHashes` share one query.
+    block_deploys: OnceCell<BlockDeploys>,
+}
+
+impl From<BlockAdded> for Block {
+    fn from(block_added: BlockAdded) -> Self {
+        Block {
+            block_added,
+            block_deploys: OnceCell::new(),
+        }
+    }
+}
+
+#[Object]
+impl Block {
+    async fn hash(&self) -> String {
+        self.block_added.hex_encoded_hash()
+    }
+
+    async fn height(&self) -> u64 {
+        self.block_added.get_height()
+    }
+
+    async fn era_id(&self) -> u64 {
+        self.block_added.get_era_id()
+    }
+
+    /// Whether this is the last block of its era.
+    async fn is_switch_block(&self) -> bool {
+        self.block_added.era_end().is_some()
+    }
+
+    /// The complete BlockAdded event, as returned by the REST API.
+    async fn json(&self) -> GraphQLResult<Json<serde_json::Value>> {
+        to_json(&self.block_added)
+    }
+
+    /// Deploys processed in this block, in the order of the block body.
+    #[graphql(complexity = "nested_list_complexity(limit, child_complexity)")]
+    async fn deploys(&self, ctx: &Context<'_>, limit: Option<u32>) -> GraphQLResult<Vec<Deploy>> {
+        let limit = check_nested_list_limit_is_in_range(limit)?;
+        let block_deploys = self.block_deploys(ctx).await?;
+        Ok(truncate(block_deploys.deploys.clone(), limit)
+            .into_iter()
+            .map(Deploy)
+            .collect())
+    }
+
+    /// Hashes listed in the block body which haven't been stored as processed (yet).
+    async fn missing_deploy_hashes(&self, ctx: &Context<'_>) -> GraphQLResult<Vec<String>> {
+        let block_deploys = self.block_deploys(ctx).await?;
+        Ok(block_deploys.missing_deploy_hashes.clone())
+    }
+
+    #[graphql(complexity = "nested_list_complexity(limit, child_complexity)")]
+    async fn finality_signatures(
+        &self,
+        ctx: &Context<'_>,
+        limit: Option<u32>,
+    ) -> GraphQLResult<Vec<FinalitySignature>> {
+        finality_signatures_by_block(ctx, &self.block_added.hex_encoded_hash(), limit).await
+    }
+
+    /// Faults reported in the era of this block.
+    #[graphql(complexity = "nested_list_complexity(limit, child_complexity)")]
+    async fn faults(&self, ctx: &Context<'_>, limit: Option<u32>) -> GraphQLResult<Vec<Fault>> {
+        faults_by_era(ctx, self.block_added.get_era_id(), limit).await
+    }
+
+    /// The step ending the era of this block. Only provided for switch blocks.
+    async fn step(&self, ctx: &Context<'_>) -> GraphQLResult<Option<Step>> {
+        if self.block_added.era_end().is_none() {
+            return Ok(None);
+        }
+        let step = reader(ctx)
+            .get_step_by_era(self.block_added.get_era_id())
+            .await;
+        optional(step).map(|step| step.map(Step))
+    }
+}
+
+impl Block {
+    async fn block_deploys(&self, ctx: &Context<'_>) -> GraphQLResult<&BlockDeploys> {
+        self.block_deploys
+            .get_or_try_init(|| async move {
+                let aggregates = reader(ctx)
+                    .get_deploy_aggregates_by_block(&self.block_added.hex_encoded_hash())
+                    .await;
+                Ok(BlockDeploys::new(&self.block_added, list(aggregates)?))
+            })
+            .await
+    }
+}
+
+/// Aggregate of the stored events of a deploy.
+pub(super) struct Deploy(DeployAggregate);
+
+#[Object]
+impl Deploy {
+    async fn hash(&self) -> &str {
+        &self.0.deploy_hash
+    }
+
+    async fn status(&self) -> database::DeployStatus {
+        database::DeployStatus::from_flags(self.0.deploy_processed.is_some(), self.0.deploy_expired)
+    }
+
+    /// The DeployAccepted event, if stored.
+    async fn accepted(&self) -> GraphQLResult<Option<Json<serde_json::Value>>> {
+        self.0.deploy_accepted.as_ref().map(to_json).transpose()
+    }
+
+    /// The DeployProcessed event, if stored.
+    async fn processed(&self) -> GraphQLResult<Option<Json<serde_json::Value>>> {
+        self.0.deploy_processed.as_ref().map(to_json).transpose()
+    }
+
+    async fn expired(&self) -> bool {
+        self.0.deploy_expired
+    }
+
+    /// The block in which the deploy was processed, if both are stored.
+    async fn block(&self, ctx: &Context<'_>) -> GraphQLResult<Option<Block>> {
+        let block_hash = match &self.0.deploy_processed {
+            Some(deploy_processed) => deploy_processed.hex_encoded_block_hash(),
+            None => return Ok(None),
+        };
+        let block = reader(ctx).get_block_by_hash(&block_hash).await;
+        optional(block).map(|block| block.map(Block::from))
+    }
+}
+
+/// Hash and status of a deploy.
+pub(super) struct DeploySummary(database::DeploySummary);
+
+#[Object]
+impl DeploySummary {
+    async fn deploy_hash(&self) -> &str {
+        &self.0.deploy_hash
+    }
+
+    async fn status(&self) -> database::DeployStatus {
+        self.0.status
+    }
+
+    /// The aggregate of the stored events of the deploy.
+    async fn deploy(&self, ctx: &Context<'_>) -> GraphQLResult<Option<Deploy>> {
+        let deploy = reader(ctx)
+            .get_deploy_aggregate_by_hash(&self.0.deploy_hash)
+            .await;
+        optional(deploy).map(|deploy| deploy.map(Deploy))
+    }
+}
+
+/// Fault of a validator in an era.
+pub(super) struct Fault(sse_events::Fault);
+
+#[Object]
+impl Fault {
+    async fn era_id(&self) -> u64 {
+        self.0.era_id.value()
+    }
+
+    async fn public_key(&self) -> String {
+        self.0.public_key.to_hex()
+    }
+
+    async fn timestamp(&self) -> String {
+        self.0.timestamp.to_string()
+    }
+}
+
+/// Execution effects of the step ending an era.
+pub(super) struct Step(sse_events::Step);
+
+#[Object]
+impl Step {
+    async fn era_id(&self) -> u64 {
+        self.0.era_id.value()
+    }
+
+    /// The complete Step event, as returned by the REST API.
+    async fn json(&self) -> GraphQLResult<Json<serde_json::Value>> {
+        to_json(&self.0)
+    }
+
+    /// Faults reported in the era ended by this step.
+    #[graphql(complexity = "nested_list_complexity(limit, child_complexity)")]
+    async fn faults(&self, ctx: &Context<'_>, limit: Option<u32>) -> GraphQLResult<Vec<Fault>> {
+        faults_by_era(ctx, self.0.era_id.value(), limit).await
+    }
+}
+
+/// Signature of a validator over a block.
+pub(super) struct FinalitySignature(FinSig);
+
+#[Object]
+impl FinalitySignature {
+    async fn block_hash(&self) -> String {
+        hex::encode(self.0.block_hash().inner())
+    }
+
+    async fn era_id(&self) -> u64 {
+        self.0.era_id().value()
+    }
+
+    async fn public_key(&self) -> String {
+        self.0.public_key().to_hex()
+    }
+
+    async fn signature(&self) -> String {
+        self.0.signature().to_hex()
+    }
+
+    /// The signed block, if stored.
+    async fn block(&self, ctx: &Context<'_>) -> GraphQLResult<Option<Block>> {
+        let block_hash = hex::encode(self.0.block_hash().inner());
+        let block = reader(ctx).get_block_by_hash(&block_hash).await;
+        optional(block).map(|block| block.map(Block::from))
+    }
+}
+
+/// A single page of a paginated result set.
+#[derive(SimpleObject)]
+#[graphql(concrete(name = "BlockPage", params(Block)))]
+#[graphql(concrete(name = "DeploySummaryPage", params(DeploySummary)))]
+pub(super) struct GraphQLPage<T: OutputType> {
+    items: Vec<T>,
+    /// Value to pass as `cursor` to fetch the next page. Absent if this is the last page.
+    next_cursor: Option<u64>,
+}
+
+impl<T: OutputType> From<Page<T>> for GraphQLPage<T> {
+    fn from(page: Page<T>) -> Self {
+        GraphQLPage {
+            items: page.items,
+            next_cursor: page.next_cursor,
+        }
+    }
+}
+
+fn reader<'a>(ctx: &Context<'a>) -> &'a SharedDatabaseReader {
+    ctx.data_unchecked::<SharedDatabaseReader>()
+}
+
+async fn faults_by_era(
+    ctx: &Context<'_>,
+    era_id: u64,
+    limit: Option<u32>,
+) -> GraphQLResult<Vec<Fault>> {
+    let limit = check_nested_list_limit_is_in_range(limit)?;
+    let faults = reader(ctx).get_faults_by_era(era_id).await;
+    Ok(truncate(list(faults)?, limit)
+        .into_iter()
+        .map(Fault)
+        .collect())
+}
+
+async fn finality_signatures_by_block(
+    ctx: &Context<'_>,
+    block_hash: &str,
+    limit: Option<u32>,
+) -> GraphQLResult<Vec<FinalitySignature>> {
+    let limit = check_nested_list_limit_is_in_range(limit)?;
+    let signatures = reader(ctx)
+        .get_finality_signatures_by_block(block_hash)
+        .await;
+    Ok(truncate(list(signatures)?, limit)
+        .into_iter()
+        .map(FinalitySignature)
+        .collect())
+}
+
+/// Complexity of a page of at most `limit` items, each of `child_complexity`.
+fn page_complexity(limit: Option<u32>, child_complexity: usize) -> usize {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    (limit as usize).saturating_mul(child_complexity)
+}
+
+/// Complexity of a nested list of at most `limit` items, each of `child_complexity`.
+fn nested_list_complexity(limit: Option<u32>, child_complexity: usize) -> usize {
+    let limit = limit
+        .unwrap_or(DEFAULT_NESTED_LIST_LIMIT)
+        .min(MAX_NESTED_LIST_LIMIT);
+    (limit as usize).saturating_mul(child_complexity)
+}
+
+fn check_hash_is_correct_format(hash: &str) -> GraphQLResult<()> {
+    let hash_regex = regex::Regex::new(HASH_REGEX)?;
+    if !hash_regex.is_match(hash) {
+        return Err(format!(
+            "Expected hex-encoded hash (64 chars), received: {} (length: {})",
+            hash,
+            hash.len()
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn check_public_key_is_correct_format(public_key_hex: &str) -> GraphQLResult<()> {
+    let public_key_regex = regex::Regex::new(PUBLIC_KEY_REGEX)?;
+    if !public_key_regex.is_match(public_key_hex) {
+        return Err(format!(
+            "Expected hex-encoded public key (66/68 chars), received: {} (length: {})",
+            public_key_hex,
+            public_key_hex.len()
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn check_nested_list_limit_is_in_range(limit: Option<u32>) -> GraphQLResult<usize> {
+    let limit = limit.unwrap_or(DEFAULT_NESTED_LIST_LIMIT);
+    if limit == 0 || limit > MAX_NESTED_LIST_LIMIT {
+        return Err(format!(
+            "Expected limit between 1 and {}, received: {}",
+            MAX_NESTED_LIST_LIMIT, limit
+        )
+        .into());
+    }
+    Ok(limit as usize)
+}
+
+fn truncate<T>(mut items: Vec<T>, limit: usize) -> Vec<T> {
+    items.truncate(limit);
+    items
+}
+
+fn check_limit_is_in_range(limit: Option<u32>) -> GraphQLResult<u32> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+    if limit == 0 || limit > MAX_PAGE_LIMIT {
+        return Err(format!(
+            "Expected limit between 1 and {}, received: {}",
+            MAX_PAGE_LIMIT, limit
+        )
+        .into());
+    }
+    Ok(limit)
+}
+
+fn to_json<T: Serialize>(data: &T) -> GraphQLResult<Json<serde_json::Value>> {
+    Ok(Json(serde_json::to_value(data)?))
+}
+
+/// Missing records are represented as `null` in GraphQL rather than as errors.
+fn optional<T>(storage_result: Result<T, DatabaseReadError>) -> GraphQLResult<Option<T>> {
+    match storage_result {
+        Ok(data) => Ok(Some(data)),
+        Err(DatabaseReadError::NotFound) => Ok(None),
+        Err(err) => Err(to_graphql_error(err)),
+    }
+}
+
+/// Empty result sets are represented as empty lists in GraphQL rather than as errors.
+fn list<T>(storage_result: Result<Vec<T>, DatabaseReadError>) -> GraphQLResult<Vec<T>> {
+    optional(storage_result).map(Option::unwrap_or_default)
+}
+
+fn to_graphql_error(err: DatabaseReadError) -> async_graphql::Error {
+    match err {
+        DatabaseReadError::NotFound => "Query returned no results".into(),
+        DatabaseReadError::Serialisation(err) => {
+            format!("Error deserializing returned data: {}", err).into()
+        }
+        DatabaseReadError::Unhandled(err) => {
+            format!("Unhandled error occurred in storage: {}", err).into()
+        }
+    }
+}
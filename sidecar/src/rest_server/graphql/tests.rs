@@ -0,0 +1,305 @@
+use casper_types::{testing::TestRng, AsymmetricType, PublicKey, SecretKey};
+use http::StatusCode;
+use serde_json::{json, Value};
+use warp::test::request;
+
+use super::build_schema;
+use crate::{
+    rest_server::filters,
+    testing::fake_database::FakeDatabase,
+    types::{config::RestServerConfig, database::DatabaseWriter, sse_events::*},
+};
+
+async fn execute_query(database: FakeDatabase, query: &str) -> Value {
+    let response = build_schema(database).execute(query).await;
+
+    assert!(
+        response.errors.is_empty(),
+        "Unexpected errors: {:?}",
+        response.errors
+    );
+    response
+        .data
+        .into_json()
+        .expect("Error converting GraphQL response to JSON")
+}
+
+async fn execute_query_expecting_error(database: FakeDatabase, query: &str) {
+    let response = build_schema(database).execute(query).await;
+
+    assert!(!response.errors.is_empty());
+}
+
+#[tokio::test]
+async fn block_should_resolve_nested_deploys_and_finality_signatures() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let processed_deploy = DeployAccepted::random(&mut test_rng);
+    let missing_deploy = DeployAccepted::random(&mut test_rng);
+    let block_added = BlockAdded::random_with_deploy_hashes(
+        &mut test_rng,
+        vec![processed_deploy.deploy_hash(), missing_deploy.deploy_hash()],
+    );
+    let secret_key = SecretKey::random(&mut test_rng);
+    database
+        .save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+    database
+        .save_deploy_accepted(processed_deploy.clone(), 2, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_accepted");
+    database
+        .save_deploy_processed(
+            DeployProcessed::random_in_block(
+                &mut test_rng,
+                processed_deploy.deploy_hash(),
+                block_added.block_hash(),
+            ),
+            3,
+            "127.0.0.1".to_string(),
+        )
+        .await
+        .expect("Error saving deploy_processed");
+    database
+        .save_finality_signature(
+            FinalitySignature::signed_by(
+                block_added.block_hash(),
+                block_added.get_era_id(),
+                &secret_key,
+            ),
+            4,
+            "127.0.0.1".to_string(),
+        )
+        .await
+        .expect("Error saving finality_signature");
+
+    let query = format!(
+        r#"{{ block(hash: "{}") {{ height deploys {{ hash status block {{ hash }} }} missingDeployHashes finalitySignatures {{ publicKey }} }} }}"#,
+        block_added.hex_encoded_hash()
+    );
+    let data = execute_query(database, &query).await;
+
+    assert_eq!(
+        data,
+        json!({
+            "block": {
+                "height": block_added.get_height(),
+                "deploys": [{
+                    "hash": processed_deploy.hex_encoded_hash(),
+                    "status": "PROCESSED",
+                    "block": { "hash": block_added.hex_encoded_hash() },
+                }],
+                "missingDeployHashes": [missing_deploy.hex_encoded_hash()],
+                "finalitySignatures": [{
+                    "publicKey": PublicKey::from(&secret_key).to_hex(),
+                }],
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn blocks_should_be_paginated() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    for height in 10..15 {
+        database
+            .save_block_added(
+                BlockAdded::random_with_height(&mut test_rng, height),
+                height as u32,
+                "127.0.0.1".to_string(),
+            )
+            .await
+            .expect("Error saving block_added");
+    }
+
+    let data = execute_query(
+        database,
+        "{ blocks(fromHeight: 11, limit: 2, cursor: 11) { items { height } nextCursor } }",
+    )
+    .await;
+
+    assert_eq!(
+        data,
+        json!({
+            "blocks": {
+                "items": [{ "height": 12 }, { "height": 13 }],
+                "nextCursor": 13,
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn block_not_stored_should_resolve_to_null() {
+    let query = format!(r#"{{ block(hash: "{}") {{ hash }} }}"#, "0".repeat(64));
+
+    let data = execute_query(FakeDatabase::new(), &query).await;
+
+    assert_eq!(data, json!({ "block": null }));
+}
+
+#[tokio::test]
+async fn block_by_hash_and_height_should_fail() {
+    let query = format!(
+        r#"{{ block(hash: "{}", height: 1) {{ hash }} }}"#,
+        "0".repeat(64)
+    );
+
+    execute_query_expecting_error(FakeDatabase::new(), &query).await;
+}
+
+#[tokio::test]
+async fn blocks_with_invalid_limit_should_fail() {
+    execute_query_expecting_error(
+        FakeDatabase::new(),
+        "{ blocks(limit: 1001) { items { hash } } }",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn nested_list_with_invalid_limit_should_fail() {
+    execute_query_expecting_error(
+        FakeDatabase::new(),
+        "{ latestBlock { deploys(limit: 501) { hash } } }",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn query_exceeding_complexity_limit_should_fail() {
+    // Every one of the 100 blocks of the page could list 500 deploys.
+    execute_query_expecting_error(
+        FakeDatabase::new(),
+        "{ blocks { items { deploys(limit: 500) { hash } } } }",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn nested_deploys_of_blocks_with_default_limits_should_be_resolved() {
+    let mut test_rng = TestRng::new();
+    let database = FakeDatabase::new();
+    database
+        .save_block_added(
+            BlockAdded::random_with_height(&mut test_rng, 10),
+            1,
+            "127.0.0.1".to_string(),
+        )
+        .await
+        .expect("Error saving block_added");
+
+    let data = execute_query(database, "{ blocks { items { hash deploys { hash } } } }").await;
+
+    assert_eq!(data["blocks"]["items"].as_array().map(Vec::len), Some(1));
+}
+
+#[tokio::test]
+async fn block_by_invalid_hash_should_fail() {
+    execute_query_expecting_error(
+        FakeDatabase::new(),
+        r#"{ block(hash: "not a hash") { height } }"#,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn deploy_and_finality_signatures_by_invalid_hash_should_fail() {
+    execute_query_expecting_error(FakeDatabase::new(), r#"{ deploy(hash: "abc") { status } }"#)
+        .await;
+    execute_query_expecting_error(
+        FakeDatabase::new(),
+        r#"{ finalitySignatures(blockHash: "abc") { publicKey } }"#,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn block_by_uppercase_hash_should_resolve() {
+    let mut test_rng = TestRng::new();
+    let database = FakeDatabase::new();
+    let block_added = BlockAdded::random(&mut test_rng);
+    database
+        .save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+
+    let query = format!(
+        r#"{{ block(hash: "{}") {{ height }} }}"#,
+        block_added.hex_encoded_hash().to_uppercase()
+    );
+    let data = execute_query(database, &query).await;
+
+    assert_eq!(
+        data,
+        json!({ "block": { "height": block_added.get_height() } })
+    );
+}
+
+#[tokio::test]
+async fn faults_by_invalid_public_key_should_fail() {
+    execute_query_expecting_error(
+        FakeDatabase::new(),
+        r#"{ faultsByPublicKey(publicKey: "not a public key") { eraId } }"#,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn graphql_endpoint_should_execute_posted_query() {
+    // The FakeDatabase doesn't need to be populated as it returns a random BlockAdded for get_latest_block()
+    let api = filters::combined_filters(
+        FakeDatabase::new(),
+        &RestServerConfig::default(),
+        Vec::new(),
+    );
+
+    let response = request()
+        .method("POST")
+        .path("/graphql")
+        .json(&json!({ "query": "{ latestBlock { hash } }" }))
+        .reply(&api)
+        .await;
+
+    assert!(response.status().is_success());
+    let body =
+        serde_json::from_slice::<Value>(response.body()).expect("Error parsing GraphQL response");
+    assert!(body["data"]["latestBlock"]["hash"].is_string());
+    assert!(body.get("errors").is_none());
+}
+
+#[tokio::test]
+async fn graphql_endpoint_with_invalid_body_should_return_400() {
+    let api = filters::combined_filters(
+        FakeDatabase::new(),
+        &RestServerConfig::default(),
+        Vec::new(),
+    );
+
+    let response = request()
+        .method("POST")
+        .path("/graphql")
+        .header("content-type", "application/json")
+        .body("not a graphql request")
+        .reply(&api)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn graphiql_should_return_html_page() {
+    let api = filters::combined_filters(
+        FakeDatabase::new(),
+        &RestServerConfig::default(),
+        Vec::new(),
+    );
+
+    let response = request().path("/graphiql").reply(&api).await;
+
+    assert!(response.status().is_success());
+    let body = String::from_utf8_lossy(response.body());
+    assert!(body.contains("/graphql"));
+}
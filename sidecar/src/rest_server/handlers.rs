@@ -1,18 +1,270 @@
-use super::errors::StorageError;
+use super::{
+    caching::CachePolicy,
+    chain_stats::ChainTipCache,
+    deploy_waiter::DeployWaiter,
+    errors::{StorageError, TooManyDeployWaits},
+};
 use crate::{
     rest_server::errors::InvalidParam,
-    types::database::{DatabaseReadError, DatabaseReader},
+    sql::tables::event_type::EventTypeId,
+    types::{
+        database::{
+            BlockDeploys, BlockFinality, DatabaseReadError, DatabaseReader, DeployAggregate,
+            DeployAggregatesBatch, EraSummary, NodeEventStatistics, Page, ValidatorSignature,
+        },
+        sse_events::BlockAdded,
+    },
     utils::Unexpected,
 };
 use anyhow::Error;
-use serde::Serialize;
-use warp::{http::StatusCode, Rejection, Reply};
+use casper_event_listener::{EventListenerStatus, NodeState};
+use casper_types::{AsymmetricType, PublicKey, Timestamp};
+use futures::future::try_join_all;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, str::FromStr, sync::Arc, time::Duration};
+use tokio::{sync::Semaphore, time::Instant};
+use utoipa::{IntoParams, ToSchema};
+use warp::{
+    http::{header::CACHE_CONTROL, StatusCode},
+    Rejection, Reply,
+};
+
+/// Number of items returned by paginated endpoints if the client doesn't specify a `limit`.
+pub(super) const DEFAULT_PAGE_LIMIT: u32 = 100;
+/// Upper bound for the `limit` of paginated endpoints.
+pub(super) const MAX_PAGE_LIMIT: u32 = 1000;
+/// Pattern of the hex-encoded block and deploy hashes accepted as parameters.
+pub(super) const HASH_REGEX: &str = "^([0-9A-Fa-f]){64}$";
+/// Pattern of the hex-encoded public keys accepted as parameters.
+pub(super) const PUBLIC_KEY_REGEX: &str = "^([0-9A-Fa-f]{2}){33,34}$";
+/// Number of deploy hashes accepted in a batch request if `max_deploys_in_batch` isn't configured.
+pub(super) const DEFAULT_MAX_DEPLOYS_IN_BATCH: u32 = 100;
+/// Number of blocks returned by the latest blocks endpoint if the client doesn't specify a `count`.
+const DEFAULT_LATEST_BLOCKS_COUNT: u32 = 10;
+/// Number of blocks the chain statistics are computed from if the client doesn't specify a
+/// `window`.
+const DEFAULT_CHAIN_STATS_WINDOW: u32 = 100;
+/// Time a deploy is waited for if the client doesn't specify a `timeout_secs`.
+const DEFAULT_DEPLOY_WAIT_TIMEOUT_SECS: u64 = 30;
+/// Upper bound for the `timeout_secs` of the deploy wait endpoint.
+const MAX_DEPLOY_WAIT_TIMEOUT_SECS: u64 = 300;
+/// Number of requests which can wait for a deploy at the same time if
+/// `max_concurrent_deploy_waits` isn't configured.
+pub(super) const DEFAULT_MAX_CONCURRENT_DEPLOY_WAITS: u32 = 100;
+/// Prefix of the formatted string of an account hash.
+const ACCOUNT_HASH_PREFIX: &str = "account-hash-";
+/// Number of the most recent shutdowns of a node reported by the node endpoints.
+const MAX_SHUTDOWNS_IN_NODE_REPORT: u32 = 10;
+
+/// Query parameters of the paginated block range endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct BlockRangeQuery {
+    /// Lowest height (inclusive) of the returned blocks. Defaults to 0.
+    from_height: Option<u64>,
+    /// Highest height (inclusive) of the returned blocks. Unbounded if not provided.
+    to_height: Option<u64>,
+    /// Maximum number of blocks in the page. Defaults to 100, can't exceed 1000.
+    limit: Option<u32>,
+    /// The `next_cursor` value of the previously fetched page.
+    cursor: Option<u64>,
+}
+
+/// Query parameters of the latest blocks endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct LatestBlocksQuery {
+    /// Number of blocks to return. Defaults to 10, can't exceed 1000.
+    count: Option<u32>,
+}
+
+/// Query parameters of the chain statistics endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct ChainStatsQuery {
+    /// Number of latest blocks to compute the statistics from. Defaults to 100, can't exceed 1000.
+    window: Option<u32>,
+}
+
+/// Query parameters of the deploy aggregate endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct DeployQuery {
+    /// Whether to include when and from which node each event of the deploy was received.
+    /// Defaults to false.
+    timeline: Option<bool>,
+}
+
+/// Query parameters of the deploy wait endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct DeployWaitQuery {
+    /// Number of seconds to wait for the deploy to be processed or to expire. Defaults to 30,
+    /// can't exceed 300.
+    timeout_secs: Option<u64>,
+}
+
+/// Query parameters of the validator history endpoints.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct EraRangeQuery {
+    /// Lowest era (inclusive) of the returned history. Unbounded if not provided.
+    from_era: Option<u64>,
+    /// Highest era (inclusive) of the returned history. Unbounded if not provided.
+    to_era: Option<u64>,
+}
+
+/// Query parameters of the paginated signed and missed blocks of a validator.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct ValidatorSignaturesQuery {
+    /// Lowest height (inclusive) of the blocks. Defaults to 0.
+    from_height: Option<u64>,
+    /// Highest height (inclusive) of the blocks. Unbounded if not provided.
+    to_height: Option<u64>,
+    /// Maximum number of blocks in the page. Defaults to 100, can't exceed 1000.
+    limit: Option<u32>,
+    /// The `next_cursor` value of the previously fetched page.
+    cursor: Option<u64>,
+}
+
+/// Query parameters of paginated endpoints which have no additional filters.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct PaginationQuery {
+    /// Maximum number of items in the page. Defaults to 100, can't exceed 1000.
+    limit: Option<u32>,
+    /// The `next_cursor` value of the previously fetched page.
+    cursor: Option<u64>,
+}
+
+/// Query parameters of the paginated failed deploys endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct FailedDeploysQuery {
+    /// Case-insensitive substring of the error message of the returned deploys.
+    error_contains: Option<String>,
+    /// Lowest height (inclusive) of the block of the returned deploys. Unbounded if not provided.
+    from_height: Option<u64>,
+    /// Highest height (inclusive) of the block of the returned deploys. Unbounded if not provided.
+    to_height: Option<u64>,
+    /// Maximum number of deploys in the page. Defaults to 100, can't exceed 1000.
+    limit: Option<u32>,
+    /// The `next_cursor` value of the previously fetched page.
+    cursor: Option<u64>,
+}
+
+/// Query parameters of the paginated transfers endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct TransfersQuery {
+    /// Hex-encoded account hash of the sender or the recipient of the returned transfers, with or
+    /// without the `account-hash-` prefix.
+    account: String,
+    /// Lowest height (inclusive) of the block of the returned transfers. Unbounded if not provided.
+    from_height: Option<u64>,
+    /// Highest height (inclusive) of the block of the returned transfers. Unbounded if not provided.
+    to_height: Option<u64>,
+    /// Maximum number of transfers in the page. Defaults to 100, can't exceed 1000.
+    limit: Option<u32>,
+    /// The `next_cursor` value of the previously fetched page.
+    cursor: Option<u64>,
+}
+
+/// Query parameters of the paginated contract deploys endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct ContractDeploysQuery {
+    /// Name of the entry point called by the returned deploys. Any if not provided.
+    entry_point: Option<String>,
+    /// Maximum number of deploys in the page. Defaults to 100, can't exceed 1000.
+    limit: Option<u32>,
+    /// The `next_cursor` value of the previously fetched page.
+    cursor: Option<u64>,
+}
+
+/// Query parameters of the paginated event log endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct EventLogQuery {
+    /// Type of the returned events, e.g. `BlockAdded`. Any if not provided.
+    #[serde(rename = "type")]
+    event_type: Option<String>,
+    /// Address of the node which delivered the returned events. Any if not provided.
+    source: Option<String>,
+    /// Earliest time (inclusive, RFC 3339) the returned events were stored. Unbounded if not
+    /// provided.
+    since: Option<String>,
+    /// Latest time (exclusive, RFC 3339) the returned events were stored. Unbounded if not
+    /// provided.
+    until: Option<String>,
+    /// Maximum number of events in the page. Defaults to 100, can't exceed 1000.
+    limit: Option<u32>,
+    /// The `next_cursor` value of the previously fetched page.
+    cursor: Option<u64>,
+}
+
+/// State of the connection to a node, together with what the sidecar stored about the events the
+/// node delivered.
+#[derive(Debug, Serialize, ToSchema)]
+pub(super) struct NodeReport {
+    /// IP address and SSE port of the node.
+    address: String,
+    /// State of the connection to the node.
+    #[schema(value_type = String)]
+    status: EventListenerStatus,
+    /// Build version reported by the node, `null` until it was fetched.
+    node_build_version: Option<String>,
+    /// Id of the last event received through each filter of the node, e.g. `events/main`.
+    last_event_id_for_filter: BTreeMap<String, u32>,
+    /// What the sidecar stored about the events the node delivered.
+    events: NodeEventStatistics,
+}
+
+/// What a searched hex string was resolved to.
+#[derive(Debug, Serialize, ToSchema)]
+pub(super) struct SearchResult {
+    /// The searched string, lowercase.
+    query: String,
+    /// Everything the string identifies, empty if nothing matched.
+    matches: Vec<SearchMatch>,
+}
+
+/// A stored item identified by a searched hex string.
+#[derive(Debug, Serialize, ToSchema)]
+pub(super) struct SearchMatch {
+    /// Kind of the item.
+    #[serde(rename = "type")]
+    match_type: SearchMatchType,
+    /// Paths of the REST resources describing the item.
+    links: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub(super) enum SearchMatchType {
+    /// A block, identified by its hash.
+    Block,
+    /// A deploy, identified by its hash.
+    Deploy,
+    /// A validator, identified by its public key.
+    Validator,
+    /// An era, identified by its id.
+    Era,
+}
+
+/// Body of the deploy batch endpoint.
+#[derive(Debug, Deserialize, ToSchema)]
+pub(super) struct DeployBatchRequest {
+    /// Hex-encoded hashes of the requested deploys.
+    deploy_hashes: Vec<String>,
+}
 
 pub(super) async fn get_latest_block<Db: DatabaseReader + Clone + Send>(
     db: Db,
 ) -> Result<impl Reply, Rejection> {
     let db_result = db.get_latest_block().await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
 }
 
 pub(super) async fn get_block_by_hash<Db: DatabaseReader + Clone + Send>(
@@ -21,7 +273,7 @@ pub(super) async fn get_block_by_hash<Db: DatabaseReader + Clone + Send>(
 ) -> Result<impl Reply, Rejection> {
     check_hash_is_correct_format(&hash)?;
     let db_result = db.get_block_by_hash(&hash).await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Immutable)
 }
 
 pub(super) async fn get_block_by_height<Db: DatabaseReader + Clone + Send>(
@@ -29,16 +281,428 @@ pub(super) async fn get_block_by_height<Db: DatabaseReader + Clone + Send>(
     db: Db,
 ) -> Result<impl Reply, Rejection> {
     let db_result = db.get_block_by_height(height).await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_blocks_by_height_range<Db: DatabaseReader + Clone + Send>(
+    query: BlockRangeQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    let limit = check_limit_is_in_range(query.limit)?;
+    let from_height = query.from_height.unwrap_or(0);
+    check_height_range_is_valid(Some(from_height), query.to_height)?;
+    let db_result = match lowest_height_of_page(from_height, query.cursor) {
+        Some(from_height) => db
+            .get_blocks_by_height_range(from_height, query.to_height, limit + 1)
+            .await
+            .map(|blocks| Page::from_overfetched(blocks, limit, BlockAdded::get_height)),
+        None => Ok(Page::empty()),
+    };
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_latest_blocks<Db: DatabaseReader + Clone + Send + Sync>(
+    query: LatestBlocksQuery,
+    db: Db,
+    cache: Arc<ChainTipCache>,
+) -> Result<impl Reply, Rejection> {
+    let count = check_block_count_is_in_range("count", query.count, DEFAULT_LATEST_BLOCKS_COUNT)?;
+    let db_result = cache.latest_headers(&db, count).await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_chain_stats<Db: DatabaseReader + Clone + Send + Sync>(
+    query: ChainStatsQuery,
+    db: Db,
+    cache: Arc<ChainTipCache>,
+) -> Result<impl Reply, Rejection> {
+    let window = check_block_count_is_in_range("window", query.window, DEFAULT_CHAIN_STATS_WINDOW)?;
+    let db_result = cache.stats(&db, window).await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+/// Returns the lowest height of the page of a block range following `cursor`, which is the
+/// height of the last block of the previous page. `None` if there can't be a following page.
+pub(super) fn lowest_height_of_page(from_height: u64, cursor: Option<u64>) -> Option<u64> {
+    match cursor {
+        None => Some(from_height),
+        Some(cursor) => cursor.checked_add(1).map(|next| next.max(from_height)),
+    }
+}
+
+pub(super) async fn get_deploys_by_block<Db: DatabaseReader + Clone + Send>(
+    hash_or_height: String,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    let block = match hash_or_height.parse::<u64>() {
+        Ok(height) => db.get_block_by_height(height).await,
+        Err(_) => {
+            check_hash_is_correct_format(&hash_or_height)?;
+            db.get_block_by_hash(&hash_or_height).await
+        }
+    };
+    let db_result = match block {
+        Ok(block) => db
+            .get_deploy_aggregates_by_block(&block.hex_encoded_hash())
+            .await
+            .map(|aggregates| BlockDeploys::new(&block, aggregates)),
+        Err(err) => Err(err),
+    };
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_block_finality<Db: DatabaseReader + Clone + Send + Sync>(
+    hash: String,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_hash_is_correct_format(&hash)?;
+    let db_result = match db.get_block_by_hash(&hash).await {
+        Ok(block) => get_block_finality_of(&block, &db).await,
+        Err(err) => Err(err),
+    };
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+async fn get_block_finality_of<Db: DatabaseReader>(
+    block: &BlockAdded,
+    db: &Db,
+) -> Result<BlockFinality, DatabaseReadError> {
+    // The weights of the validators of an era are announced by the switch block of the previous
+    // era, which blocks of the genesis era don't have.
+    let previous_era_id = block
+        .get_era_id()
+        .checked_sub(1)
+        .ok_or(DatabaseReadError::NotFound)?;
+    let switch_block = db.get_switch_block_of_era(previous_era_id).await?;
+    let signatures = match db
+        .get_finality_signatures_by_block(&block.hex_encoded_hash())
+        .await
+    {
+        Ok(signatures) => signatures,
+        Err(DatabaseReadError::NotFound) => Vec::new(),
+        Err(err) => return Err(err),
+    };
+    Ok(BlockFinality::new(block, &switch_block, &signatures))
+}
+
+pub(super) async fn get_deploy_by_hash<Db: DatabaseReader + Clone + Send + Sync>(
+    hash: String,
+    query: DeployQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_hash_is_correct_format(&hash)?;
+    let db_result = get_deploy_aggregate_of(&hash, query.timeline.unwrap_or(false), &db).await;
+    let cache_policy = deploy_cache_policy(&db_result);
+    format_or_reject_storage_result(db_result, cache_policy)
+}
+
+pub(super) async fn wait_for_deploy<Db: DatabaseReader + Clone + Send + Sync>(
+    hash: String,
+    query: DeployWaitQuery,
+    wait_permits: Arc<Semaphore>,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_hash_is_correct_format(&hash)?;
+    let timeout_secs = query
+        .timeout_secs
+        .unwrap_or(DEFAULT_DEPLOY_WAIT_TIMEOUT_SECS);
+    if timeout_secs > MAX_DEPLOY_WAIT_TIMEOUT_SECS {
+        return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
+            "Expected timeout_secs of at most {}, received: {}",
+            MAX_DEPLOY_WAIT_TIMEOUT_SECS, timeout_secs
+        )))));
+    }
+    // The permit is held until the reply is ready.
+    let _permit = wait_permits
+        .try_acquire_owned()
+        .map_err(|_| warp::reject::custom(TooManyDeployWaits))?;
+    let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+    let mut waiter = DeployWaiter::new(&hash);
+    loop {
+        let db_result = db.get_deploy_aggregate_by_hash(&hash).await;
+        let should_reply = match &db_result {
+            Ok(aggregate) => is_deploy_finished(aggregate),
+            // The deploy may not have been accepted yet.
+            Err(DatabaseReadError::NotFound) => false,
+            Err(_) => true,
+        };
+        if should_reply || Instant::now() >= deadline {
+            let cache_policy = deploy_cache_policy(&db_result);
+            return format_or_reject_storage_result(db_result, cache_policy);
+        }
+        waiter.wait_until(deadline).await;
+    }
+}
+
+fn is_deploy_finished(aggregate: &DeployAggregate) -> bool {
+    aggregate.deploy_processed.is_some() || aggregate.deploy_expired
+}
+
+// Deploys which weren't processed or expired yet will still change.
+fn deploy_cache_policy(db_result: &Result<DeployAggregate, DatabaseReadError>) -> CachePolicy {
+    match db_result {
+        Ok(aggregate) if is_deploy_finished(aggregate) => CachePolicy::Immutable,
+        _ => CachePolicy::Revalidate,
+    }
+}
+
+async fn get_deploy_aggregate_of<Db: DatabaseReader>(
+    hash: &str,
+    with_timeline: bool,
+    db: &Db,
+) -> Result<DeployAggregate, DatabaseReadError> {
+    let mut aggregate = db.get_deploy_aggregate_by_hash(hash).await?;
+    if with_timeline {
+        aggregate.timeline = not_found_as_none(db.get_deploy_timeline(hash).await)?;
+    }
+    Ok(aggregate)
+}
+
+pub(super) async fn get_deploys_batch<Db: DatabaseReader + Clone + Send>(
+    request: DeployBatchRequest,
+    max_deploys_in_batch: u32,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    if request.deploy_hashes.len() > max_deploys_in_batch as usize {
+        return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
+            "Expected at most {} deploy hashes, received: {}",
+            max_deploys_in_batch,
+            request.deploy_hashes.len()
+        )))));
+    }
+    for hash in &request.deploy_hashes {
+        check_hash_is_correct_format(hash)?;
+    }
+    // Hashes are stored lowercase, duplicates are looked up and returned only once.
+    let deploy_hashes: Vec<String> = request
+        .deploy_hashes
+        .iter()
+        .map(|hash| hash.to_lowercase())
+        .unique()
+        .collect();
+    let db_result = db
+        .get_deploy_aggregates_by_hashes(&deploy_hashes)
+        .await
+        .map(|aggregates| DeployAggregatesBatch::new(&deploy_hashes, aggregates));
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_failed_deploys<Db: DatabaseReader + Clone + Send>(
+    query: FailedDeploysQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    let limit = check_limit_is_in_range(query.limit)?;
+    check_height_range_is_valid(query.from_height, query.to_height)?;
+    let error_contains = query
+        .error_contains
+        .as_deref()
+        .filter(|error_contains| !error_contains.is_empty());
+    let db_result = db
+        .get_failed_deploys(
+            error_contains,
+            query.from_height,
+            query.to_height,
+            query.cursor,
+            limit,
+        )
+        .await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_transfers_by_account<Db: DatabaseReader + Clone + Send>(
+    query: TransfersQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    let limit = check_limit_is_in_range(query.limit)?;
+    check_height_range_is_valid(query.from_height, query.to_height)?;
+    let account = query
+        .account
+        .strip_prefix(ACCOUNT_HASH_PREFIX)
+        .unwrap_or(&query.account);
+    check_hash_is_correct_format(account)?;
+    let db_result = db
+        .get_transfers_by_account(
+            &account.to_lowercase(),
+            query.from_height,
+            query.to_height,
+            query.cursor,
+            limit,
+        )
+        .await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_transfers_by_deploy<Db: DatabaseReader + Clone + Send>(
+    hash: String,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_hash_is_correct_format(&hash)?;
+    let db_result = db.get_transfers_by_deploy(&hash.to_lowercase()).await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
 }
 
-pub(super) async fn get_deploy_by_hash<Db: DatabaseReader + Clone + Send>(
+pub(super) async fn get_deploys_by_contract<Db: DatabaseReader + Clone + Send>(
     hash: String,
+    query: ContractDeploysQuery,
     db: Db,
 ) -> Result<impl Reply, Rejection> {
     check_hash_is_correct_format(&hash)?;
-    let db_result = db.get_deploy_aggregate_by_hash(&hash).await;
-    format_or_reject_storage_result(db_result)
+    let limit = check_limit_is_in_range(query.limit)?;
+    let entry_point = query
+        .entry_point
+        .as_deref()
+        .filter(|entry_point| !entry_point.is_empty());
+    let db_result = db
+        .get_deploys_by_contract(&hash.to_lowercase(), entry_point, query.cursor, limit)
+        .await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_event_log<Db: DatabaseReader + Clone + Send>(
+    query: EventLogQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    let limit = check_limit_is_in_range(query.limit)?;
+    let event_type = query
+        .event_type
+        .as_deref()
+        .map(check_event_type_is_known)
+        .transpose()?;
+    let since = query.since.as_deref().map(parse_time).transpose()?;
+    let until = query.until.as_deref().map(parse_time).transpose()?;
+    check_time_range_is_valid(since, until)?;
+    let source = query.source.as_deref().filter(|source| !source.is_empty());
+    let db_result = db
+        .get_event_log(event_type, source, since, until, query.cursor, limit)
+        .await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_nodes<Db: DatabaseReader + Clone + Send + Sync>(
+    nodes: Vec<(String, u16)>,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    let db_result = try_join_all(
+        nodes
+            .iter()
+            .map(|(node_address, sse_port)| get_node_report(node_address, *sse_port, &db)),
+    )
+    .await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_node_by_address<Db: DatabaseReader + Clone + Send + Sync>(
+    address: String,
+    nodes: Vec<(String, u16)>,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    let maybe_node = nodes
+        .iter()
+        .find(|(node_address, sse_port)| format!("{}:{}", node_address, sse_port) == address);
+    let db_result = match maybe_node {
+        Some((node_address, sse_port)) => get_node_report(node_address, *sse_port, &db).await,
+        None => Err(DatabaseReadError::NotFound),
+    };
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+async fn get_node_report<Db: DatabaseReader>(
+    node_address: &str,
+    sse_port: u16,
+    db: &Db,
+) -> Result<NodeReport, DatabaseReadError> {
+    // Events are stored with the URL of the node's event stream, stripped of the filter's path.
+    let event_source_address = format!("http://{}:{}/", node_address, sse_port);
+    let events = db
+        .get_node_event_statistics(&event_source_address, MAX_SHUTDOWNS_IN_NODE_REPORT)
+        .await?;
+    let node_state = NodeState::of_node(node_address, sse_port);
+    Ok(NodeReport {
+        address: format!("{}:{}", node_address, sse_port),
+        status: EventListenerStatus::of_node(node_address, sse_port),
+        node_build_version: node_state
+            .node_build_version
+            .map(|version| version.to_string()),
+        last_event_id_for_filter: node_state.last_event_id_for_filter,
+        events,
+    })
+}
+
+pub(super) async fn search<Db: DatabaseReader + Clone + Send + Sync>(
+    query: String,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_search_query_is_correct_format(&query)?;
+    // Hashes and keys are stored lowercase.
+    let query = query.to_lowercase();
+    let db_result = search_matches(&query, &db)
+        .await
+        .map(|matches| SearchResult { query, matches });
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+/// Looks up `query` as each kind of item its format allows: a block or deploy hash, the public key
+/// of a validator and an era id.
+async fn search_matches<Db: DatabaseReader>(
+    query: &str,
+    db: &Db,
+) -> Result<Vec<SearchMatch>, DatabaseReadError> {
+    let mut matches = Vec::new();
+    if check_hash_is_correct_format(query).is_ok() {
+        if not_found_as_none(db.get_block_by_hash(query).await)?.is_some() {
+            matches.push(SearchMatch {
+                match_type: SearchMatchType::Block,
+                links: vec![
+                    format!("/block/{}", query),
+                    format!("/block/{}/deploys", query),
+                    format!("/block/{}/finality", query),
+                ],
+            });
+        }
+        let mut deploy_links = Vec::new();
+        if not_found_as_none(db.get_deploy_accepted_by_hash(query).await)?.is_some() {
+            deploy_links.push(format!("/deploy/accepted/{}", query));
+        }
+        if not_found_as_none(db.get_deploy_processed_by_hash(query).await)?.is_some() {
+            deploy_links.push(format!("/deploy/processed/{}", query));
+        }
+        if not_found_as_none(db.get_deploy_expired_by_hash(query).await)?.is_some() {
+            deploy_links.push(format!("/deploy/expired/{}", query));
+        }
+        if !deploy_links.is_empty() {
+            deploy_links.insert(0, format!("/deploy/{}", query));
+            matches.push(SearchMatch {
+                match_type: SearchMatchType::Deploy,
+                links: deploy_links,
+            });
+        }
+    }
+    if PublicKey::from_hex(query).is_ok() {
+        let has_faults = not_found_as_none(db.get_faults_by_public_key(query).await)?.is_some();
+        if has_faults || db.has_finality_signatures_by_public_key(query).await? {
+            let mut links = vec![
+                format!("/validators/{}/rewards", query),
+                format!("/validators/{}/weights", query),
+            ];
+            if has_faults {
+                links.push(format!("/faults/{}", query));
+            }
+            matches.push(SearchMatch {
+                match_type: SearchMatchType::Validator,
+                links,
+            });
+        }
+    }
+    if let Ok(era_id) = query.parse::<u64>() {
+        if not_found_as_none(db.get_era_block_range(era_id).await)?.is_some() {
+            matches.push(SearchMatch {
+                match_type: SearchMatchType::Era,
+                // The era summary includes the era's step and faults.
+                links: vec![format!("/era/{}", era_id)],
+            });
+        }
+    }
+    Ok(matches)
 }
 
 pub(super) async fn get_deploy_accepted_by_hash<Db: DatabaseReader + Clone + Send>(
@@ -47,7 +711,7 @@ pub(super) async fn get_deploy_accepted_by_hash<Db: DatabaseReader + Clone + Sen
 ) -> Result<impl Reply, Rejection> {
     check_hash_is_correct_format(&hash)?;
     let db_result = db.get_deploy_accepted_by_hash(&hash).await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Immutable)
 }
 
 pub(super) async fn get_deploy_processed_by_hash<Db: DatabaseReader + Clone + Send>(
@@ -56,7 +720,7 @@ pub(super) async fn get_deploy_processed_by_hash<Db: DatabaseReader + Clone + Se
 ) -> Result<impl Reply, Rejection> {
     check_hash_is_correct_format(&hash)?;
     let db_result = db.get_deploy_processed_by_hash(&hash).await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Immutable)
 }
 
 pub(super) async fn get_deploy_expired_by_hash<Db: DatabaseReader + Clone + Send>(
@@ -65,7 +729,47 @@ pub(super) async fn get_deploy_expired_by_hash<Db: DatabaseReader + Clone + Send
 ) -> Result<impl Reply, Rejection> {
     check_hash_is_correct_format(&hash)?;
     let db_result = db.get_deploy_expired_by_hash(&hash).await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Immutable)
+}
+
+pub(super) async fn get_deploys_by_account<Db: DatabaseReader + Clone + Send>(
+    public_key: String,
+    query: PaginationQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_public_key_is_correct_format(&public_key)?;
+    let limit = check_limit_is_in_range(query.limit)?;
+    // Accounts are stored the way the node encodes them, i.e. lowercase.
+    let db_result = db
+        .get_deploy_summaries_by_account(&public_key.to_lowercase(), query.cursor, limit)
+        .await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_era_summary<Db: DatabaseReader + Clone + Send + Sync>(
+    era_id: u64,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    let db_result = get_era_summary_of(era_id, &db).await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+/// The era is only unknown if none of its blocks is stored, the rest of the summary is optional.
+async fn get_era_summary_of<Db: DatabaseReader>(
+    era_id: u64,
+    db: &Db,
+) -> Result<EraSummary, DatabaseReadError> {
+    let block_range = db.get_era_block_range(era_id).await?;
+    let switch_block = not_found_as_none(db.get_switch_block_of_era(era_id).await)?;
+    let step = not_found_as_none(db.get_step_by_era(era_id).await)?;
+    let faults = not_found_as_none(db.get_faults_by_era(era_id).await)?.unwrap_or_default();
+    Ok(EraSummary::new(
+        era_id,
+        block_range,
+        switch_block,
+        step,
+        faults,
+    ))
 }
 
 pub(super) async fn get_step_by_era<Db: DatabaseReader + Clone + Send>(
@@ -73,7 +777,7 @@ pub(super) async fn get_step_by_era<Db: DatabaseReader + Clone + Send>(
     db: Db,
 ) -> Result<impl Reply, Rejection> {
     let db_result = db.get_step_by_era(era_id).await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Immutable)
 }
 
 pub(super) async fn get_faults_by_public_key<Db: DatabaseReader + Clone + Send>(
@@ -82,7 +786,7 @@ pub(super) async fn get_faults_by_public_key<Db: DatabaseReader + Clone + Send>(
 ) -> Result<impl Reply, Rejection> {
     check_public_key_is_correct_format(&public_key)?;
     let db_result = db.get_faults_by_public_key(&public_key).await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
 }
 
 pub(super) async fn get_faults_by_era<Db: DatabaseReader + Clone + Send>(
@@ -90,7 +794,74 @@ pub(super) async fn get_faults_by_era<Db: DatabaseReader + Clone + Send>(
     db: Db,
 ) -> Result<impl Reply, Rejection> {
     let db_result = db.get_faults_by_era(era).await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_validator_rewards<Db: DatabaseReader + Clone + Send>(
+    public_key: String,
+    query: EraRangeQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_public_key_is_correct_format(&public_key)?;
+    check_era_range_is_valid(&query)?;
+    let db_result = db
+        .get_validator_rewards(&public_key.to_lowercase(), query.from_era, query.to_era)
+        .await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_validator_weights<Db: DatabaseReader + Clone + Send>(
+    public_key: String,
+    query: EraRangeQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_public_key_is_correct_format(&public_key)?;
+    check_era_range_is_valid(&query)?;
+    let db_result = db
+        .get_validator_weights(&public_key.to_lowercase(), query.from_era, query.to_era)
+        .await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_validator_signatures<Db: DatabaseReader + Clone + Send>(
+    public_key: String,
+    query: ValidatorSignaturesQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_public_key_is_correct_format(&public_key)?;
+    let limit = check_limit_is_in_range(query.limit)?;
+    let from_height = query.from_height.unwrap_or(0);
+    check_height_range_is_valid(Some(from_height), query.to_height)?;
+    let db_result = match lowest_height_of_page(from_height, query.cursor) {
+        Some(from_height) => db
+            .get_validator_signatures(
+                &public_key.to_lowercase(),
+                from_height,
+                query.to_height,
+                limit + 1,
+            )
+            .await
+            .map(|signatures| {
+                Page::from_overfetched(signatures, limit, |signature: &ValidatorSignature| {
+                    signature.block_height
+                })
+            }),
+        None => Ok(Page::empty()),
+    };
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
+}
+
+pub(super) async fn get_validator_participation<Db: DatabaseReader + Clone + Send>(
+    public_key: String,
+    query: EraRangeQuery,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    check_public_key_is_correct_format(&public_key)?;
+    check_era_range_is_valid(&query)?;
+    let db_result = db
+        .get_validator_participation(&public_key.to_lowercase(), query.from_era, query.to_era)
+        .await;
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
 }
 
 pub(super) async fn get_finality_signatures_by_block<Db: DatabaseReader + Clone + Send>(
@@ -99,11 +870,12 @@ pub(super) async fn get_finality_signatures_by_block<Db: DatabaseReader + Clone
 ) -> Result<impl Reply, Rejection> {
     check_hash_is_correct_format(&block_hash)?;
     let db_result = db.get_finality_signatures_by_block(&block_hash).await;
-    format_or_reject_storage_result(db_result)
+    format_or_reject_storage_result(db_result, CachePolicy::Revalidate)
 }
 
 fn format_or_reject_storage_result<T>(
     storage_result: Result<T, DatabaseReadError>,
+    cache_policy: CachePolicy,
 ) -> Result<impl Reply, Rejection>
 where
     T: Serialize,
@@ -111,14 +883,114 @@ where
     match storage_result {
         Ok(data) => {
             let json = warp::reply::json(&data);
+            let json = warp::reply::with_header(json, CACHE_CONTROL, cache_policy.header_value());
             Ok(warp::reply::with_status(json, StatusCode::OK).into_response())
         }
         Err(req_err) => Err(warp::reject::custom(StorageError(req_err))),
     }
 }
 
+fn not_found_as_none<T>(
+    storage_result: Result<T, DatabaseReadError>,
+) -> Result<Option<T>, DatabaseReadError> {
+    match storage_result {
+        Ok(data) => Ok(Some(data)),
+        Err(DatabaseReadError::NotFound) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn check_limit_is_in_range(limit: Option<u32>) -> Result<u32, Rejection> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+    if limit == 0 || limit > MAX_PAGE_LIMIT {
+        return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
+            "Expected limit between 1 and {}, received: {}",
+            MAX_PAGE_LIMIT, limit
+        )))));
+    }
+    Ok(limit)
+}
+
+/// Checks the number of latest blocks requested through the parameter `name`, `default` if it
+/// wasn't provided.
+fn check_block_count_is_in_range(
+    name: &str,
+    count: Option<u32>,
+    default: u32,
+) -> Result<u32, Rejection> {
+    let count = count.unwrap_or(default);
+    if count == 0 || count > MAX_PAGE_LIMIT {
+        return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
+            "Expected {} between 1 and {}, received: {}",
+            name, MAX_PAGE_LIMIT, count
+        )))));
+    }
+    Ok(count)
+}
+
+fn check_height_range_is_valid(
+    from_height: Option<u64>,
+    to_height: Option<u64>,
+) -> Result<(), Rejection> {
+    if let (Some(from_height), Some(to_height)) = (from_height, to_height) {
+        if from_height > to_height {
+            return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
+                "from_height ({}) can't be greater than to_height ({})",
+                from_height, to_height
+            )))));
+        }
+    }
+    Ok(())
+}
+
+fn check_era_range_is_valid(query: &EraRangeQuery) -> Result<(), Rejection> {
+    if let (Some(from_era), Some(to_era)) = (query.from_era, query.to_era) {
+        if from_era > to_era {
+            return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
+                "from_era ({}) can't be greater than to_era ({})",
+                from_era, to_era
+            )))));
+        }
+    }
+    Ok(())
+}
+
+fn check_event_type_is_known(event_type: &str) -> Result<EventTypeId, Rejection> {
+    EventTypeId::from_name(event_type).ok_or_else(|| {
+        warp::reject::custom(InvalidParam(Error::msg(format!(
+            "Expected one of BlockAdded, DeployAccepted, DeployExpired, DeployProcessed, Fault, \
+             FinalitySignature, Step or Shutdown as event type, received: {}",
+            event_type
+        ))))
+    })
+}
+
+fn parse_time(time: &str) -> Result<Timestamp, Rejection> {
+    Timestamp::from_str(time).map_err(|err| {
+        warp::reject::custom(InvalidParam(Error::msg(format!(
+            "Expected RFC 3339 time such as 2023-03-01T12:00:00Z, received: {} ({})",
+            time, err
+        ))))
+    })
+}
+
+fn check_time_range_is_valid(
+    since: Option<Timestamp>,
+    until: Option<Timestamp>,
+) -> Result<(), Rejection> {
+    if let (Some(since), Some(until)) = (since, until) {
+        if since >= until {
+            return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
+                "since ({}) must be earlier than until ({})",
+                since, until
+            )))));
+        }
+    }
+    Ok(())
+}
+
 fn check_hash_is_correct_format(hash: &str) -> Result<(), Rejection> {
-    let hash_regex = regex::Regex::new("^([0-9A-Fa-f]){64}$")
+    let hash_regex = regex::Regex::new(HASH_REGEX)
         .map_err(|err| warp::reject::custom(Unexpected(err.into())))?;
     if !hash_regex.is_match(hash) {
         return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
@@ -130,8 +1002,18 @@ fn check_hash_is_correct_format(hash: &str) -> Result<(), Rejection> {
     Ok(())
 }
 
+fn check_search_query_is_correct_format(query: &str) -> Result<(), Rejection> {
+    if query.is_empty() || !query.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
+            "Expected hex-encoded hash or public key, or era id, received: {}",
+            query
+        )))));
+    }
+    Ok(())
+}
+
 fn check_public_key_is_correct_format(public_key_hex: &str) -> Result<(), Rejection> {
-    let public_key_regex = regex::Regex::new("^([0-9A-Fa-f]{2}){33,34}$")
+    let public_key_regex = regex::Regex::new(PUBLIC_KEY_REGEX)
         .map_err(|err| warp::reject::custom(Unexpected(err.into())))?;
     if !public_key_regex.is_match(public_key_hex) {
         return Err(warp::reject::custom(InvalidParam(Error::msg(format!(
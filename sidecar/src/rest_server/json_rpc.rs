@@ -0,0 +1,280 @@
+#[cfg(test)]
+mod tests;
+
+use crate::types::{
+    database::{DatabaseReadError, DatabaseReader},
+    sse_events::BlockAdded,
+};
+use bytes::Bytes;
+use casper_event_types::{Deploy, JsonBlock};
+use casper_types::{ExecutionResult, ProtocolVersion};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use warp::{Rejection, Reply};
+
+const JSON_RPC_VERSION: &str = "2.0";
+
+// Error codes defined by the JSON-RPC 2.0 specification.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+// Error codes returned by the node for the same conditions.
+const NO_SUCH_DEPLOY: i64 = -32000;
+const NO_SUCH_BLOCK: i64 = -32001;
+
+#[derive(Deserialize)]
+struct JsonRpcRequest {
+    jsonrpc: String,
+    #[serde(default)]
+    id: Value,
+    method: String,
+    params: Option<Value>,
+}
+
+#[derive(Serialize)]
+struct JsonRpcResponse {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(flatten)]
+    outcome: Outcome,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Outcome {
+    Result(Value),
+    Error(JsonRpcError),
+}
+
+#[derive(Debug, Serialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+impl JsonRpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        JsonRpcError {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn from_read_error(err: DatabaseReadError, not_found: Self) -> Self {
+        match err {
+            DatabaseReadError::NotFound => not_found,
+            DatabaseReadError::Serialisation(err) => JsonRpcError::new(
+                INTERNAL_ERROR,
+                format!("Error deserializing stored data: {}", err),
+            ),
+            DatabaseReadError::Unhandled(err) => JsonRpcError::new(
+                INTERNAL_ERROR,
+                format!("Unhandled error occurred in storage: {}", err),
+            ),
+        }
+    }
+}
+
+/// Identifies a block the same way as the node's JSON-RPC, e.g. `{"Height": 42}`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+enum BlockIdentifier {
+    Hash(String),
+    Height(u64),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BlockParams {
+    block_identifier: Option<BlockIdentifier>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DeployParams {
+    deploy_hash: String,
+    // Approvals aren't stored separately by the Sidecar, so this is accepted but has no effect.
+    #[serde(default)]
+    #[allow(dead_code)]
+    finalized_approvals: bool,
+}
+
+#[derive(Serialize)]
+struct GetBlockResult<'a> {
+    api_version: ProtocolVersion,
+    block: &'a JsonBlock,
+}
+
+#[derive(Serialize)]
+struct ExecutionResultInBlock<'a> {
+    block_hash: String,
+    result: &'a ExecutionResult,
+}
+
+#[derive(Serialize)]
+struct GetDeployResult<'a> {
+    api_version: Option<ProtocolVersion>,
+    deploy: &'a Deploy,
+    execution_results: Vec<ExecutionResultInBlock<'a>>,
+}
+
+/// Answers a JSON-RPC 2.0 request with the node-shaped result of one of the supported methods:
+/// `chain_get_block` and `info_get_deploy`.
+/// Failures are reported as JSON-RPC errors, so the HTTP status is always 200.
+pub(super) async fn handle_json_rpc_request<Db: DatabaseReader + Clone + Send + Sync>(
+    body: Bytes,
+    db: Db,
+) -> Result<impl Reply, Rejection> {
+    let response = match parse_request(&body) {
+        Ok(request) => JsonRpcResponse {
+            jsonrpc: JSON_RPC_VERSION,
+            outcome: match dispatch(&request.method, request.params, &db).await {
+                Ok(result) => Outcome::Result(result),
+                Err(err) => Outcome::Error(err),
+            },
+            id: request.id,
+        },
+        Err(err) => JsonRpcResponse {
+            jsonrpc: JSON_RPC_VERSION,
+            id: Value::Null,
+            outcome: Outcome::Error(err),
+        },
+    };
+    Ok(warp::reply::json(&response))
+}
+
+fn parse_request(body: &[u8]) -> Result<JsonRpcRequest, JsonRpcError> {
+    let value = serde_json::from_slice::<Value>(body)
+        .map_err(|err| JsonRpcError::new(PARSE_ERROR, format!("Parse error: {}", err)))?;
+    if value.is_array() {
+        return Err(JsonRpcError::new(
+            INVALID_REQUEST,
+            "Batch requests are not supported",
+        ));
+    }
+    let request = serde_json::from_value::<JsonRpcRequest>(value)
+        .map_err(|err| JsonRpcError::new(INVALID_REQUEST, format!("Invalid request: {}", err)))?;
+    if request.jsonrpc != JSON_RPC_VERSION {
+        return Err(JsonRpcError::new(
+            INVALID_REQUEST,
+            format!(
+                "Expected jsonrpc version 2.0, received: {}",
+                request.jsonrpc
+            ),
+        ));
+    }
+    Ok(request)
+}
+
+async fn dispatch<Db: DatabaseReader>(
+    method: &str,
+    params: Option<Value>,
+    db: &Db,
+) -> Result<Value, JsonRpcError> {
+    match method {
+        "chain_get_block" => chain_get_block(parse_params(params)?, db).await,
+        "info_get_deploy" => info_get_deploy(parse_params(params)?, db).await,
+        _ => Err(JsonRpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Method not found: {}", method),
+        )),
+    }
+}
+
+/// Params have to be passed by name, omitting them is the same as passing an empty object.
+fn parse_params<T: DeserializeOwned>(params: Option<Value>) -> Result<T, JsonRpcError> {
+    let params = match params {
+        None => Value::Object(Default::default()),
+        Some(Value::Array(_)) => {
+            return Err(JsonRpcError::new(
+                INVALID_PARAMS,
+                "Invalid params: expected params to be passed by name",
+            ))
+        }
+        Some(params) => params,
+    };
+    serde_json::from_value(params)
+        .map_err(|err| JsonRpcError::new(INVALID_PARAMS, format!("Invalid params: {}", err)))
+}
+
+async fn chain_get_block<Db: DatabaseReader>(
+    params: BlockParams,
+    db: &Db,
+) -> Result<Value, JsonRpcError> {
+    let block = get_block(params.block_identifier, db).await?;
+    to_result(&GetBlockResult {
+        api_version: block.get_protocol_version(),
+        block: block.block(),
+    })
+}
+
+async fn info_get_deploy<Db: DatabaseReader>(
+    params: DeployParams,
+    db: &Db,
+) -> Result<Value, JsonRpcError> {
+    check_hash_is_correct_format(&params.deploy_hash)?;
+    let no_such_deploy = || JsonRpcError::new(NO_SUCH_DEPLOY, "deploy not known");
+    let aggregate = db
+        .get_deploy_aggregate_by_hash(&params.deploy_hash.to_lowercase())
+        .await
+        .map_err(|err| JsonRpcError::from_read_error(err, no_such_deploy()))?;
+    // The deploy itself is only part of the DeployAccepted event.
+    let deploy_accepted = aggregate.deploy_accepted.ok_or_else(no_such_deploy)?;
+    let execution_results = aggregate
+        .deploy_processed
+        .iter()
+        .map(|deploy_processed| ExecutionResultInBlock {
+            block_hash: deploy_processed.hex_encoded_block_hash(),
+            result: deploy_processed.execution_result(),
+        })
+        .collect();
+    let api_version = match db.get_latest_block().await {
+        Ok(block) => Some(block.get_protocol_version()),
+        Err(DatabaseReadError::NotFound) => None,
+        Err(err) => return Err(JsonRpcError::from_read_error(err, no_such_deploy())),
+    };
+    to_result(&GetDeployResult {
+        api_version,
+        deploy: deploy_accepted.deploy(),
+        execution_results,
+    })
+}
+
+/// The latest block is returned if no `block_identifier` is given, like the node does.
+async fn get_block<Db: DatabaseReader>(
+    block_identifier: Option<BlockIdentifier>,
+    db: &Db,
+) -> Result<BlockAdded, JsonRpcError> {
+    let block = match block_identifier {
+        None => db.get_latest_block().await,
+        Some(BlockIdentifier::Height(height)) => db.get_block_by_height(height).await,
+        Some(BlockIdentifier::Hash(hash)) => {
+            check_hash_is_correct_format(&hash)?;
+            db.get_block_by_hash(&hash.to_lowercase()).await
+        }
+    };
+    block.map_err(|err| {
+        JsonRpcError::from_read_error(err, JsonRpcError::new(NO_SUCH_BLOCK, "block not known"))
+    })
+}
+
+fn check_hash_is_correct_format(hash: &str) -> Result<(), JsonRpcError> {
+    if hash.len() != 64 || hex::decode(hash).is_err() {
+        return Err(JsonRpcError::new(
+            INVALID_PARAMS,
+            format!(
+                "Invalid params: expected hex-encoded hash (64 chars), received: {}",
+                hash
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn to_result<T: Serialize>(result: &T) -> Result<Value, JsonRpcError> {
+    serde_json::to_value(result).map_err(|err| {
+        JsonRpcError::new(INTERNAL_ERROR, format!("Error serializing result: {}", err))
+    })
+}
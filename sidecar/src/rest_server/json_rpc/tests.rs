@@ -0,0 +1,221 @@
+use casper_event_types::block::json_compatibility::JsonEraEnd;
+use casper_types::testing::TestRng;
+use serde_json::{json, Value};
+use warp::test::request;
+
+use crate::{
+    rest_server::filters,
+    testing::fake_database::FakeDatabase,
+    types::{config::RestServerConfig, database::DatabaseWriter, sse_events::*},
+};
+
+async fn call(database: FakeDatabase, body: String) -> Value {
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let response = request()
+        .method("POST")
+        .path("/rpc")
+        .header("content-type", "application/json")
+        .body(body)
+        .reply(&api)
+        .await;
+
+    assert!(response.status().is_success());
+    serde_json::from_slice::<Value>(response.body()).expect("Error parsing JSON-RPC response")
+}
+
+async fn call_method(database: FakeDatabase, method: &str, params: Value) -> Value {
+    let body = json!({ "jsonrpc": "2.0", "id": 7, "method": method, "params": params });
+    let response = call(database, body.to_string()).await;
+
+    assert_eq!(response["jsonrpc"], "2.0");
+    assert_eq!(response["id"], 7);
+    response
+}
+
+async fn save_block(database: &FakeDatabase, block_added: &BlockAdded) {
+    database
+        .save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+}
+
+#[tokio::test]
+async fn chain_get_block_should_return_block_by_hash_and_height() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let block_added = BlockAdded::random(&mut test_rng);
+    save_block(&database, &block_added).await;
+
+    for block_identifier in [
+        json!({ "Hash": block_added.hex_encoded_hash() }),
+        json!({ "Height": block_added.get_height() }),
+    ] {
+        let response = call_method(
+            database.clone(),
+            "chain_get_block",
+            json!({ "block_identifier": block_identifier }),
+        )
+        .await;
+
+        assert_eq!(
+            response["result"]["block"],
+            serde_json::to_value(block_added.block()).unwrap()
+        );
+        assert_eq!(
+            response["result"]["api_version"],
+            block_added.get_protocol_version().to_string()
+        );
+    }
+}
+
+#[tokio::test]
+async fn chain_get_block_of_not_stored_should_return_no_such_block() {
+    let response = call_method(
+        FakeDatabase::new(),
+        "chain_get_block",
+        json!({ "block_identifier": { "Height": 1 } }),
+    )
+    .await;
+
+    assert_eq!(response["error"]["code"], -32001);
+    assert!(response.get("result").is_none());
+}
+
+#[tokio::test]
+async fn info_get_deploy_should_return_deploy_and_execution_results() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let deploy_accepted = DeployAccepted::random(&mut test_rng);
+    let deploy_processed =
+        DeployProcessed::random(&mut test_rng, Some(deploy_accepted.deploy_hash()));
+    database
+        .save_deploy_accepted(deploy_accepted.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_accepted");
+    database
+        .save_deploy_processed(deploy_processed.clone(), 2, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_processed");
+
+    let response = call_method(
+        database,
+        "info_get_deploy",
+        json!({ "deploy_hash": deploy_accepted.hex_encoded_hash() }),
+    )
+    .await;
+
+    let result = &response["result"];
+    assert_eq!(
+        result["deploy"],
+        serde_json::to_value(deploy_accepted.deploy()).unwrap()
+    );
+    assert_eq!(
+        result["execution_results"],
+        json!([{
+            "block_hash": deploy_processed.hex_encoded_block_hash(),
+            "result": deploy_processed.execution_result(),
+        }])
+    );
+}
+
+#[tokio::test]
+async fn info_get_deploy_of_not_stored_should_return_no_such_deploy() {
+    let response = call_method(
+        FakeDatabase::new(),
+        "info_get_deploy",
+        json!({ "deploy_hash": "0".repeat(64) }),
+    )
+    .await;
+
+    assert_eq!(response["error"]["code"], -32000);
+}
+
+#[tokio::test]
+async fn info_get_deploy_with_invalid_hash_should_return_invalid_params() {
+    let response = call_method(
+        FakeDatabase::new(),
+        "info_get_deploy",
+        json!({ "deploy_hash": "not_a_hash" }),
+    )
+    .await;
+
+    assert_eq!(response["error"]["code"], -32602);
+}
+
+#[tokio::test]
+async fn chain_get_era_info_by_switch_block_should_return_method_not_found() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let era_end = JsonEraEnd::random_with_validator_weights(&mut test_rng, vec![]);
+    let block_added = BlockAdded::random_in_era(&mut test_rng, 3, 30, Some(era_end));
+    save_block(&database, &block_added).await;
+
+    let response = call_method(
+        database,
+        "chain_get_era_info_by_switch_block",
+        json!({ "block_identifier": { "Height": 30 } }),
+    )
+    .await;
+
+    assert_eq!(response["error"]["code"], -32601);
+}
+
+#[tokio::test]
+async fn unknown_method_should_return_method_not_found() {
+    let response = call_method(FakeDatabase::new(), "state_get_item", json!({})).await;
+
+    assert_eq!(response["error"]["code"], -32601);
+}
+
+#[tokio::test]
+async fn positional_params_should_return_invalid_params() {
+    let response = call_method(
+        FakeDatabase::new(),
+        "chain_get_block",
+        json!([{ "Height": 1 }]),
+    )
+    .await;
+
+    assert_eq!(response["error"]["code"], -32602);
+}
+
+#[tokio::test]
+async fn malformed_json_should_return_parse_error() {
+    let response = call(FakeDatabase::new(), "{not json".to_string()).await;
+
+    assert_eq!(response["error"]["code"], -32700);
+    assert_eq!(response["id"], Value::Null);
+}
+
+#[tokio::test]
+async fn request_without_method_should_return_invalid_request() {
+    let response = call(
+        FakeDatabase::new(),
+        json!({ "jsonrpc": "2.0", "id": 1 }).to_string(),
+    )
+    .await;
+
+    assert_eq!(response["error"]["code"], -32600);
+}
+
+#[tokio::test]
+async fn oversized_request_should_be_rejected() {
+    let api = filters::combined_filters(
+        FakeDatabase::new(),
+        &RestServerConfig::default(),
+        Vec::new(),
+    );
+    let params = json!({ "block_identifier": { "Hash": "0".repeat(32 * 1024) } });
+    let body = json!({ "jsonrpc": "2.0", "id": 7, "method": "chain_get_block", "params": params });
+
+    let response = request()
+        .method("POST")
+        .path("/rpc")
+        .header("content-type", "application/json")
+        .body(body.to_string())
+        .reply(&api)
+        .await;
+
+    assert_eq!(response.status(), http::StatusCode::PAYLOAD_TOO_LARGE);
+}
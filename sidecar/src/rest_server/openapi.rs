@@ -1,7 +1,20 @@
 mod schema_transformation_visitor;
-use crate::types::{
-    database::DeployAggregate,
-    sse_events::{BlockAdded, DeployAccepted, DeployExpired, DeployProcessed, Fault, Step},
+use crate::{
+    rest_server::{
+        chain_stats::{BlockIntervalStats, ChainStats},
+        handlers::{DeployBatchRequest, NodeReport, SearchMatch, SearchMatchType, SearchResult},
+    },
+    types::{
+        database::{
+            BlockDeploys, BlockFinality, BlockHeaderSummary, BlocksPage, ContractDeploy,
+            ContractDeploysPage, DeployAggregate, DeployAggregatesBatch, DeployEventTiming,
+            DeployStatus, DeploySummariesPage, DeploySummary, DeployTimeline, EraSummary,
+            EventLogEntry, EventLogPage, FailedDeploy, FailedDeploysPage, NativeTransfer,
+            NodeEventStatistics, TransfersPage, ValidatorEraParticipation, ValidatorEraReward,
+            ValidatorEraWeight, ValidatorSignature, ValidatorSignaturesPage,
+        },
+        sse_events::{BlockAdded, DeployAccepted, DeployExpired, DeployProcessed, Fault, Step},
+    },
 };
 use casper_event_types::{
     block::json_compatibility::{
@@ -38,19 +51,40 @@ use self::schema_transformation_visitor::SchemaTransformationVisitor;
         paths(crate::rest_server::filters::latest_block,
             crate::rest_server::filters::block_by_hash,
             crate::rest_server::filters::block_by_height,
+            crate::rest_server::filters::blocks_by_height_range,
+            crate::rest_server::filters::deploys_by_block,
+            crate::rest_server::filters::block_finality,
+            crate::rest_server::filters::latest_blocks,
+            crate::rest_server::filters::chain_stats,
             crate::rest_server::filters::deploy_by_hash,
+            crate::rest_server::filters::deploy_wait,
             crate::rest_server::filters::deploy_accepted_by_hash,
             crate::rest_server::filters::deploy_expired_by_hash,
             crate::rest_server::filters::deploy_processed_by_hash,
+            crate::rest_server::filters::deploys_by_account,
+            crate::rest_server::filters::failed_deploys,
+            crate::rest_server::filters::deploy_transfers,
+            crate::rest_server::filters::deploys_batch,
             crate::rest_server::filters::faults_by_public_key,
             crate::rest_server::filters::faults_by_era,
             crate::rest_server::filters::finality_signatures_by_block,
             crate::rest_server::filters::step_by_era,
+            crate::rest_server::filters::era_summary,
+            crate::rest_server::filters::validator_rewards,
+            crate::rest_server::filters::validator_weights,
+            crate::rest_server::filters::validator_signatures,
+            crate::rest_server::filters::validator_participation,
+            crate::rest_server::filters::transfers_by_account,
+            crate::rest_server::filters::deploys_by_contract,
+            crate::rest_server::filters::event_log,
+            crate::rest_server::filters::nodes_list,
+            crate::rest_server::filters::node_by_address,
+            crate::rest_server::filters::search,
 
 
         ),
         components(
-            schemas(Step, FinalitySignature, Fault, DeployExpired, Deploy, DeployHeader, ExecutableDeployItem, Approval, DeployAggregate, DeployAccepted, DeployProcessed, BlockAdded, JsonBlock, BlockHash, JsonEraEnd, JsonEraReport, JsonBlockBody, JsonBlockHeader, JsonProof, Digest, DeployHash, ValidatorWeight, Reward)
+            schemas(Step, FinalitySignature, Fault, DeployExpired, Deploy, DeployHeader, ExecutableDeployItem, Approval, DeployAggregate, DeployTimeline, DeployEventTiming, DeployAggregatesBatch, DeployBatchRequest, DeployAccepted, DeployProcessed, DeploySummary, DeployStatus, DeploySummariesPage, FailedDeploy, FailedDeploysPage, NativeTransfer, TransfersPage, ContractDeploy, ContractDeploysPage, EventLogEntry, EventLogPage, NodeReport, NodeEventStatistics, SearchResult, SearchMatch, SearchMatchType, BlockAdded, BlocksPage, BlockDeploys, BlockFinality, BlockHeaderSummary, ChainStats, BlockIntervalStats, EraSummary, ValidatorEraReward, ValidatorEraWeight, ValidatorSignature, ValidatorSignaturesPage, ValidatorEraParticipation, JsonBlock, BlockHash, JsonEraEnd, JsonEraReport, JsonBlockBody, JsonBlockHeader, JsonProof, Digest, DeployHash, ValidatorWeight, Reward)
         ),
         tags(
             (name = "event-sidecar", description = "Event-sidecar rest API")
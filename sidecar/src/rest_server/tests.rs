@@ -1,20 +1,61 @@
-use casper_event_types::FinalitySignature as FinSig;
-use casper_types::AsymmetricType;
+use casper_event_types::sse_data::SseData;
+use casper_event_types::{
+    block::json_compatibility::JsonEraEnd, ExecutableDeployItem, FinalitySignature as FinSig,
+};
+use casper_types::{
+    account::AccountHash, testing::TestRng, AccessRights, AsymmetricType, ContractHash, EraId,
+    PublicKey, RuntimeArgs, SecretKey, Timestamp, Transfer, URef, U512,
+};
+use futures::future::poll_fn;
 use http::StatusCode;
-use warp::test::request;
-
-use super::filters;
+use hyper::{Body, Request};
+use rand::Rng;
+use std::time::Duration;
+use tokio::time;
+use tower::Service;
+use warp::{test::request, Filter};
+
+use super::{chain_stats::ChainStats, cors, deploy_waiter, filters, limit_requests};
 use crate::{
     testing::fake_database::FakeDatabase,
-    types::{database::DeployAggregate, sse_events::*},
+    types::{
+        config::{RestApiKey, RestServerConfig},
+        database::{
+            BlockDeploys, BlockFinality, BlockHeaderSummary, BlocksPage, ContractDeploy,
+            ContractDeploysPage, DatabaseWriter, DeployAggregate, DeployAggregatesBatch,
+            DeployStatus, DeploySummariesPage, EraSummary, EventLogPage, FailedDeploysPage,
+            NativeTransfer, TransfersPage, ValidatorEraParticipation, ValidatorEraReward,
+            ValidatorEraWeight, ValidatorSignaturesPage,
+        },
+        sse_events::*,
+    },
 };
 
 // Path elements
 const BLOCK: &str = "block";
+const BLOCKS: &str = "blocks";
+const BLOCKS_LATEST: &str = "blocks/latest";
+const CHAIN_STATS: &str = "chain/stats";
+const CONTRACTS: &str = "contracts";
+const DEPLOYS: &str = "deploys";
 const DEPLOY: &str = "deploy";
+const DEPLOYS_BY_ACCOUNT: &str = "deploys/by-account";
+const DEPLOYS_BATCH: &str = "deploys/batch";
+const DEPLOYS_FAILED: &str = "deploys/failed";
+const ERA: &str = "era";
+const EVENT_LOG: &str = "events/log";
+const FINALITY: &str = "finality";
 const FAULTS: &str = "faults";
+const NODES: &str = "nodes";
+const PARTICIPATION: &str = "participation";
 const SIGNATURES: &str = "signatures";
 const STEP: &str = "step";
+const TRANSFERS: &str = "transfers";
+const VALIDATORS: &str = "validators";
+const WAIT: &str = "wait";
+const REWARDS: &str = "rewards";
+const SEARCH: &str = "search";
+const WEIGHTS: &str = "weights";
 const ACCEPTED: &str = "accepted";
 const PROCESSED: &str = "processed";
 const EXPIRED: &str = "expired";
@@ -29,7 +70,7 @@ const INVALID_PUBLIC_KEY: &str = "not_a_public_key";
 async fn should_respond_to_path_with(request_path: String, expected_status: StatusCode) {
     let database = FakeDatabase::new();
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
     let response = request().path(&request_path).reply(&api).await;
 
@@ -57,7 +98,7 @@ async fn block_root_should_return_valid_data() {
 
     // The database doesn't need to be populated with events for this test as it returns a random BlockAdded for get_latest_block()
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
     let request_path = format!("/{}", BLOCK);
 
@@ -78,7 +119,7 @@ async fn block_by_hash_should_return_valid_data() {
         .await
         .expect("Error populating FakeDatabase");
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
     let request_path = format!("/{}/{}", BLOCK, identifiers.block_added_hash);
 
@@ -102,7 +143,7 @@ async fn block_by_height_should_return_valid_data() {
         .await
         .expect("Error populating FakeDatabase");
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
     let request_path = format!("/{}/{}", BLOCK, identifiers.block_added_height);
 
@@ -118,7 +159,7 @@ async fn block_by_height_should_return_valid_data() {
 }
 
 #[tokio::test]
-async fn deploy_by_hash_should_return_valid_data() {
+async fn blocks_by_height_range_should_return_valid_data() {
     let database = FakeDatabase::new();
 
     let identifiers = database
@@ -126,56 +167,269 @@ async fn deploy_by_hash_should_return_valid_data() {
         .await
         .expect("Error populating FakeDatabase");
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
-    let request_path = format!("/{}/{}", DEPLOY, identifiers.deploy_accepted_hash);
+    let request_path = format!(
+        "/{}?from_height={}&to_height={}",
+        BLOCKS, identifiers.block_added_height, identifiers.block_added_height
+    );
 
     let response = request().path(&request_path).reply(&api).await;
 
     assert!(response.status().is_success());
 
     let body = response.into_body();
-    let deploy_aggregate = serde_json::from_slice::<DeployAggregate>(&body)
-        .expect("Error parsing AggregateDeployInfo from response");
+    let page = serde_json::from_slice::<BlocksPage>(&body)
+        .expect("Error parsing BlocksPage from response");
 
-    assert_eq!(
-        deploy_aggregate.deploy_hash,
-        identifiers.deploy_accepted_hash
-    );
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].get_height(), identifiers.block_added_height);
+    assert!(page.next_cursor.is_none());
 }
 
 #[tokio::test]
-async fn deploy_accepted_by_hash_should_return_valid_data() {
+async fn blocks_by_height_range_should_paginate_with_cursor() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    for height in 10..15 {
+        database
+            .save_block_added(
+                BlockAdded::random_with_height(&mut test_rng, height),
+                height as u32,
+                "127.0.0.1".to_string(),
+            )
+            .await
+            .expect("Error saving block_added");
+    }
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!("/{}?from_height=10&limit=3", BLOCKS);
+    let response = request().path(&request_path).reply(&api).await;
+    assert!(response.status().is_success());
+    let first_page = serde_json::from_slice::<BlocksPage>(response.body())
+        .expect("Error parsing BlocksPage from response");
+    let heights: Vec<u64> = first_page
+        .items
+        .iter()
+        .map(BlockAdded::get_height)
+        .collect();
+    assert_eq!(heights, vec![10, 11, 12]);
+    assert_eq!(first_page.next_cursor, Some(12));
+
+    let request_path = format!("/{}?from_height=10&limit=3&cursor=12", BLOCKS);
+    let response = request().path(&request_path).reply(&api).await;
+    assert!(response.status().is_success());
+    let second_page = serde_json::from_slice::<BlocksPage>(response.body())
+        .expect("Error parsing BlocksPage from response");
+    let heights: Vec<u64> = second_page
+        .items
+        .iter()
+        .map(BlockAdded::get_height)
+        .collect();
+    assert_eq!(heights, vec![13, 14]);
+    assert!(second_page.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn latest_blocks_and_chain_stats_should_return_valid_data() {
     let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    // Block 5 is missing, so the interval between blocks 4 and 6 is left out of the statistics.
+    for (height, millis) in [
+        (1u64, 1_000u64),
+        (2, 2_000),
+        (3, 4_000),
+        (4, 8_000),
+        (6, 20_000),
+    ] {
+        database
+            .save_block_added(
+                BlockAdded::random_with_timestamp(&mut test_rng, height, Timestamp::from(millis)),
+                height as u32,
+                "127.0.0.1".to_string(),
+            )
+            .await
+            .expect("Error saving block_added");
+    }
+
+    let api = filters::combined_filters(database.clone(), &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!("/{}?count=4", BLOCKS_LATEST);
+    let response = request().path(&request_path).reply(&api).await;
+    assert!(response.status().is_success());
+    let headers = serde_json::from_slice::<Vec<BlockHeaderSummary>>(response.body())
+        .expect("Error parsing block headers from response");
+    let heights: Vec<u64> = headers.iter().map(|header| header.height).collect();
+    assert_eq!(heights, vec![6, 4, 3, 2]);
 
-    let identifiers = database
-        .populate_with_events()
+    let request_path = format!("/{}?window=4", CHAIN_STATS);
+    let response = request().path(&request_path).reply(&api).await;
+    assert!(response.status().is_success());
+    let stats = serde_json::from_slice::<ChainStats>(response.body())
+        .expect("Error parsing ChainStats from response");
+    assert_eq!(stats.block_count, 4);
+    assert_eq!((stats.from_height, stats.to_height), (2, 6));
+    let block_interval = stats
+        .block_interval
+        .expect("Block interval should be computed");
+    assert_eq!(
+        (block_interval.min_ms, block_interval.max_ms),
+        (2_000, 4_000)
+    );
+    assert_eq!(
+        (block_interval.p50_ms, block_interval.p90_ms),
+        (2_000, 4_000)
+    );
+    assert_eq!(
+        stats.deploy_count,
+        headers
+            .iter()
+            .map(|header| header.deploy_count)
+            .sum::<u64>()
+    );
+    assert_eq!(stats.proposers.values().sum::<u64>(), 4);
+
+    // A new block invalidates the cached statistics.
+    database
+        .save_block_added(
+            BlockAdded::random_with_timestamp(&mut test_rng, 7, Timestamp::from(21_000)),
+            7,
+            "127.0.0.1".to_string(),
+        )
         .await
-        .expect("Error populating FakeDatabase");
+        .expect("Error saving block_added");
 
-    let api = filters::combined_filters(database);
+    let response = request().path(&request_path).reply(&api).await;
+    let stats = serde_json::from_slice::<ChainStats>(response.body())
+        .expect("Error parsing ChainStats from response");
+    assert_eq!((stats.from_height, stats.to_height), (3, 7));
+    assert_eq!(
+        stats.block_interval.map(|interval| interval.min_ms),
+        Some(1_000)
+    );
+}
 
-    let request_path = format!(
-        "/{}/{}/{}",
-        DEPLOY, ACCEPTED, identifiers.deploy_accepted_hash
+#[tokio::test]
+async fn deploys_by_block_should_return_valid_data() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let processed_deploy = DeployAccepted::random(&mut test_rng);
+    let missing_deploy = DeployAccepted::random(&mut test_rng);
+    let block_added = BlockAdded::random_with_deploy_hashes(
+        &mut test_rng,
+        vec![processed_deploy.deploy_hash(), missing_deploy.deploy_hash()],
+    );
+    database
+        .save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+    database
+        .save_deploy_processed(
+            DeployProcessed::random_in_block(
+                &mut test_rng,
+                processed_deploy.deploy_hash(),
+                block_added.block_hash(),
+            ),
+            2,
+            "127.0.0.1".to_string(),
+        )
+        .await
+        .expect("Error saving deploy_processed");
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    for hash_or_height in [
+        block_added.hex_encoded_hash(),
+        block_added.get_height().to_string(),
+    ] {
+        let request_path = format!("/{}/{}/{}", BLOCK, hash_or_height, DEPLOYS);
+
+        let response = request().path(&request_path).reply(&api).await;
+
+        assert!(response.status().is_success());
+
+        let block_deploys = serde_json::from_slice::<BlockDeploys>(response.body())
+            .expect("Error parsing BlockDeploys from response");
+
+        assert_eq!(block_deploys.block_hash, block_added.hex_encoded_hash());
+        assert_eq!(block_deploys.deploys.len(), 1);
+        assert_eq!(
+            block_deploys.deploys[0].deploy_hash,
+            processed_deploy.hex_encoded_hash()
+        );
+        assert_eq!(
+            block_deploys.missing_deploy_hashes,
+            vec![missing_deploy.hex_encoded_hash()]
+        );
+    }
+}
+
+#[tokio::test]
+async fn block_finality_should_return_valid_data() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let signing_keys: Vec<SecretKey> = (0..2).map(|_| SecretKey::random(&mut test_rng)).collect();
+    let missing_key = PublicKey::from(&SecretKey::random(&mut test_rng));
+    let mut validator_weights: Vec<(PublicKey, U512)> = signing_keys
+        .iter()
+        .map(|secret_key| (PublicKey::from(secret_key), U512::from(10)))
+        .collect();
+    validator_weights.push((missing_key.clone(), U512::from(5)));
+    let switch_block = BlockAdded::random_in_era(
+        &mut test_rng,
+        4,
+        10,
+        Some(JsonEraEnd::random_with_validator_weights(
+            &mut test_rng,
+            validator_weights,
+        )),
     );
+    let block_added = BlockAdded::random_in_era(&mut test_rng, 5, 11, None);
+    database
+        .save_block_added(switch_block, 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving switch block");
+    database
+        .save_block_added(block_added.clone(), 2, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+    for (event_id, secret_key) in (3..).zip(signing_keys.iter()) {
+        database
+            .save_finality_signature(
+                FinalitySignature::signed_by(block_added.block_hash(), 5, secret_key),
+                event_id,
+                "127.0.0.1".to_string(),
+            )
+            .await
+            .expect("Error saving finality_signature");
+    }
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!("/{}/{}/{}", BLOCK, block_added.hex_encoded_hash(), FINALITY);
 
     let response = request().path(&request_path).reply(&api).await;
 
     assert!(response.status().is_success());
 
-    let body = response.into_body();
-    let deploy_accepted = serde_json::from_slice::<DeployAccepted>(&body)
-        .expect("Error parsing DeployAccepted from response");
+    let block_finality = serde_json::from_slice::<BlockFinality>(response.body())
+        .expect("Error parsing BlockFinality from response");
 
+    assert_eq!(block_finality.block_hash, block_added.hex_encoded_hash());
+    assert_eq!(block_finality.era_id, 5);
+    assert_eq!(block_finality.signed_weight, "20");
+    assert_eq!(block_finality.total_weight, "25");
+    assert_eq!(block_finality.signed_weight_fraction, 0.8);
     assert_eq!(
-        deploy_accepted.hex_encoded_hash(),
-        identifiers.deploy_accepted_hash
+        block_finality.missing_validators,
+        vec![missing_key.to_hex()]
     );
+    assert!(block_finality.is_finalized);
 }
 
 #[tokio::test]
-async fn deploy_processed_by_hash_should_return_valid_data() {
+async fn deploy_by_hash_should_return_valid_data() {
     let database = FakeDatabase::new();
 
     let identifiers = database
@@ -183,29 +437,26 @@ async fn deploy_processed_by_hash_should_return_valid_data() {
         .await
         .expect("Error populating FakeDatabase");
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
-    let request_path = format!(
-        "/{}/{}/{}",
-        DEPLOY, PROCESSED, identifiers.deploy_processed_hash
-    );
+    let request_path = format!("/{}/{}", DEPLOY, identifiers.deploy_accepted_hash);
 
     let response = request().path(&request_path).reply(&api).await;
 
     assert!(response.status().is_success());
 
     let body = response.into_body();
-    let deploy_processed = serde_json::from_slice::<DeployProcessed>(&body)
-        .expect("Error parsing DeployProcessed from response");
+    let deploy_aggregate = serde_json::from_slice::<DeployAggregate>(&body)
+        .expect("Error parsing AggregateDeployInfo from response");
 
     assert_eq!(
-        deploy_processed.hex_encoded_hash(),
-        identifiers.deploy_processed_hash
+        deploy_aggregate.deploy_hash,
+        identifiers.deploy_accepted_hash
     );
 }
 
 #[tokio::test]
-async fn deploy_expired_by_hash_should_return_valid_data() {
+async fn deploy_by_hash_with_timeline_should_include_timeline() {
     let database = FakeDatabase::new();
 
     let identifiers = database
@@ -213,11 +464,11 @@ async fn deploy_expired_by_hash_should_return_valid_data() {
         .await
         .expect("Error populating FakeDatabase");
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
     let request_path = format!(
-        "/{}/{}/{}",
-        DEPLOY, EXPIRED, identifiers.deploy_expired_hash
+        "/{}/{}?timeline=true",
+        DEPLOY, identifiers.deploy_accepted_hash
     );
 
     let response = request().path(&request_path).reply(&api).await;
@@ -225,17 +476,19 @@ async fn deploy_expired_by_hash_should_return_valid_data() {
     assert!(response.status().is_success());
 
     let body = response.into_body();
-    let deploy_expired = serde_json::from_slice::<DeployExpired>(&body)
-        .expect("Error parsing DeployExpired from response");
+    let deploy_aggregate = serde_json::from_slice::<DeployAggregate>(&body)
+        .expect("Error parsing AggregateDeployInfo from response");
 
-    assert_eq!(
-        deploy_expired.hex_encoded_hash(),
-        identifiers.deploy_expired_hash
-    );
+    let timeline = deploy_aggregate
+        .timeline
+        .expect("Timeline should be included when requested");
+    assert!(timeline.accepted.is_some());
+    assert!(timeline.processed.is_none());
+    assert_eq!(timeline.accept_to_process_latency_ms, None);
 }
 
 #[tokio::test]
-async fn step_by_era_should_return_valid_data() {
+async fn deploy_by_hash_without_timeline_should_omit_timeline() {
     let database = FakeDatabase::new();
 
     let identifiers = database
@@ -243,22 +496,159 @@ async fn step_by_era_should_return_valid_data() {
         .await
         .expect("Error populating FakeDatabase");
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
-    let request_path = format!("/{}/{}", STEP, identifiers.step_era_id);
+    let request_path = format!("/{}/{}", DEPLOY, identifiers.deploy_accepted_hash);
 
     let response = request().path(&request_path).reply(&api).await;
 
     assert!(response.status().is_success());
 
     let body = response.into_body();
-    let step = serde_json::from_slice::<Step>(&body).expect("Error parsing Step from response");
+    let deploy_aggregate = serde_json::from_slice::<serde_json::Value>(&body)
+        .expect("Error parsing AggregateDeployInfo from response");
 
-    assert_eq!(step.era_id.value(), identifiers.step_era_id);
+    assert!(deploy_aggregate.get("timeline").is_none());
 }
 
 #[tokio::test]
-async fn faults_by_public_key_should_return_valid_data() {
+async fn deploy_wait_should_return_once_deploy_expired() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let deploy_accepted = DeployAccepted::random(&mut test_rng);
+    database
+        .save_deploy_accepted(deploy_accepted.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_accepted");
+    let api = filters::combined_filters(database.clone(), &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!(
+        "/{}/{}/{}?timeout_secs=60",
+        DEPLOY,
+        deploy_accepted.hex_encoded_hash(),
+        WAIT
+    );
+    let expire_deploy = async {
+        time::sleep(Duration::from_millis(200)).await;
+        let deploy_hash = deploy_accepted.deploy_hash();
+        database
+            .save_deploy_expired(
+                DeployExpired::new(deploy_hash.clone()),
+                2,
+                "127.0.0.1".to_string(),
+            )
+            .await
+            .expect("Error saving deploy_expired");
+        deploy_waiter::announce_stored_event(&SseData::DeployExpired { deploy_hash });
+    };
+    let (response, _) = time::timeout(
+        Duration::from_secs(10),
+        futures::future::join(request().path(&request_path).reply(&api), expire_deploy),
+    )
+    .await
+    .expect("Request should be woken up before its timeout");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let deploy_aggregate = serde_json::from_slice::<DeployAggregate>(response.body())
+        .expect("Error parsing AggregateDeployInfo from response");
+    assert!(deploy_aggregate.deploy_expired);
+}
+
+#[tokio::test]
+async fn deploy_wait_should_return_pending_deploy_after_timeout() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let deploy_accepted = DeployAccepted::random(&mut test_rng);
+    database
+        .save_deploy_accepted(deploy_accepted.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_accepted");
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!(
+        "/{}/{}/{}?timeout_secs=1",
+        DEPLOY,
+        deploy_accepted.hex_encoded_hash(),
+        WAIT
+    );
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let deploy_aggregate = serde_json::from_slice::<DeployAggregate>(response.body())
+        .expect("Error parsing AggregateDeployInfo from response");
+    assert!(deploy_aggregate.deploy_processed.is_none());
+    assert!(!deploy_aggregate.deploy_expired);
+}
+
+#[tokio::test]
+async fn deploy_wait_beyond_max_concurrent_deploy_waits_should_return_503() {
+    let config = RestServerConfig {
+        max_concurrent_deploy_waits: Some(1),
+        ..RestServerConfig::default()
+    };
+    let api = filters::combined_filters(FakeDatabase::new(), &config, Vec::new());
+    let request_path = format!("/{}/{}/{}?timeout_secs=5", DEPLOY, VALID_HASH, WAIT);
+
+    let parked_api = api.clone();
+    let parked_request_path = request_path.clone();
+    let parked_wait = tokio::spawn(async move {
+        request()
+            .path(&parked_request_path)
+            .reply(&parked_api)
+            .await
+    });
+    time::sleep(Duration::from_millis(200)).await;
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    parked_wait.abort();
+}
+
+#[tokio::test]
+async fn block_should_be_served_while_deploy_waits_are_parked() {
+    let config = RestServerConfig {
+        max_concurrent_requests: 1,
+        ..RestServerConfig::default()
+    };
+    let api = filters::combined_filters(FakeDatabase::new(), &config, Vec::new());
+    let service = limit_requests(warp::service(api), &config);
+
+    let parked_waits: Vec<_> = (0..2)
+        .map(|_| {
+            let mut service = service.clone();
+            let wait_request = Request::get(format!(
+                "/{}/{}/{}?timeout_secs=5",
+                DEPLOY, VALID_HASH, WAIT
+            ))
+            .body(Body::empty())
+            .unwrap();
+            tokio::spawn(async move {
+                poll_fn(|cx| service.poll_ready(cx)).await.unwrap();
+                service.call(wait_request).await
+            })
+        })
+        .collect();
+    time::sleep(Duration::from_millis(200)).await;
+    let mut service = service.clone();
+    let block_request = Request::get(format!("/{}", BLOCK))
+        .body(Body::empty())
+        .unwrap();
+    let response = time::timeout(Duration::from_secs(2), async {
+        poll_fn(|cx| service.poll_ready(cx)).await.unwrap();
+        service.call(block_request).await
+    })
+    .await
+    .expect("Block request shouldn't wait for the parked deploy waits")
+    .expect("Error serving block request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    for parked_wait in parked_waits {
+        parked_wait.abort();
+    }
+}
+
+#[tokio::test]
+async fn deploy_accepted_by_hash_should_return_valid_data() {
     let database = FakeDatabase::new();
 
     let identifiers = database
@@ -266,23 +656,29 @@ async fn faults_by_public_key_should_return_valid_data() {
         .await
         .expect("Error populating FakeDatabase");
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
-    let request_path = format!("/{}/{}", FAULTS, identifiers.fault_public_key);
+    let request_path = format!(
+        "/{}/{}/{}",
+        DEPLOY, ACCEPTED, identifiers.deploy_accepted_hash
+    );
 
     let response = request().path(&request_path).reply(&api).await;
 
     assert!(response.status().is_success());
 
     let body = response.into_body();
-    let faults =
-        serde_json::from_slice::<Vec<Fault>>(&body).expect("Error parsing Fault from response");
+    let deploy_accepted = serde_json::from_slice::<DeployAccepted>(&body)
+        .expect("Error parsing DeployAccepted from response");
 
-    assert_eq!(faults[0].public_key.to_hex(), identifiers.fault_public_key);
+    assert_eq!(
+        deploy_accepted.hex_encoded_hash(),
+        identifiers.deploy_accepted_hash
+    );
 }
 
 #[tokio::test]
-async fn faults_by_era_should_return_valid_data() {
+async fn deploy_processed_by_hash_should_return_valid_data() {
     let database = FakeDatabase::new();
 
     let identifiers = database
@@ -290,23 +686,29 @@ async fn faults_by_era_should_return_valid_data() {
         .await
         .expect("Error populating FakeDatabase");
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
-    let request_path = format!("/{}/{}", FAULTS, identifiers.fault_era_id);
+    let request_path = format!(
+        "/{}/{}/{}",
+        DEPLOY, PROCESSED, identifiers.deploy_processed_hash
+    );
 
     let response = request().path(&request_path).reply(&api).await;
 
     assert!(response.status().is_success());
 
     let body = response.into_body();
-    let faults =
-        serde_json::from_slice::<Vec<Fault>>(&body).expect("Error parsing Fault from response");
+    let deploy_processed = serde_json::from_slice::<DeployProcessed>(&body)
+        .expect("Error parsing DeployProcessed from response");
 
-    assert_eq!(faults[0].era_id.value(), identifiers.fault_era_id);
+    assert_eq!(
+        deploy_processed.hex_encoded_hash(),
+        identifiers.deploy_processed_hash
+    );
 }
 
 #[tokio::test]
-async fn finality_signatures_by_block_should_return_valid_data() {
+async fn deploy_expired_by_hash_should_return_valid_data() {
     let database = FakeDatabase::new();
 
     let identifiers = database
@@ -314,11 +716,11 @@ async fn finality_signatures_by_block_should_return_valid_data() {
         .await
         .expect("Error populating FakeDatabase");
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
     let request_path = format!(
-        "/{}/{}",
-        SIGNATURES, identifiers.finality_signatures_block_hash
+        "/{}/{}/{}",
+        DEPLOY, EXPIRED, identifiers.deploy_expired_hash
     );
 
     let response = request().path(&request_path).reply(&api).await;
@@ -326,88 +728,1022 @@ async fn finality_signatures_by_block_should_return_valid_data() {
     assert!(response.status().is_success());
 
     let body = response.into_body();
-    let finality_signatures = serde_json::from_slice::<Vec<FinSig>>(&body)
-        .expect("Error parsing FinalitySignatures from response");
+    let deploy_expired = serde_json::from_slice::<DeployExpired>(&body)
+        .expect("Error parsing DeployExpired from response");
 
     assert_eq!(
-        hex::encode(finality_signatures[0].block_hash().inner()),
-        identifiers.finality_signatures_block_hash
+        deploy_expired.hex_encoded_hash(),
+        identifiers.deploy_expired_hash
     );
 }
 
 #[tokio::test]
-async fn block_by_hash_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}", BLOCK, VALID_HASH);
+async fn deploys_by_account_should_return_valid_data() {
+    let database = FakeDatabase::new();
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
-}
+    let identifiers = database
+        .populate_with_events()
+        .await
+        .expect("Error populating FakeDatabase");
 
-#[tokio::test]
-async fn block_by_height_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}", BLOCK, 0);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
-}
+    let request_path = format!(
+        "/{}/{}",
+        DEPLOYS_BY_ACCOUNT, identifiers.deploy_accepted_account
+    );
 
-#[tokio::test]
-async fn deploy_by_hash_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}", DEPLOY, VALID_HASH);
+    let response = request().path(&request_path).reply(&api).await;
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
-}
+    assert!(response.status().is_success());
 
-#[tokio::test]
-async fn deploy_accepted_by_hash_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}/{}", DEPLOY, ACCEPTED, VALID_HASH);
+    let body = response.into_body();
+    let page = serde_json::from_slice::<DeploySummariesPage>(&body)
+        .expect("Error parsing DeploySummariesPage from response");
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].deploy_hash, identifiers.deploy_accepted_hash);
+    assert_eq!(page.items[0].status, DeployStatus::Accepted);
+    assert!(page.next_cursor.is_none());
 }
 
 #[tokio::test]
-async fn deploy_processed_by_hash_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}/{}", DEPLOY, PROCESSED, VALID_HASH);
+async fn deploys_by_account_should_paginate_with_cursor() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let secret_key = SecretKey::random(&mut test_rng);
+    for event_id in 0..3 {
+        database
+            .save_deploy_accepted(
+                DeployAccepted::random_with_secret_key(&mut test_rng, &secret_key),
+                event_id,
+                "127.0.0.1".to_string(),
+            )
+            .await
+            .expect("Error saving deploy_accepted");
+    }
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let account = PublicKey::from(&secret_key).to_hex();
+    let request_path = format!("/{}/{}?limit=2", DEPLOYS_BY_ACCOUNT, account);
+    let response = request().path(&request_path).reply(&api).await;
+    assert!(response.status().is_success());
+    let first_page = serde_json::from_slice::<DeploySummariesPage>(response.body())
+        .expect("Error parsing DeploySummariesPage from response");
+    assert_eq!(first_page.items.len(), 2);
+    let cursor = first_page
+        .next_cursor
+        .expect("Expected a cursor of the next page");
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+    let request_path = format!(
+        "/{}/{}?limit=2&cursor={}",
+        DEPLOYS_BY_ACCOUNT, account, cursor
+    );
+    let response = request().path(&request_path).reply(&api).await;
+    assert!(response.status().is_success());
+    let second_page = serde_json::from_slice::<DeploySummariesPage>(response.body())
+        .expect("Error parsing DeploySummariesPage from response");
+    assert_eq!(second_page.items.len(), 1);
+    assert!(second_page.next_cursor.is_none());
 }
 
 #[tokio::test]
-async fn deploy_expired_by_hash_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}/{}", DEPLOY, EXPIRED, VALID_HASH);
+async fn failed_deploys_should_return_deploys_with_matching_error_message() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let block_added = BlockAdded::random_with_height(&mut test_rng, 10);
+    database
+        .save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+    let out_of_gas =
+        DeployProcessed::random_failed(&mut test_rng, block_added.block_hash(), "Out of gas");
+    let unknown_entry_point = DeployProcessed::random_failed(
+        &mut test_rng,
+        block_added.block_hash(),
+        "Unknown entry point",
+    );
+    for (event_id, deploy_processed) in [(2, &out_of_gas), (3, &unknown_entry_point)].iter() {
+        database
+            .save_deploy_processed(
+                (*deploy_processed).clone(),
+                *event_id,
+                "127.0.0.1".to_string(),
+            )
+            .await
+            .expect("Error saving deploy_processed");
+    }
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+    let request_path = format!(
+        "/{}?error_contains=out%20of%20GAS&from_height=10&to_height=10",
+        DEPLOYS_FAILED
+    );
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    let page = serde_json::from_slice::<FailedDeploysPage>(response.body())
+        .expect("Error parsing FailedDeploysPage from response");
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].deploy_hash, out_of_gas.hex_encoded_hash());
+    assert_eq!(page.items[0].block_height, Some(10));
+    assert_eq!(page.items[0].error_message, "Out of gas");
+    assert!(page.next_cursor.is_none());
 }
 
 #[tokio::test]
-async fn faults_by_public_key_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}", FAULTS, VALID_PUBLIC_KEY);
+async fn transfers_by_account_and_deploy_should_return_valid_data() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let account = AccountHash::new(test_rng.gen());
+    let block_added = BlockAdded::random_with_height(&mut test_rng, 10);
+    database
+        .save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+    let transfer = Transfer::new(
+        casper_types::DeployHash::new(test_rng.gen()),
+        account,
+        None,
+        URef::new(test_rng.gen(), AccessRights::READ_ADD_WRITE),
+        URef::new(test_rng.gen(), AccessRights::READ_ADD_WRITE),
+        U512::from(100u64),
+        U512::zero(),
+        Some(7),
+    );
+    let deploy_processed = DeployProcessed::random_with_transfers(
+        &mut test_rng,
+        block_added.block_hash(),
+        vec![transfer],
+    );
+    database
+        .save_deploy_processed(deploy_processed.clone(), 2, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_processed");
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
-}
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
-#[tokio::test]
-async fn faults_by_era_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}", FAULTS, VALID_ERA);
+    let request_path = format!(
+        "/{}?account={}&from_height=10",
+        TRANSFERS,
+        account.to_formatted_string()
+    );
+    let response = request().path(&request_path).reply(&api).await;
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
-}
+    assert!(response.status().is_success());
+    let page = serde_json::from_slice::<TransfersPage>(response.body())
+        .expect("Error parsing TransfersPage from response");
+    assert_eq!(
+        page.items,
+        vec![NativeTransfer {
+            deploy_hash: deploy_processed.hex_encoded_hash(),
+            block_hash: block_added.hex_encoded_hash(),
+            block_height: Some(10),
+            from: hex::encode(account.value()),
+            to: None,
+            amount: "100".to_string(),
+            transfer_id: Some(7),
+        }]
+    );
+    assert!(page.next_cursor.is_none());
 
-#[tokio::test]
-async fn finality_signature_by_block_hash_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}", SIGNATURES, VALID_HASH);
+    let request_path = format!(
+        "/{}/{}/{}",
+        DEPLOY,
+        deploy_processed.hex_encoded_hash(),
+        TRANSFERS
+    );
+    let response = request().path(&request_path).reply(&api).await;
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+    assert!(response.status().is_success());
+    let transfers = serde_json::from_slice::<Vec<NativeTransfer>>(response.body())
+        .expect("Error parsing transfers from response");
+    assert_eq!(transfers, page.items);
 }
 
-#[tokio::test]
-async fn step_by_era_of_not_stored_should_return_404() {
-    let request_path = format!("/{}/{}", STEP, VALID_ERA);
+async fn request_event_log(api_path: String, database: FakeDatabase) -> EventLogPage {
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
-    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+    let response = request().path(&api_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    serde_json::from_slice::<EventLogPage>(response.body())
+        .expect("Error parsing EventLogPage from response")
 }
 
 #[tokio::test]
-async fn block_by_invalid_hash_should_return_400() {
-    let request_path = format!("/{}/{}", BLOCK, INVALID_HASH);
+async fn event_log_should_return_valid_data() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let block_added = BlockAdded::random(&mut test_rng);
+    let deploy_accepted = DeployAccepted::random(&mut test_rng);
+    let step = Step::random(&mut test_rng);
+    database
+        .save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+    database
+        .save_deploy_accepted(deploy_accepted.clone(), 1, "127.0.0.2".to_string())
+        .await
+        .expect("Error saving deploy_accepted");
+    database
+        .save_step(step, 2, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving step");
+
+    let first_page = request_event_log(
+        format!("/{}?source=127.0.0.1&limit=1", EVENT_LOG),
+        database.clone(),
+    )
+    .await;
+
+    assert_eq!(first_page.items.len(), 1);
+    assert_eq!(first_page.items[0].event_type, "BlockAdded");
+    assert_eq!(
+        first_page.items[0].event_key,
+        block_added.hex_encoded_hash()
+    );
+    assert_eq!(
+        first_page.items[0].payload,
+        Some(serde_json::to_value(&block_added).unwrap())
+    );
+    let next_cursor = first_page.next_cursor.expect("Expected a next page");
+
+    let second_page = request_event_log(
+        format!("/{}?source=127.0.0.1&cursor={}", EVENT_LOG, next_cursor),
+        database.clone(),
+    )
+    .await;
+
+    assert_eq!(second_page.items.len(), 1);
+    assert_eq!(second_page.items[0].event_type, "Step");
+    assert_eq!(second_page.items[0].event_id, 2);
+    assert!(second_page.next_cursor.is_none());
+
+    let accepted_page =
+        request_event_log(format!("/{}?type=DeployAccepted", EVENT_LOG), database).await;
+
+    assert_eq!(accepted_page.items.len(), 1);
+    assert_eq!(accepted_page.items[0].event_source_address, "127.0.0.2");
+    assert_eq!(
+        accepted_page.items[0].event_key,
+        deploy_accepted.hex_encoded_hash()
+    );
+}
+
+#[tokio::test]
+async fn nodes_should_return_valid_data() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    database
+        .save_block_added(
+            BlockAdded::random(&mut test_rng),
+            1,
+            "http://127.0.0.1:18101/".to_string(),
+        )
+        .await
+        .expect("Error saving block_added");
+    database
+        .save_shutdown(2, "http://127.0.0.1:18101/".to_string())
+        .await
+        .expect("Error saving shutdown");
+    let nodes = vec![
+        ("127.0.0.1".to_string(), 18101),
+        ("127.0.0.2".to_string(), 18101),
+    ];
+    let api = filters::combined_filters(database, &RestServerConfig::default(), nodes);
+
+    let response = request().path(&format!("/{}", NODES)).reply(&api).await;
+
+    assert!(response.status().is_success());
+    let reports = serde_json::from_slice::<serde_json::Value>(response.body())
+        .expect("Error parsing node reports from response");
+    assert_eq!(reports[0]["address"], "127.0.0.1:18101");
+    assert_eq!(reports[0]["events"]["events_received"]["BlockAdded"], 1);
+    assert_eq!(reports[0]["events"]["events_received"]["Shutdown"], 1);
+    assert_eq!(
+        reports[0]["events"]["shutdowns"].as_array().map(Vec::len),
+        Some(1)
+    );
+    assert_eq!(reports[1]["address"], "127.0.0.2:18101");
+    assert_eq!(reports[1]["status"], "Preparing");
+    assert_eq!(reports[1]["node_build_version"], serde_json::Value::Null);
+    assert_eq!(reports[1]["events"]["last_seen"], serde_json::Value::Null);
+
+    let request_path = format!("/{}/127.0.0.2:18101", NODES);
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    let report = serde_json::from_slice::<serde_json::Value>(response.body())
+        .expect("Error parsing node report from response");
+    assert_eq!(report["address"], "127.0.0.2:18101");
+}
+
+#[tokio::test]
+async fn node_by_address_should_return_404_for_unknown_node() {
+    let nodes = vec![("127.0.0.1".to_string(), 18101)];
+    let api = filters::combined_filters(FakeDatabase::new(), &RestServerConfig::default(), nodes);
+
+    let request_path = format!("/{}/127.0.0.1:18102", NODES);
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+async fn request_search(query: &str, database: FakeDatabase) -> serde_json::Value {
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!("/{}/{}", SEARCH, query);
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    serde_json::from_slice(response.body()).expect("Error parsing search result from response")
+}
+
+#[tokio::test]
+async fn search_should_resolve_hashes_public_keys_and_era_ids() {
+    let database = FakeDatabase::new();
+    let identifiers = database
+        .populate_with_events()
+        .await
+        .expect("Error populating FakeDatabase");
+    let mut test_rng = TestRng::new();
+    let block_added = BlockAdded::random(&mut test_rng);
+    database
+        .save_block_added(block_added.clone(), 1, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving block_added");
+
+    let result = request_search(
+        &identifiers.block_added_hash.to_uppercase(),
+        database.clone(),
+    )
+    .await;
+
+    assert_eq!(result["query"], identifiers.block_added_hash);
+    assert_eq!(result["matches"][0]["type"], "Block");
+    assert_eq!(
+        result["matches"][0]["links"][0],
+        format!("/block/{}", identifiers.block_added_hash)
+    );
+
+    let result = request_search(&identifiers.deploy_processed_hash, database.clone()).await;
+
+    assert_eq!(result["matches"][0]["type"], "Deploy");
+    assert_eq!(
+        result["matches"][0]["links"],
+        serde_json::json!([
+            format!("/deploy/{}", identifiers.deploy_processed_hash),
+            format!("/deploy/processed/{}", identifiers.deploy_processed_hash)
+        ])
+    );
+
+    let result = request_search(&identifiers.fault_public_key, database.clone()).await;
+
+    assert_eq!(result["matches"][0]["type"], "Validator");
+    assert_eq!(
+        result["matches"][0]["links"][2],
+        format!("/faults/{}", identifiers.fault_public_key)
+    );
+
+    let era_id = block_added.get_era_id();
+    let result = request_search(&era_id.to_string(), database).await;
+
+    assert_eq!(result["matches"][0]["type"], "Era");
+    assert_eq!(result["matches"][0]["links"][0], format!("/era/{}", era_id));
+}
+
+#[tokio::test]
+async fn search_should_return_no_matches_for_unknown_hash() {
+    let result = request_search(VALID_HASH, FakeDatabase::new()).await;
+
+    assert_eq!(result["matches"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn search_should_return_400_for_invalid_query() {
+    let request_path = format!("/{}/{}", SEARCH, INVALID_HASH);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await;
+}
+
+#[tokio::test]
+async fn deploys_by_contract_should_return_valid_data() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let contract_hash = ContractHash::new(test_rng.gen());
+    let mut contract_calls = vec![];
+    for entry_point in ["transfer", "approve"].iter() {
+        let deploy_accepted = DeployAccepted::random_with_session(
+            &mut test_rng,
+            ExecutableDeployItem::StoredContractByHash {
+                hash: contract_hash,
+                entry_point: entry_point.to_string(),
+                args: RuntimeArgs::new(),
+            },
+        );
+        database
+            .save_deploy_accepted(deploy_accepted.clone(), 1, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving deploy_accepted");
+        contract_calls.push(deploy_accepted);
+    }
+    let deploy_processed =
+        DeployProcessed::random(&mut test_rng, Some(contract_calls[0].deploy_hash()));
+    database
+        .save_deploy_processed(deploy_processed.clone(), 2, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving deploy_processed");
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!(
+        "/{}/{}/{}?entry_point=transfer",
+        CONTRACTS,
+        hex::encode(contract_hash.value()),
+        DEPLOYS
+    );
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    let page = serde_json::from_slice::<ContractDeploysPage>(response.body())
+        .expect("Error parsing ContractDeploysPage from response");
+    assert_eq!(
+        page.items,
+        vec![ContractDeploy {
+            deploy_hash: contract_calls[0].hex_encoded_hash(),
+            contract_hash: Some(hex::encode(contract_hash.value())),
+            contract_package_hash: None,
+            entry_point: "transfer".to_string(),
+            status: DeployStatus::Processed,
+            is_success: Some(deploy_processed.is_success()),
+            block_hash: Some(deploy_processed.hex_encoded_block_hash()),
+        }]
+    );
+    assert!(page.next_cursor.is_none());
+
+    let request_path = format!(
+        "/{}/{}/{}",
+        CONTRACTS,
+        hex::encode(contract_hash.value()),
+        DEPLOYS
+    );
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    let page = serde_json::from_slice::<ContractDeploysPage>(response.body())
+        .expect("Error parsing ContractDeploysPage from response");
+    assert_eq!(page.items.len(), 2);
+}
+
+#[tokio::test]
+async fn step_by_era_should_return_valid_data() {
+    let database = FakeDatabase::new();
+
+    let identifiers = database
+        .populate_with_events()
+        .await
+        .expect("Error populating FakeDatabase");
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!("/{}/{}", STEP, identifiers.step_era_id);
+
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+
+    let body = response.into_body();
+    let step = serde_json::from_slice::<Step>(&body).expect("Error parsing Step from response");
+
+    assert_eq!(step.era_id.value(), identifiers.step_era_id);
+}
+
+#[tokio::test]
+async fn faults_by_public_key_should_return_valid_data() {
+    let database = FakeDatabase::new();
+
+    let identifiers = database
+        .populate_with_events()
+        .await
+        .expect("Error populating FakeDatabase");
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!("/{}/{}", FAULTS, identifiers.fault_public_key);
+
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+
+    let body = response.into_body();
+    let faults =
+        serde_json::from_slice::<Vec<Fault>>(&body).expect("Error parsing Fault from response");
+
+    assert_eq!(faults[0].public_key.to_hex(), identifiers.fault_public_key);
+}
+
+#[tokio::test]
+async fn faults_by_era_should_return_valid_data() {
+    let database = FakeDatabase::new();
+
+    let identifiers = database
+        .populate_with_events()
+        .await
+        .expect("Error populating FakeDatabase");
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!("/{}/{}", FAULTS, identifiers.fault_era_id);
+
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+
+    let body = response.into_body();
+    let faults =
+        serde_json::from_slice::<Vec<Fault>>(&body).expect("Error parsing Fault from response");
+
+    assert_eq!(faults[0].era_id.value(), identifiers.fault_era_id);
+}
+
+#[tokio::test]
+async fn finality_signatures_by_block_should_return_valid_data() {
+    let database = FakeDatabase::new();
+
+    let identifiers = database
+        .populate_with_events()
+        .await
+        .expect("Error populating FakeDatabase");
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!(
+        "/{}/{}",
+        SIGNATURES, identifiers.finality_signatures_block_hash
+    );
+
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+
+    let body = response.into_body();
+    let finality_signatures = serde_json::from_slice::<Vec<FinSig>>(&body)
+        .expect("Error parsing FinalitySignatures from response");
+
+    assert_eq!(
+        hex::encode(finality_signatures[0].block_hash().inner()),
+        identifiers.finality_signatures_block_hash
+    );
+}
+
+#[tokio::test]
+async fn era_summary_should_return_valid_data() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let era_end = JsonEraEnd::random_with_validator_weights(&mut test_rng, vec![]);
+    let blocks = vec![
+        BlockAdded::random_in_era(&mut test_rng, 4, 10, None),
+        BlockAdded::random_in_era(&mut test_rng, 4, 11, None),
+        BlockAdded::random_in_era(&mut test_rng, 4, 12, Some(era_end.clone())),
+        BlockAdded::random_in_era(&mut test_rng, 5, 13, None),
+    ];
+    for (event_id, block_added) in (1..).zip(blocks.iter()) {
+        database
+            .save_block_added(block_added.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving block_added");
+    }
+    let mut step = Step::random(&mut test_rng);
+    step.era_id = EraId::new(4);
+    database
+        .save_step(step, 5, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving step");
+    let mut fault = Fault::random(&mut test_rng);
+    fault.era_id = EraId::new(4);
+    database
+        .save_fault(fault.clone(), 6, "127.0.0.1".to_string())
+        .await
+        .expect("Error saving fault");
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!("/{}/{}", ERA, 4);
+
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+
+    let era_summary = serde_json::from_slice::<EraSummary>(response.body())
+        .expect("Error parsing EraSummary from response");
+
+    assert_eq!(era_summary.era_id, 4);
+    assert_eq!(era_summary.first_block_height, 10);
+    assert_eq!(era_summary.last_block_height, 12);
+    assert_eq!(era_summary.block_count, 3);
+    assert_eq!(
+        era_summary
+            .switch_block
+            .expect("Switch block should be part of the summary")
+            .hex_encoded_hash(),
+        blocks[2].hex_encoded_hash()
+    );
+    assert_eq!(era_summary.era_report.as_ref(), Some(era_end.era_report()));
+    assert_eq!(
+        era_summary
+            .step
+            .expect("Step should be part of the summary")
+            .era_id,
+        EraId::new(4)
+    );
+    assert_eq!(era_summary.faults.len(), 1);
+    assert_eq!(era_summary.faults[0].public_key, fault.public_key);
+}
+
+#[tokio::test]
+async fn era_summary_of_era_in_progress_should_omit_switch_block() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    database
+        .save_block_added(
+            BlockAdded::random_in_era(&mut test_rng, 5, 13, None),
+            1,
+            "127.0.0.1".to_string(),
+        )
+        .await
+        .expect("Error saving block_added");
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let response = request().path(&format!("/{}/{}", ERA, 5)).reply(&api).await;
+
+    assert!(response.status().is_success());
+
+    let era_summary = serde_json::from_slice::<EraSummary>(response.body())
+        .expect("Error parsing EraSummary from response");
+
+    assert_eq!(era_summary.block_count, 1);
+    assert!(era_summary.switch_block.is_none());
+    assert!(era_summary.era_report.is_none());
+    assert!(era_summary.step.is_none());
+    assert!(era_summary.faults.is_empty());
+}
+
+#[tokio::test]
+async fn validator_rewards_and_weights_should_return_valid_data_within_era_range() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let validator = PublicKey::from(&SecretKey::random(&mut test_rng));
+    for (era_id, height) in [(4u64, 10u64), (5, 20), (6, 30)].iter() {
+        let era_end = JsonEraEnd::random_with_rewards_and_validator_weights(
+            &mut test_rng,
+            vec![(validator.clone(), era_id * 100)],
+            vec![(validator.clone(), U512::from(era_id * 1000))],
+        );
+        database
+            .save_block_added(
+                BlockAdded::random_in_era(&mut test_rng, *era_id, *height, Some(era_end)),
+                *height as u32,
+                "127.0.0.1".to_string(),
+            )
+            .await
+            .expect("Error saving block_added");
+    }
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!(
+        "/{}/{}/{}?from_era=5",
+        VALIDATORS,
+        validator.to_hex(),
+        REWARDS
+    );
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    let rewards = serde_json::from_slice::<Vec<ValidatorEraReward>>(response.body())
+        .expect("Error parsing rewards from response");
+    assert_eq!(
+        rewards,
+        vec![
+            ValidatorEraReward {
+                era_id: 5,
+                amount: 500,
+            },
+            ValidatorEraReward {
+                era_id: 6,
+                amount: 600,
+            },
+        ]
+    );
+
+    let request_path = format!(
+        "/{}/{}/{}?from_era=5&to_era=6",
+        VALIDATORS,
+        validator.to_hex(),
+        WEIGHTS
+    );
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    let weights = serde_json::from_slice::<Vec<ValidatorEraWeight>>(response.body())
+        .expect("Error parsing weights from response");
+    assert_eq!(
+        weights,
+        vec![
+            ValidatorEraWeight {
+                era_id: 5,
+                weight: "4000".to_string(),
+            },
+            ValidatorEraWeight {
+                era_id: 6,
+                weight: "5000".to_string(),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn block_by_hash_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}", BLOCK, VALID_HASH);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn block_by_height_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}", BLOCK, 0);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn block_finality_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}/{}", BLOCK, VALID_HASH, FINALITY);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn deploys_by_block_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}/{}", BLOCK, VALID_HASH, DEPLOYS);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn deploy_by_hash_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}", DEPLOY, VALID_HASH);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn deploy_wait_of_not_stored_should_return_404_after_timeout() {
+    let request_path = format!("/{}/{}/{}?timeout_secs=0", DEPLOY, VALID_HASH, WAIT);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await;
+}
+
+#[tokio::test]
+async fn deploy_accepted_by_hash_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}/{}", DEPLOY, ACCEPTED, VALID_HASH);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn deploy_processed_by_hash_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}/{}", DEPLOY, PROCESSED, VALID_HASH);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn deploy_expired_by_hash_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}/{}", DEPLOY, EXPIRED, VALID_HASH);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn faults_by_public_key_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}", FAULTS, VALID_PUBLIC_KEY);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn faults_by_era_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}", FAULTS, VALID_ERA);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn validator_signatures_and_participation_should_return_valid_data() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let secret_key = SecretKey::random(&mut test_rng);
+    let validator = PublicKey::from(&secret_key);
+    let era_end = JsonEraEnd::random_with_validator_weights(
+        &mut test_rng,
+        vec![(validator.clone(), U512::from(1000))],
+    );
+    let blocks = vec![
+        BlockAdded::random_in_era(&mut test_rng, 4, 9, Some(era_end)),
+        BlockAdded::random_in_era(&mut test_rng, 5, 10, None),
+        BlockAdded::random_in_era(&mut test_rng, 5, 11, None),
+        BlockAdded::random_in_era(&mut test_rng, 5, 12, None),
+    ];
+    for (event_id, block_added) in (1..).zip(blocks.iter()) {
+        database
+            .save_block_added(block_added.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving block_added");
+    }
+    for (event_id, block_added) in (10..).zip([&blocks[1], &blocks[3]]) {
+        database
+            .save_finality_signature(
+                FinalitySignature::signed_by(block_added.block_hash(), 5, &secret_key),
+                event_id,
+                "127.0.0.1".to_string(),
+            )
+            .await
+            .expect("Error saving finality_signature");
+    }
+
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let request_path = format!(
+        "/{}/{}/{}?limit=1",
+        VALIDATORS,
+        validator.to_hex(),
+        SIGNATURES
+    );
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    let page = serde_json::from_slice::<ValidatorSignaturesPage>(response.body())
+        .expect("Error parsing signatures page from response");
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].block_height, 10);
+    assert!(page.items[0].signed);
+    assert_eq!(page.next_cursor, Some(10));
+
+    let request_path = format!(
+        "/{}/{}/{}?limit=1&cursor=10",
+        VALIDATORS,
+        validator.to_hex(),
+        SIGNATURES
+    );
+    let response = request().path(&request_path).reply(&api).await;
+
+    let page = serde_json::from_slice::<ValidatorSignaturesPage>(response.body())
+        .expect("Error parsing signatures page from response");
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].block_height, 11);
+    assert!(!page.items[0].signed);
+    assert_eq!(page.items[0].finality_signature, None);
+    assert_eq!(page.next_cursor, Some(11));
+
+    let request_path = format!(
+        "/{}/{}/{}?limit=1&cursor=11",
+        VALIDATORS,
+        validator.to_hex(),
+        SIGNATURES
+    );
+    let response = request().path(&request_path).reply(&api).await;
+
+    let page = serde_json::from_slice::<ValidatorSignaturesPage>(response.body())
+        .expect("Error parsing signatures page from response");
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].block_height, 12);
+    assert!(page.items[0].signed);
+    assert_eq!(page.next_cursor, None);
+
+    let request_path = format!("/{}/{}/{}", VALIDATORS, validator.to_hex(), PARTICIPATION);
+    let response = request().path(&request_path).reply(&api).await;
+
+    assert!(response.status().is_success());
+    let participation = serde_json::from_slice::<Vec<ValidatorEraParticipation>>(response.body())
+        .expect("Error parsing participation from response");
+    assert_eq!(
+        participation,
+        vec![ValidatorEraParticipation {
+            era_id: 5,
+            weight: "1000".to_string(),
+            expected_blocks: 3,
+            signed_blocks: 2,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn era_summary_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}", ERA, VALID_ERA);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn validator_rewards_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}/{}", VALIDATORS, VALID_PUBLIC_KEY, REWARDS);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn validator_weights_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}/{}", VALIDATORS, VALID_PUBLIC_KEY, WEIGHTS);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn validator_participation_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}/{}", VALIDATORS, VALID_PUBLIC_KEY, PARTICIPATION);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn finality_signature_by_block_hash_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}", SIGNATURES, VALID_HASH);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn step_by_era_of_not_stored_should_return_404() {
+    let request_path = format!("/{}/{}", STEP, VALID_ERA);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn block_by_invalid_hash_should_return_400() {
+    let request_path = format!("/{}/{}", BLOCK, INVALID_HASH);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn block_finality_of_invalid_hash_should_return_400() {
+    let request_path = format!("/{}/{}/{}", BLOCK, INVALID_HASH, FINALITY);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn deploys_by_block_of_invalid_hash_should_return_400() {
+    let request_path = format!("/{}/{}/{}", BLOCK, INVALID_HASH, DEPLOYS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn blocks_with_invalid_limit_should_return_400() {
+    let request_path = format!("/{}?limit=0", BLOCKS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await;
+
+    let request_path = format!("/{}?limit=1001", BLOCKS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn latest_blocks_and_chain_stats_with_invalid_count_should_return_400() {
+    let request_path = format!("/{}?count=0", BLOCKS_LATEST);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await;
+
+    let request_path = format!("/{}?window=1001", CHAIN_STATS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn chain_stats_without_stored_blocks_should_return_404() {
+    let request_path = format!("/{}", CHAIN_STATS);
+
+    should_respond_to_path_with(request_path, StatusCode::NOT_FOUND).await
+}
+
+#[tokio::test]
+async fn blocks_with_inverted_range_should_return_400() {
+    let request_path = format!("/{}?from_height=10&to_height=5", BLOCKS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn blocks_with_malformed_query_should_return_400() {
+    let request_path = format!("/{}?from_height=not_a_number", BLOCKS);
 
     should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
 }
@@ -419,6 +1755,15 @@ async fn deploy_by_hash_of_invalid_should_return_400() {
     should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
 }
 
+#[tokio::test]
+async fn deploy_wait_with_invalid_hash_or_timeout_should_return_400() {
+    let request_path = format!("/{}/{}/{}", DEPLOY, INVALID_HASH, WAIT);
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await;
+
+    let request_path = format!("/{}/{}/{}?timeout_secs=301", DEPLOY, VALID_HASH, WAIT);
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await;
+}
+
 #[tokio::test]
 async fn deploy_accepted_by_hash_of_invalid_should_return_400() {
     let request_path = format!("/{}/{}/{}", DEPLOY, ACCEPTED, INVALID_HASH);
@@ -447,6 +1792,124 @@ async fn faults_by_invalid_public_key_should_return_400() {
     should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
 }
 
+#[tokio::test]
+async fn deploys_by_invalid_account_should_return_400() {
+    let request_path = format!("/{}/{}", DEPLOYS_BY_ACCOUNT, INVALID_PUBLIC_KEY);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn deploys_by_account_with_invalid_limit_should_return_400() {
+    let request_path = format!("/{}/{}?limit=0", DEPLOYS_BY_ACCOUNT, VALID_PUBLIC_KEY);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn validator_rewards_of_invalid_public_key_should_return_400() {
+    let request_path = format!("/{}/{}/{}", VALIDATORS, INVALID_PUBLIC_KEY, REWARDS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn validator_signatures_of_invalid_public_key_should_return_400() {
+    let request_path = format!("/{}/{}/{}", VALIDATORS, INVALID_PUBLIC_KEY, SIGNATURES);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn validator_weights_with_inverted_era_range_should_return_400() {
+    let request_path = format!(
+        "/{}/{}/{}?from_era=10&to_era=5",
+        VALIDATORS, VALID_PUBLIC_KEY, WEIGHTS
+    );
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn failed_deploys_with_inverted_range_should_return_400() {
+    let request_path = format!("/{}?from_height=10&to_height=5", DEPLOYS_FAILED);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn failed_deploys_with_invalid_limit_should_return_400() {
+    let request_path = format!("/{}?limit=1001", DEPLOYS_FAILED);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn transfers_by_invalid_account_should_return_400() {
+    let request_path = format!("/{}?account={}", TRANSFERS, INVALID_HASH);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn transfers_without_account_should_return_400() {
+    let request_path = format!("/{}?from_height=10", TRANSFERS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn transfers_by_invalid_deploy_hash_should_return_400() {
+    let request_path = format!("/{}/{}/{}", DEPLOY, INVALID_HASH, TRANSFERS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn event_log_with_unknown_type_should_return_400() {
+    let request_path = format!("/{}?type=NotAnEvent", EVENT_LOG);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn event_log_with_invalid_time_should_return_400() {
+    let request_path = format!("/{}?since=yesterday", EVENT_LOG);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn event_log_with_inverted_time_range_should_return_400() {
+    let request_path = format!(
+        "/{}?since=2023-03-02T00:00:00Z&until=2023-03-01T00:00:00Z",
+        EVENT_LOG
+    );
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn deploys_by_invalid_contract_hash_should_return_400() {
+    let request_path = format!("/{}/{}/{}", CONTRACTS, INVALID_HASH, DEPLOYS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn deploys_by_contract_with_invalid_limit_should_return_400() {
+    let request_path = format!("/{}/{}/{}?limit=0", CONTRACTS, VALID_HASH, DEPLOYS);
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
+#[tokio::test]
+async fn era_summary_of_invalid_era_should_return_400() {
+    let request_path = format!("/{}/{}", ERA, "not_an_era");
+
+    should_respond_to_path_with(request_path, StatusCode::BAD_REQUEST).await
+}
+
 #[tokio::test]
 async fn finality_signature_by_invalid_block_hash_should_return_400() {
     let request_path = format!("/{}/{}", SIGNATURES, INVALID_HASH);
@@ -458,7 +1921,7 @@ async fn finality_signature_by_invalid_block_hash_should_return_400() {
 async fn should_have_correct_content_type() {
     let database = FakeDatabase::new();
 
-    let api = filters::combined_filters(database);
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
 
     let request_path = format!("/{}", BLOCK);
 
@@ -472,3 +1935,421 @@ async fn should_have_correct_content_type() {
         "application/json"
     );
 }
+
+fn request_with_if_none_match(
+    request_path: &str,
+    if_none_match: Option<&str>,
+) -> warp::test::RequestBuilder {
+    let request = request().path(request_path);
+    match if_none_match {
+        Some(etag) => request.header("if-none-match", etag),
+        None => request,
+    }
+}
+
+fn header_of(response: &warp::http::Response<bytes::Bytes>, name: &str) -> String {
+    response
+        .headers()
+        .get(name)
+        .unwrap_or_else(|| panic!("Error extracting '{}' from headers", name))
+        .to_str()
+        .expect("Header should be visible ASCII")
+        .to_string()
+}
+
+#[tokio::test]
+async fn block_by_hash_should_be_cached_as_immutable() {
+    let database = FakeDatabase::new();
+    let identifiers = database
+        .populate_with_events()
+        .await
+        .expect("Error populating FakeDatabase");
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+    let request_path = format!("/{}/{}", BLOCK, identifiers.block_added_hash);
+
+    let response = request_with_if_none_match(&request_path, None)
+        .reply(&api)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        header_of(&response, "cache-control"),
+        "public, max-age=31536000, immutable"
+    );
+    let etag = header_of(&response, "etag");
+    assert!(etag.starts_with('"') && etag.ends_with('"'));
+
+    let not_modified = request_with_if_none_match(&request_path, Some(&etag))
+        .reply(&api)
+        .await;
+
+    assert_eq!(not_modified.status(), StatusCode::NOT_MODIFIED);
+    assert!(not_modified.body().is_empty());
+    assert_eq!(header_of(&not_modified, "etag"), etag);
+
+    let weak_etag = format!("W/{}", etag);
+    let listed_etags = format!("\"other\", {}", weak_etag);
+    let not_modified = request_with_if_none_match(&request_path, Some(&listed_etags))
+        .reply(&api)
+        .await;
+
+    assert_eq!(not_modified.status(), StatusCode::NOT_MODIFIED);
+
+    let modified = request_with_if_none_match(&request_path, Some("\"other\""))
+        .reply(&api)
+        .await;
+
+    assert_eq!(modified.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn latest_block_should_require_revalidation() {
+    let api = filters::combined_filters(
+        FakeDatabase::new(),
+        &RestServerConfig::default(),
+        Vec::new(),
+    );
+
+    let response = request_with_if_none_match(&format!("/{}", BLOCK), None)
+        .reply(&api)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(header_of(&response, "cache-control"), "no-cache");
+    assert!(response.headers().contains_key("etag"));
+}
+
+#[tokio::test]
+async fn deploy_by_hash_should_be_immutable_only_once_processed() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let pending_deploy = DeployAccepted::random(&mut test_rng);
+    let processed_deploy = DeployAccepted::random(&mut test_rng);
+    for (event_id, deploy_accepted) in [(1, &pending_deploy), (2, &processed_deploy)] {
+        database
+            .save_deploy_accepted(deploy_accepted.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving deploy_accepted");
+    }
+    database
+        .save_deploy_processed(
+            DeployProcessed::random(&mut test_rng, Some(processed_deploy.deploy_hash())),
+            3,
+            "127.0.0.1".to_string(),
+        )
+        .await
+        .expect("Error saving deploy_processed");
+    let api = filters::combined_filters(database, &RestServerConfig::default(), Vec::new());
+
+    let pending_path = format!("/{}/{}", DEPLOY, pending_deploy.hex_encoded_hash());
+    let response = request_with_if_none_match(&pending_path, None)
+        .reply(&api)
+        .await;
+
+    assert_eq!(header_of(&response, "cache-control"), "no-cache");
+
+    let processed_path = format!("/{}/{}", DEPLOY, processed_deploy.hex_encoded_hash());
+    let response = request_with_if_none_match(&processed_path, None)
+        .reply(&api)
+        .await;
+
+    assert_eq!(
+        header_of(&response, "cache-control"),
+        "public, max-age=31536000, immutable"
+    );
+}
+
+#[tokio::test]
+async fn errors_should_not_be_cached() {
+    let api = filters::combined_filters(
+        FakeDatabase::new(),
+        &RestServerConfig::default(),
+        Vec::new(),
+    );
+    let request_path = format!("/{}/{}", BLOCK, VALID_HASH);
+
+    let response = request_with_if_none_match(&request_path, Some("*"))
+        .reply(&api)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert!(!response.headers().contains_key("cache-control"));
+    assert!(!response.headers().contains_key("etag"));
+}
+
+async fn request_deploys_batch(
+    config: &RestServerConfig,
+    database: FakeDatabase,
+    body: String,
+) -> warp::http::Response<bytes::Bytes> {
+    let api = filters::combined_filters(database, config, Vec::new());
+
+    request()
+        .method("POST")
+        .path(&format!("/{}", DEPLOYS_BATCH))
+        .header("content-type", "application/json")
+        .body(body)
+        .reply(&api)
+        .await
+}
+
+#[tokio::test]
+async fn deploys_batch_should_return_valid_data() {
+    let database = FakeDatabase::new();
+    let mut test_rng = TestRng::new();
+    let accepted_deploy = DeployAccepted::random(&mut test_rng);
+    let processed_deploy = DeployAccepted::random(&mut test_rng);
+    for (event_id, deploy_accepted) in [(1, &accepted_deploy), (2, &processed_deploy)] {
+        database
+            .save_deploy_accepted(deploy_accepted.clone(), event_id, "127.0.0.1".to_string())
+            .await
+            .expect("Error saving deploy_accepted");
+    }
+    database
+        .save_deploy_processed(
+            DeployProcessed::random(&mut test_rng, Some(processed_deploy.deploy_hash())),
+            3,
+            "127.0.0.1".to_string(),
+        )
+        .await
+        .expect("Error saving deploy_processed");
+    let body = serde_json::json!({
+        "deploy_hashes": [
+            processed_deploy.hex_encoded_hash(),
+            VALID_HASH,
+            accepted_deploy.hex_encoded_hash().to_uppercase(),
+            processed_deploy.hex_encoded_hash(),
+        ]
+    });
+
+    let response =
+        request_deploys_batch(&RestServerConfig::default(), database, body.to_string()).await;
+
+    assert!(response.status().is_success());
+    assert!(!response.headers().contains_key("etag"));
+
+    let body = response.into_body();
+    let batch = serde_json::from_slice::<DeployAggregatesBatch>(&body)
+        .expect("Error parsing DeployAggregatesBatch from response");
+
+    let deploy_hashes: Vec<&str> = batch
+        .deploys
+        .iter()
+        .map(|aggregate| aggregate.deploy_hash.as_str())
+        .collect();
+    assert_eq!(
+        deploy_hashes,
+        vec![
+            processed_deploy.hex_encoded_hash(),
+            accepted_deploy.hex_encoded_hash()
+        ]
+    );
+    assert!(batch.deploys[0].deploy_processed.is_some());
+    assert!(batch.deploys[1].deploy_processed.is_none());
+    assert_eq!(batch.missing_deploy_hashes, vec![VALID_HASH.to_string()]);
+}
+
+#[tokio::test]
+async fn deploys_batch_of_no_hashes_should_return_empty_batch() {
+    let body = serde_json::json!({ "deploy_hashes": [] });
+
+    let response = request_deploys_batch(
+        &RestServerConfig::default(),
+        FakeDatabase::new(),
+        body.to_string(),
+    )
+    .await;
+
+    assert!(response.status().is_success());
+    let body = response.into_body();
+    let batch = serde_json::from_slice::<DeployAggregatesBatch>(&body)
+        .expect("Error parsing DeployAggregatesBatch from response");
+    assert!(batch.deploys.is_empty());
+    assert!(batch.missing_deploy_hashes.is_empty());
+}
+
+#[tokio::test]
+async fn deploys_batch_with_too_many_hashes_should_return_400() {
+    let config = RestServerConfig {
+        max_deploys_in_batch: Some(2),
+        ..RestServerConfig::default()
+    };
+    let body = serde_json::json!({ "deploy_hashes": [VALID_HASH, VALID_HASH, VALID_HASH] });
+
+    let response = request_deploys_batch(&config, FakeDatabase::new(), body.to_string()).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn deploys_batch_with_oversized_body_should_return_413() {
+    let config = RestServerConfig {
+        max_deploys_in_batch: Some(2),
+        ..RestServerConfig::default()
+    };
+    // Padding an otherwise valid request beyond the size of 2 hashes.
+    let body = format!(
+        "{{\"deploy_hashes\": [\"{}\"]{}}}",
+        VALID_HASH,
+        " ".repeat(4096)
+    );
+
+    let response = request_deploys_batch(&config, FakeDatabase::new(), body).await;
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn deploys_batch_with_invalid_hash_should_return_400() {
+    let body = serde_json::json!({ "deploy_hashes": [VALID_HASH, INVALID_HASH] });
+
+    let response = request_deploys_batch(
+        &RestServerConfig::default(),
+        FakeDatabase::new(),
+        body.to_string(),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn deploys_batch_with_malformed_body_should_return_400() {
+    let response = request_deploys_batch(
+        &RestServerConfig::default(),
+        FakeDatabase::new(),
+        "{\"hashes\": []}".to_string(),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+const API_KEY: &str = "secret-key";
+
+fn config_with_api_key(max_requests_per_second: Option<u32>) -> RestServerConfig {
+    RestServerConfig {
+        api_keys: vec![RestApiKey {
+            name: "client".to_string(),
+            key: API_KEY.to_string(),
+            max_requests_per_second,
+        }],
+        ..RestServerConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn request_without_api_key_should_return_401_if_keys_are_configured() {
+    let api =
+        filters::combined_filters(FakeDatabase::new(), &config_with_api_key(None), Vec::new());
+
+    let response = request().path(&format!("/{}", BLOCK)).reply(&api).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn request_with_unknown_api_key_should_return_401() {
+    let api =
+        filters::combined_filters(FakeDatabase::new(), &config_with_api_key(None), Vec::new());
+
+    let response = request()
+        .path(&format!("/{}", BLOCK))
+        .header("x-api-key", "other-key")
+        .reply(&api)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn request_with_api_key_in_header_or_query_should_return_valid_data() {
+    let api =
+        filters::combined_filters(FakeDatabase::new(), &config_with_api_key(None), Vec::new());
+
+    let header_response = request()
+        .path(&format!("/{}", BLOCK))
+        .header("x-api-key", API_KEY)
+        .reply(&api)
+        .await;
+    let query_response = request()
+        .path(&format!("/{}?api_key={}", BLOCK, API_KEY))
+        .reply(&api)
+        .await;
+
+    assert!(header_response.status().is_success());
+    assert!(query_response.status().is_success());
+    serde_json::from_slice::<BlockAdded>(query_response.body())
+        .expect("Error parsing BlockAdded from response");
+}
+
+#[tokio::test]
+async fn cors_preflight_should_allow_api_key_header_without_api_key() {
+    let api =
+        filters::combined_filters(FakeDatabase::new(), &config_with_api_key(None), Vec::new())
+            .with(cors());
+
+    let response = request()
+        .method("OPTIONS")
+        .path(&format!("/{}", DEPLOYS_BATCH))
+        .header("origin", "https://example.com")
+        .header("access-control-request-method", "POST")
+        .header("access-control-request-headers", "x-api-key, content-type")
+        .reply(&api)
+        .await;
+
+    assert!(response.status().is_success());
+    let allowed_headers = response.headers()["access-control-allow-headers"]
+        .to_str()
+        .unwrap();
+    assert!(allowed_headers.contains("x-api-key"));
+    let allowed_methods = response.headers()["access-control-allow-methods"]
+        .to_str()
+        .unwrap();
+    assert!(allowed_methods.contains("POST"));
+}
+
+#[tokio::test]
+async fn block_by_hash_requested_with_api_key_should_be_cached_privately() {
+    let database = FakeDatabase::new();
+    let identifiers = database
+        .populate_with_events()
+        .await
+        .expect("Error populating FakeDatabase");
+    let api = filters::combined_filters(database, &config_with_api_key(None), Vec::new());
+
+    let response = request()
+        .path(&format!("/{}/{}", BLOCK, identifiers.block_added_hash))
+        .header("x-api-key", API_KEY)
+        .reply(&api)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        header_of(&response, "cache-control"),
+        "private, max-age=31536000, immutable"
+    );
+}
+
+#[tokio::test]
+async fn request_exceeding_rate_of_api_key_should_return_429() {
+    let api = filters::combined_filters(
+        FakeDatabase::new(),
+        &config_with_api_key(Some(1)),
+        Vec::new(),
+    );
+
+    let first_response = request()
+        .path(&format!("/{}", BLOCK))
+        .header("x-api-key", API_KEY)
+        .reply(&api)
+        .await;
+    let second_response = request()
+        .path(&format!("/{}", BLOCK))
+        .header("x-api-key", API_KEY)
+        .reply(&api)
+        .await;
+
+    assert!(first_response.status().is_success());
+    assert_eq!(second_response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
@@ -1,4 +1,5 @@
 pub mod block_added;
+pub mod contract_call;
 pub mod deploy_accepted;
 pub mod deploy_event;
 pub mod deploy_expired;
@@ -10,3 +11,6 @@ pub mod finality_signature;
 pub mod migration;
 pub mod shutdown;
 pub mod step;
+pub mod transfer;
+pub mod validator_reward;
+pub mod validator_weight;
@@ -1,18 +1,20 @@
 use sea_query::{
-    error::Result as SqResult, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Iden, Index,
-    InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
+    error::Result as SqResult, Alias, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Iden, Index,
+    IndexCreateStatement, InsertStatement, Order, Query, SelectStatement, Table,
+    TableAlterStatement, TableCreateStatement, UpdateStatement,
 };
 
 use super::event_log::EventLog;
 
 #[derive(Iden)]
-enum BlockAdded {
+pub enum BlockAdded {
     #[iden = "BlockAdded"]
     Table,
     Height,
     BlockHash,
     Raw,
     EventLogId,
+    EraId,
 }
 
 pub fn create_table_stmt() -> TableCreateStatement {
@@ -50,9 +52,35 @@ pub fn create_table_stmt() -> TableCreateStatement {
         .to_owned()
 }
 
+pub fn create_event_log_id_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_BlockAdded_event_log_id")
+        .table(BlockAdded::Table)
+        .col(BlockAdded::EventLogId)
+        .to_owned()
+}
+
+pub fn create_add_era_id_column_stmt() -> TableAlterStatement {
+    Table::alter()
+        .table(BlockAdded::Table)
+        .add_column(ColumnDef::new(BlockAdded::EraId).big_unsigned().null())
+        .to_owned()
+}
+
+pub fn create_era_id_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_BlockAdded_era_id")
+        .table(BlockAdded::Table)
+        .col(BlockAdded::EraId)
+        .to_owned()
+}
+
 pub fn create_insert_stmt(
     height: u64,
     block_hash: String,
+    era_id: u64,
     raw: String,
     event_log_id: u64,
 ) -> SqResult<InsertStatement> {
@@ -61,18 +89,52 @@ pub fn create_insert_stmt(
         .columns([
             BlockAdded::Height,
             BlockAdded::BlockHash,
+            BlockAdded::EraId,
             BlockAdded::Raw,
             BlockAdded::EventLogId,
         ])
         .values(vec![
             height.into(),
             block_hash.into(),
+            era_id.into(),
             raw.into(),
             event_log_id.into(),
         ])
         .map(|stmt| stmt.to_owned())
 }
 
+/// Selects `block_hash` and `raw` of records stored before the `era_id` column was populated.
+pub fn create_get_without_era_id_stmt(limit: u32) -> SelectStatement {
+    Query::select()
+        .columns([BlockAdded::BlockHash, BlockAdded::Raw])
+        .from(BlockAdded::Table)
+        .and_where(Expr::col(BlockAdded::EraId).is_null())
+        .limit(limit as u64)
+        .to_owned()
+}
+
+pub fn create_update_era_id_stmt(block_hash: String, era_id: u64) -> UpdateStatement {
+    Query::update()
+        .table(BlockAdded::Table)
+        .value(BlockAdded::EraId, era_id)
+        .and_where(Expr::col(BlockAdded::BlockHash).eq(block_hash))
+        .to_owned()
+}
+
+/// Selects `block_hash` and `raw` of at most `limit` blocks with a hash greater than
+/// `after_block_hash`, ordered by hash, so that all blocks can be read in batches.
+pub fn create_get_after_hash_stmt(after_block_hash: Option<String>, limit: u32) -> SelectStatement {
+    Query::select()
+        .columns([BlockAdded::BlockHash, BlockAdded::Raw])
+        .from(BlockAdded::Table)
+        .and_where_option(
+            after_block_hash.map(|block_hash| Expr::col(BlockAdded::BlockHash).gt(block_hash)),
+        )
+        .order_by(BlockAdded::BlockHash, Order::Asc)
+        .limit(limit as u64)
+        .to_owned()
+}
+
 pub fn create_get_by_hash_stmt(block_hash: String) -> SelectStatement {
     Query::select()
         .column(BlockAdded::Raw)
@@ -89,6 +151,90 @@ pub fn create_get_by_height_stmt(height: u64) -> SelectStatement {
         .to_owned()
 }
 
+pub fn create_get_by_height_range_stmt(
+    from_height: u64,
+    to_height: Option<u64>,
+    limit: u32,
+) -> SelectStatement {
+    Query::select()
+        .column(BlockAdded::Raw)
+        .from(BlockAdded::Table)
+        .and_where(Expr::col(BlockAdded::Height).gte(from_height))
+        .and_where_option(to_height.map(|height| Expr::col(BlockAdded::Height).lte(height)))
+        .order_by(BlockAdded::Height, Order::Asc)
+        .limit(limit as u64)
+        .to_owned()
+}
+
+/// Selects the lowest height (`first_height`), highest height (`last_height`) and number
+/// (`block_count`) of the stored blocks of the given era.
+pub fn create_get_era_block_range_stmt(era_id: u64) -> SelectStatement {
+    Query::select()
+        .expr_as(
+            Expr::col(BlockAdded::Height).min(),
+            Alias::new("first_height"),
+        )
+        .expr_as(
+            Expr::col(BlockAdded::Height).max(),
+            Alias::new("last_height"),
+        )
+        .expr_as(
+            Expr::col(BlockAdded::Height).count(),
+            Alias::new("block_count"),
+        )
+        .from(BlockAdded::Table)
+        .and_where(Expr::col(BlockAdded::EraId).eq(era_id))
+        .to_owned()
+}
+
+/// Selects the highest stored block of the given era, which is its switch block once the era
+/// has ended.
+pub fn create_get_last_of_era_stmt(era_id: u64) -> SelectStatement {
+    Query::select()
+        .column(BlockAdded::Raw)
+        .from(BlockAdded::Table)
+        .and_where(Expr::col(BlockAdded::EraId).eq(era_id))
+        .order_by(BlockAdded::Height, Order::Desc)
+        .limit(1)
+        .to_owned()
+}
+
+/// Selects, for every era in the given range, the number (`block_count`) of its stored blocks.
+pub fn create_count_per_era_stmt(from_era: Option<u64>, to_era: Option<u64>) -> SelectStatement {
+    Query::select()
+        .column(BlockAdded::EraId)
+        .expr_as(
+            Expr::col(BlockAdded::Height).count(),
+            Alias::new("block_count"),
+        )
+        .from(BlockAdded::Table)
+        .and_where_option(from_era.map(|era_id| Expr::col(BlockAdded::EraId).gte(era_id)))
+        .and_where_option(to_era.map(|era_id| Expr::col(BlockAdded::EraId).lte(era_id)))
+        .group_by_col(BlockAdded::EraId)
+        .to_owned()
+}
+
+/// Selects the `count` highest stored blocks, highest first.
+pub fn create_get_latest_blocks_stmt(count: u32) -> SelectStatement {
+    Query::select()
+        .column(BlockAdded::Raw)
+        .from(BlockAdded::Table)
+        .order_by(BlockAdded::Height, Order::Desc)
+        .limit(count as u64)
+        .to_owned()
+}
+
+/// Selects the highest `event_log_id` of the stored blocks, NULL if no block is stored.
+pub fn create_get_last_event_log_id_stmt() -> SelectStatement {
+    Query::select()
+        .expr_as(
+            Expr::col(BlockAdded::EventLogId).max(),
+            Alias::new("event_log_id"),
+        )
+        .from(BlockAdded::Table)
+        .to_owned()
+}
+
 pub fn create_get_latest_stmt() -> SelectStatement {
     let select_max = Query::select()
         .expr(Expr::col(BlockAdded::Height).max())
@@ -100,3 +246,98 @@ pub fn create_get_latest_stmt() -> SelectStatement {
         .and_where(Expr::col(BlockAdded::Height).in_subquery(select_max))
         .to_owned()
 }
+
+#[test]
+fn create_get_by_height_range_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"raw\" FROM \"BlockAdded\" WHERE \"height\" >= 10 AND \"height\" <= 20 ORDER BY \"height\" ASC LIMIT 5";
+
+    let got_sql = create_get_by_height_range_stmt(10, Some(20), 5).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_by_height_range_stmt_without_upper_bound_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql =
+        "SELECT \"raw\" FROM \"BlockAdded\" WHERE \"height\" >= 10 ORDER BY \"height\" ASC LIMIT 5";
+
+    let got_sql = create_get_by_height_range_stmt(10, None, 5).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_insert_stmt_should_produce_insert_sql_with_era_id() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "INSERT INTO \"BlockAdded\" (\"height\", \"block_hash\", \"era_id\", \"raw\", \"event_log_id\") VALUES (10, 'ab', 2, '{}', 1)";
+
+    let got_sql = create_insert_stmt(10, "ab".to_string(), 2, "{}".to_string(), 1)
+        .unwrap()
+        .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_era_block_range_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT MIN(\"height\") AS \"first_height\", MAX(\"height\") AS \"last_height\", COUNT(\"height\") AS \"block_count\" FROM \"BlockAdded\" WHERE \"era_id\" = 5";
+
+    let got_sql = create_get_era_block_range_stmt(5).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_last_of_era_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql =
+        "SELECT \"raw\" FROM \"BlockAdded\" WHERE \"era_id\" = 5 ORDER BY \"height\" DESC LIMIT 1";
+
+    let got_sql = create_get_last_of_era_stmt(5).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_after_hash_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"block_hash\", \"raw\" FROM \"BlockAdded\" WHERE \"block_hash\" > 'ab' ORDER BY \"block_hash\" ASC LIMIT 5";
+
+    let got_sql =
+        create_get_after_hash_stmt(Some("ab".to_string()), 5).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_count_per_era_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"era_id\", COUNT(\"height\") AS \"block_count\" FROM \"BlockAdded\" WHERE \"era_id\" >= 2 AND \"era_id\" <= 4 GROUP BY \"era_id\"";
+
+    let got_sql = create_count_per_era_stmt(Some(2), Some(4)).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_latest_blocks_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"raw\" FROM \"BlockAdded\" ORDER BY \"height\" DESC LIMIT 10";
+
+    let got_sql = create_get_latest_blocks_stmt(10).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_last_event_log_id_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT MAX(\"event_log_id\") AS \"event_log_id\" FROM \"BlockAdded\"";
+
+    let got_sql = create_get_last_event_log_id_stmt().to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
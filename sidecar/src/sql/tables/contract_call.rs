@@ -0,0 +1,222 @@
+use sea_query::{
+    error::Result as SqResult, Alias, ColumnDef, Cond, Expr, Iden, Index, IndexCreateStatement,
+    InsertStatement, Order, Query, SelectStatement, Table, TableCreateStatement,
+};
+
+use super::{deploy_expired::DeployExpired, deploy_processed::DeployProcessed};
+
+/// Contracts called by the session code of accepted deploys.
+#[derive(Iden)]
+enum ContractCall {
+    #[iden = "ContractCall"]
+    Table,
+    Id,
+    DeployHash,
+    ContractHash,
+    ContractPackageHash,
+    ContractName,
+    EntryPoint,
+    EventLogId,
+}
+
+pub fn create_table_stmt(is_big_integer_id: bool) -> TableCreateStatement {
+    let mut binding = ColumnDef::new(ContractCall::Id);
+    let mut id_col_definition = binding.auto_increment().not_null().primary_key();
+    if is_big_integer_id {
+        id_col_definition = id_col_definition.big_integer();
+    } else {
+        id_col_definition = id_col_definition.integer();
+    }
+    Table::create()
+        .table(ContractCall::Table)
+        .if_not_exists()
+        .col(id_col_definition)
+        .col(ColumnDef::new(ContractCall::DeployHash).string().not_null())
+        .col(ColumnDef::new(ContractCall::ContractHash).string().null())
+        .col(
+            ColumnDef::new(ContractCall::ContractPackageHash)
+                .string()
+                .null(),
+        )
+        .col(ColumnDef::new(ContractCall::ContractName).string().null())
+        .col(ColumnDef::new(ContractCall::EntryPoint).string().not_null())
+        .col(
+            ColumnDef::new(ContractCall::EventLogId)
+                .big_unsigned()
+                .not_null(),
+        )
+        .index(
+            Index::create()
+                .unique()
+                .name("UDX_ContractCall_deploy_hash")
+                .col(ContractCall::DeployHash),
+        )
+        .to_owned()
+}
+
+pub fn create_contract_hash_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_ContractCall_contract_hash")
+        .table(ContractCall::Table)
+        .col(ContractCall::ContractHash)
+        .to_owned()
+}
+
+pub fn create_contract_package_hash_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_ContractCall_contract_package_hash")
+        .table(ContractCall::Table)
+        .col(ContractCall::ContractPackageHash)
+        .to_owned()
+}
+
+/// Values of the columns describing the contract called by a deploy. Exactly one of
+/// `contract_hash`, `contract_package_hash` and `contract_name` is set, depending on how the
+/// session code referenced the contract.
+pub struct ContractCallRecord {
+    /// Hex-encoded contract hash.
+    pub contract_hash: Option<String>,
+    /// Hex-encoded contract package hash.
+    pub contract_package_hash: Option<String>,
+    /// Name under which the contract is stored in the named keys of the account.
+    pub contract_name: Option<String>,
+    pub entry_point: String,
+}
+
+pub fn create_insert_stmt(
+    deploy_hash: String,
+    contract_call: ContractCallRecord,
+    event_log_id: u64,
+) -> SqResult<InsertStatement> {
+    Query::insert()
+        .into_table(ContractCall::Table)
+        .columns([
+            ContractCall::DeployHash,
+            ContractCall::ContractHash,
+            ContractCall::ContractPackageHash,
+            ContractCall::ContractName,
+            ContractCall::EntryPoint,
+            ContractCall::EventLogId,
+        ])
+        .values(vec![
+            deploy_hash.into(),
+            contract_call.contract_hash.into(),
+            contract_call.contract_package_hash.into(),
+            contract_call.contract_name.into(),
+            contract_call.entry_point.into(),
+            event_log_id.into(),
+        ])
+        .map(|stmt| stmt.to_owned())
+}
+
+/// Selects the deploys calling the contract with the given hash, newest first. The hash is matched
+/// against both the contract hash and the contract package hash. Every row holds `deploy_hash`,
+/// `contract_hash`, `contract_package_hash`, `entry_point`, `event_log_id` (used as the pagination
+/// cursor), the nullable `is_success` and `block_hash` of the processed deploy and the nullable
+/// `expired_deploy_hash` which is set if the deploy expired.
+///
+/// * `hash` - hex-encoded contract hash or contract package hash
+/// * `entry_point` - if set, only calls of this entry point are selected
+pub fn create_get_by_contract_hash_stmt(
+    hash: String,
+    entry_point: Option<String>,
+    cursor: Option<u64>,
+    limit: u32,
+) -> SelectStatement {
+    Query::select()
+        .column((ContractCall::Table, ContractCall::DeployHash))
+        .column((ContractCall::Table, ContractCall::ContractHash))
+        .column((ContractCall::Table, ContractCall::ContractPackageHash))
+        .column((ContractCall::Table, ContractCall::EntryPoint))
+        .column((ContractCall::Table, ContractCall::EventLogId))
+        .column((DeployProcessed::Table, DeployProcessed::IsSuccess))
+        .column((DeployProcessed::Table, DeployProcessed::BlockHash))
+        .expr_as(
+            Expr::col((DeployExpired::Table, DeployExpired::DeployHash)),
+            Alias::new("expired_deploy_hash"),
+        )
+        .from(ContractCall::Table)
+        .left_join(
+            DeployProcessed::Table,
+            Expr::col((DeployProcessed::Table, DeployProcessed::DeployHash))
+                .equals((ContractCall::Table, ContractCall::DeployHash)),
+        )
+        .left_join(
+            DeployExpired::Table,
+            Expr::col((DeployExpired::Table, DeployExpired::DeployHash))
+                .equals((ContractCall::Table, ContractCall::DeployHash)),
+        )
+        .cond_where(
+            Cond::all()
+                .add(
+                    Cond::any()
+                        .add(
+                            Expr::col((ContractCall::Table, ContractCall::ContractHash))
+                                .eq(hash.clone()),
+                        )
+                        .add(
+                            Expr::col((ContractCall::Table, ContractCall::ContractPackageHash))
+                                .eq(hash),
+                        ),
+                )
+                .add_option(entry_point.map(|entry_point| {
+                    Expr::col((ContractCall::Table, ContractCall::EntryPoint)).eq(entry_point)
+                }))
+                .add_option(cursor.map(|event_log_id| {
+                    Expr::col((ContractCall::Table, ContractCall::EventLogId)).lt(event_log_id)
+                })),
+        )
+        .order_by((ContractCall::Table, ContractCall::EventLogId), Order::Desc)
+        .limit(limit as u64)
+        .to_owned()
+}
+
+#[test]
+fn create_table_stmt_should_use_id_type_of_database() {
+    use sea_query::{PostgresQueryBuilder, SqliteQueryBuilder};
+
+    let postgres_sql = create_table_stmt(true).to_string(PostgresQueryBuilder);
+    let sqlite_sql = create_table_stmt(false).to_string(SqliteQueryBuilder);
+
+    assert!(postgres_sql.contains("\"id\" bigserial"));
+    assert!(sqlite_sql.contains("\"id\" integer"));
+}
+
+#[test]
+fn create_insert_stmt_should_produce_insert_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "INSERT INTO \"ContractCall\" (\"deploy_hash\", \"contract_hash\", \"contract_package_hash\", \"contract_name\", \"entry_point\", \"event_log_id\") VALUES ('deploy', NULL, 'ab', NULL, 'transfer', 3)";
+
+    let got_sql = create_insert_stmt(
+        "deploy".to_string(),
+        ContractCallRecord {
+            contract_hash: None,
+            contract_package_hash: Some("ab".to_string()),
+            contract_name: None,
+            entry_point: "transfer".to_string(),
+        },
+        3,
+    )
+    .unwrap()
+    .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_by_contract_hash_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"ContractCall\".\"deploy_hash\", \"ContractCall\".\"contract_hash\", \"ContractCall\".\"contract_package_hash\", \"ContractCall\".\"entry_point\", \"ContractCall\".\"event_log_id\", \"DeployProcessed\".\"is_success\", \"DeployProcessed\".\"block_hash\", \"DeployExpired\".\"deploy_hash\" AS \"expired_deploy_hash\" FROM \"ContractCall\" LEFT JOIN \"DeployProcessed\" ON \"DeployProcessed\".\"deploy_hash\" = \"ContractCall\".\"deploy_hash\" LEFT JOIN \"DeployExpired\" ON \"DeployExpired\".\"deploy_hash\" = \"ContractCall\".\"deploy_hash\" WHERE (\"ContractCall\".\"contract_hash\" = 'ab' OR \"ContractCall\".\"contract_package_hash\" = 'ab') AND \"ContractCall\".\"entry_point\" = 'transfer' AND \"ContractCall\".\"event_log_id\" < 7 ORDER BY \"ContractCall\".\"event_log_id\" DESC LIMIT 5";
+
+    let got_sql = create_get_by_contract_hash_stmt(
+        "ab".to_string(),
+        Some("transfer".to_string()),
+        Some(7),
+        5,
+    )
+    .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
@@ -1,9 +1,12 @@
 use sea_query::{
-    error::Result as SqResult, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Iden, Index,
-    InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
+    error::Result as SqResult, Alias, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Func, Iden,
+    Index, IndexCreateStatement, InsertStatement, Order, Query, SelectStatement, Table,
+    TableAlterStatement, TableCreateStatement, UpdateStatement,
 };
 
-use super::event_log::EventLog;
+use super::{
+    deploy_expired::DeployExpired, deploy_processed::DeployProcessed, event_log::EventLog,
+};
 
 #[derive(Iden)]
 pub(super) enum DeployAccepted {
@@ -12,6 +15,7 @@ pub(super) enum DeployAccepted {
     DeployHash,
     Raw,
     EventLogId,
+    Account,
 }
 
 pub fn create_table_stmt() -> TableCreateStatement {
@@ -46,8 +50,34 @@ pub fn create_table_stmt() -> TableCreateStatement {
         .to_owned()
 }
 
+pub fn create_event_log_id_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_DeployAccepted_event_log_id")
+        .table(DeployAccepted::Table)
+        .col(DeployAccepted::EventLogId)
+        .to_owned()
+}
+
+pub fn create_add_account_column_stmt() -> TableAlterStatement {
+    Table::alter()
+        .table(DeployAccepted::Table)
+        .add_column(ColumnDef::new(DeployAccepted::Account).string().null())
+        .to_owned()
+}
+
+pub fn create_account_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_DeployAccepted_account")
+        .table(DeployAccepted::Table)
+        .col(DeployAccepted::Account)
+        .to_owned()
+}
+
 pub fn create_insert_stmt(
     deploy_hash: String,
+    account: String,
     raw: String,
     event_log_id: u64,
 ) -> SqResult<InsertStatement> {
@@ -55,13 +85,106 @@ pub fn create_insert_stmt(
         .into_table(DeployAccepted::Table)
         .columns([
             DeployAccepted::DeployHash,
+            DeployAccepted::Account,
             DeployAccepted::Raw,
             DeployAccepted::EventLogId,
         ])
-        .values(vec![deploy_hash.into(), raw.into(), event_log_id.into()])
+        .values(vec![
+            deploy_hash.into(),
+            account.into(),
+            raw.into(),
+            event_log_id.into(),
+        ])
         .map(|stmt| stmt.to_owned())
 }
 
+/// Selects `deploy_hash` and `raw` of records stored before the `account` column was populated.
+pub fn create_get_without_account_stmt(limit: u32) -> SelectStatement {
+    Query::select()
+        .columns([DeployAccepted::DeployHash, DeployAccepted::Raw])
+        .from(DeployAccepted::Table)
+        .and_where(Expr::col(DeployAccepted::Account).is_null())
+        .limit(limit as u64)
+        .to_owned()
+}
+
+pub fn create_update_account_stmt(deploy_hash: String, account: String) -> UpdateStatement {
+    Query::update()
+        .table(DeployAccepted::Table)
+        .value(DeployAccepted::Account, account)
+        .and_where(Expr::col(DeployAccepted::DeployHash).eq(deploy_hash))
+        .to_owned()
+}
+
+/// Selects deploy summaries of the given account, newest first. Every row holds `deploy_hash`,
+/// `event_log_id` (used as the pagination cursor) and the nullable `processed_deploy_hash` and
+/// `expired_deploy_hash` columns which are set if the deploy reached the respective state.
+pub fn create_get_summaries_by_account_stmt(
+    account: String,
+    cursor: Option<u64>,
+    limit: u32,
+) -> SelectStatement {
+    select_summaries()
+        .and_where(Expr::col((DeployAccepted::Table, DeployAccepted::Account)).eq(account))
+        .and_where_option(cursor.map(|event_log_id| {
+            Expr::col((DeployAccepted::Table, DeployAccepted::EventLogId)).lt(event_log_id)
+        }))
+        .order_by(
+            (DeployAccepted::Table, DeployAccepted::EventLogId),
+            Order::Desc,
+        )
+        .limit(limit as u64)
+        .to_owned()
+}
+
+fn select_summaries() -> SelectStatement {
+    Query::select()
+        .column((DeployAccepted::Table, DeployAccepted::DeployHash))
+        .column((DeployAccepted::Table, DeployAccepted::EventLogId))
+        .expr_as(
+            Expr::col((DeployProcessed::Table, DeployProcessed::DeployHash)),
+            Alias::new("processed_deploy_hash"),
+        )
+        .expr_as(
+            Expr::col((DeployExpired::Table, DeployExpired::DeployHash)),
+            Alias::new("expired_deploy_hash"),
+        )
+        .from(DeployAccepted::Table)
+        .left_join(
+            DeployProcessed::Table,
+            Expr::col((DeployProcessed::Table, DeployProcessed::DeployHash))
+                .equals((DeployAccepted::Table, DeployAccepted::DeployHash)),
+        )
+        .left_join(
+            DeployExpired::Table,
+            Expr::col((DeployExpired::Table, DeployExpired::DeployHash))
+                .equals((DeployAccepted::Table, DeployAccepted::DeployHash)),
+        )
+        .to_owned()
+}
+
+/// Selects `event_log_id` (cast to text) and `raw` of at most `limit` records with an
+/// `event_log_id` greater than `after_event_log_id`, oldest first.
+pub fn create_get_after_event_log_id_stmt(
+    after_event_log_id: Option<u64>,
+    limit: u32,
+) -> SelectStatement {
+    Query::select()
+        .expr_as(
+            Func::cast_as(Expr::col(DeployAccepted::EventLogId), Alias::new("TEXT")),
+            Alias::new("event_log_id"),
+        )
+        .column(DeployAccepted::Raw)
+        .from(DeployAccepted::Table)
+        .and_where_option(
+            after_event_log_id
+                .map(|event_log_id| Expr::col(DeployAccepted::EventLogId).gt(event_log_id)),
+        )
+        .order_by(DeployAccepted::EventLogId, Order::Asc)
+        .limit(limit as u64)
+        .to_owned()
+}
+
 pub fn create_get_by_hash_stmt(deploy_hash: String) -> SelectStatement {
     Query::select()
         .column(DeployAccepted::Raw)
@@ -69,3 +192,60 @@ pub fn create_get_by_hash_stmt(deploy_hash: String) -> SelectStatement {
         .and_where(Expr::col(DeployAccepted::DeployHash).eq(deploy_hash))
         .to_owned()
 }
+
+/// Selects the events needed to aggregate each of the accepted deploys with the given hashes.
+/// Hashes of deploys which weren't accepted are left out of the result.
+pub fn create_get_aggregates_by_hashes_stmt(deploy_hashes: Vec<String>) -> SelectStatement {
+    Query::select()
+        .column((DeployAccepted::Table, DeployAccepted::DeployHash))
+        .expr_as(
+            Expr::col((DeployAccepted::Table, DeployAccepted::Raw)),
+            Alias::new("accepted_raw"),
+        )
+        .expr_as(
+            Expr::col((DeployProcessed::Table, DeployProcessed::Raw)),
+            Alias::new("processed_raw"),
+        )
+        .expr_as(
+            Expr::col((DeployExpired::Table, DeployExpired::DeployHash)),
+            Alias::new("expired_deploy_hash"),
+        )
+        .from(DeployAccepted::Table)
+        .left_join(
+            DeployProcessed::Table,
+            Expr::col((DeployProcessed::Table, DeployProcessed::DeployHash))
+                .equals((DeployAccepted::Table, DeployAccepted::DeployHash)),
+        )
+        .left_join(
+            DeployExpired::Table,
+            Expr::col((DeployExpired::Table, DeployExpired::DeployHash))
+                .equals((DeployAccepted::Table, DeployAccepted::DeployHash)),
+        )
+        .and_where(
+            Expr::col((DeployAccepted::Table, DeployAccepted::DeployHash)).is_in(deploy_hashes),
+        )
+        .to_owned()
+}
+
+#[test]
+fn create_update_account_stmt_should_produce_update_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql =
+        "UPDATE \"DeployAccepted\" SET \"account\" = '01ab' WHERE \"deploy_hash\" = 'cd'";
+
+    let got_sql = create_update_account_stmt("cd".to_string(), "01ab".to_string())
+        .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_aggregates_by_hashes_stmt_should_produce_set_based_select() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"DeployAccepted\".\"deploy_hash\", \"DeployAccepted\".\"raw\" AS \"accepted_raw\", \"DeployProcessed\".\"raw\" AS \"processed_raw\", \"DeployExpired\".\"deploy_hash\" AS \"expired_deploy_hash\" FROM \"DeployAccepted\" LEFT JOIN \"DeployProcessed\" ON \"DeployProcessed\".\"deploy_hash\" = \"DeployAccepted\".\"deploy_hash\" LEFT JOIN \"DeployExpired\" ON \"DeployExpired\".\"deploy_hash\" = \"DeployAccepted\".\"deploy_hash\" WHERE \"DeployAccepted\".\"deploy_hash\" IN ('ab', 'cd')";
+
+    let got_sql = create_get_aggregates_by_hashes_stmt(vec!["ab".to_string(), "cd".to_string()])
+        .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
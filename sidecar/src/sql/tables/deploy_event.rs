@@ -1,6 +1,6 @@
 use sea_query::{
-    error::Result as SqResult, ColumnDef, ForeignKey, ForeignKeyAction, Iden, Index,
-    InsertStatement, Query, Table, TableCreateStatement,
+    error::Result as SqResult, Alias, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Func, Iden,
+    Index, InsertStatement, Order, Query, SelectStatement, Table, TableCreateStatement,
 };
 
 use super::event_log::EventLog;
@@ -49,3 +49,44 @@ pub fn create_insert_stmt(event_log_id: u64, deploy_hash: String) -> SqResult<In
 
     Ok(insert_stmt)
 }
+
+/// Selects the event log entries of all events of the given deploy, oldest first. Timestamps are
+/// cast to text so that they can be read the same way from every supported database.
+pub fn create_get_event_logs_stmt(deploy_hash: String) -> SelectStatement {
+    Query::select()
+        .column((EventLog::Table, EventLog::EventTypeId))
+        .column((EventLog::Table, EventLog::EventSourceAddress))
+        .expr_as(
+            Func::cast_as(
+                Expr::col((EventLog::Table, EventLog::InsertedTimestamp)),
+                Alias::new("TEXT"),
+            ),
+            Alias::new("inserted_timestamp"),
+        )
+        .expr_as(
+            Func::cast_as(
+                Expr::col((EventLog::Table, EventLog::EmittedTimestamp)),
+                Alias::new("TEXT"),
+            ),
+            Alias::new("emitted_timestamp"),
+        )
+        .from(DeployEvent::Table)
+        .inner_join(
+            EventLog::Table,
+            Expr::col((EventLog::Table, EventLog::EventLogId))
+                .equals((DeployEvent::Table, DeployEvent::EventLogId)),
+        )
+        .and_where(Expr::col((DeployEvent::Table, DeployEvent::DeployHash)).eq(deploy_hash))
+        .order_by((EventLog::Table, EventLog::EventLogId), Order::Asc)
+        .to_owned()
+}
+
+#[test]
+fn create_get_event_logs_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"event_log\".\"event_type_id\", \"event_log\".\"event_source_address\", CAST(\"event_log\".\"inserted_timestamp\" AS TEXT) AS \"inserted_timestamp\", CAST(\"event_log\".\"emitted_timestamp\" AS TEXT) AS \"emitted_timestamp\" FROM \"deploy_event\" INNER JOIN \"event_log\" ON \"event_log\".\"event_log_id\" = \"deploy_event\".\"event_log_id\" WHERE \"deploy_event\".\"deploy_hash\" = 'abc' ORDER BY \"event_log\".\"event_log_id\" ASC";
+
+    let got_sql = create_get_event_logs_stmt("abc".to_string()).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
@@ -1,6 +1,6 @@
 use sea_query::{
     error::Result as SqResult, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Iden, Index,
-    InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
+    IndexCreateStatement, InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
 };
 
 use super::event_log::EventLog;
@@ -46,6 +46,15 @@ pub fn create_table_stmt() -> TableCreateStatement {
         .to_owned()
 }
 
+pub fn create_event_log_id_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_DeployExpired_event_log_id")
+        .table(DeployExpired::Table)
+        .col(DeployExpired::EventLogId)
+        .to_owned()
+}
+
 pub fn create_insert_stmt(
     deploy_hash: String,
     event_log_id: u64,
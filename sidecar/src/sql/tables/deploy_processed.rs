@@ -1,9 +1,13 @@
 use sea_query::{
-    error::Result as SqResult, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Iden, Index,
-    InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
+    error::Result as SqResult, Alias, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Func, Iden,
+    Index, IndexCreateStatement, InsertStatement, LikeExpr, Order, Query, SelectStatement, Table,
+    TableAlterStatement, TableCreateStatement, UpdateStatement,
 };
 
-use super::event_log::EventLog;
+use super::{block_added::BlockAdded, deploy_accepted::DeployAccepted, event_log::EventLog};
+
+/// Character escaping the wildcards of a `LIKE` pattern.
+const LIKE_ESCAPE: char = '!';
 
 #[derive(Iden)]
 pub enum DeployProcessed {
@@ -12,6 +16,10 @@ pub enum DeployProcessed {
     DeployHash,
     Raw,
     EventLogId,
+    BlockHash,
+    IsSuccess,
+    Cost,
+    ErrorMessage,
 }
 
 pub fn create_table_stmt() -> TableCreateStatement {
@@ -46,8 +54,67 @@ pub fn create_table_stmt() -> TableCreateStatement {
         .to_owned()
 }
 
+pub fn create_event_log_id_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_DeployProcessed_event_log_id")
+        .table(DeployProcessed::Table)
+        .col(DeployProcessed::EventLogId)
+        .to_owned()
+}
+
+pub fn create_add_block_hash_column_stmt() -> TableAlterStatement {
+    Table::alter()
+        .table(DeployProcessed::Table)
+        .add_column(ColumnDef::new(DeployProcessed::BlockHash).string().null())
+        .to_owned()
+}
+
+pub fn create_block_hash_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_DeployProcessed_block_hash")
+        .table(DeployProcessed::Table)
+        .col(DeployProcessed::BlockHash)
+        .to_owned()
+}
+
+// SQLite can only add a single column per ALTER TABLE statement.
+pub fn create_add_is_success_column_stmt() -> TableAlterStatement {
+    Table::alter()
+        .table(DeployProcessed::Table)
+        .add_column(ColumnDef::new(DeployProcessed::IsSuccess).boolean().null())
+        .to_owned()
+}
+
+pub fn create_add_cost_column_stmt() -> TableAlterStatement {
+    Table::alter()
+        .table(DeployProcessed::Table)
+        // Costs are U512 values, so they are stored as decimal strings.
+        .add_column(ColumnDef::new(DeployProcessed::Cost).string().null())
+        .to_owned()
+}
+
+pub fn create_add_error_message_column_stmt() -> TableAlterStatement {
+    Table::alter()
+        .table(DeployProcessed::Table)
+        .add_column(ColumnDef::new(DeployProcessed::ErrorMessage).text().null())
+        .to_owned()
+}
+
+pub fn create_is_success_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_DeployProcessed_is_success")
+        .table(DeployProcessed::Table)
+        .col(DeployProcessed::IsSuccess)
+        .to_owned()
+}
+
 pub fn create_insert_stmt(
     deploy_hash: String,
+    block_hash: String,
+    execution_outcome: ExecutionOutcome,
     raw: String,
     event_log_id: u64,
 ) -> SqResult<InsertStatement> {
@@ -55,13 +122,201 @@ pub fn create_insert_stmt(
         .into_table(DeployProcessed::Table)
         .columns([
             DeployProcessed::DeployHash,
+            DeployProcessed::BlockHash,
+            DeployProcessed::IsSuccess,
+            DeployProcessed::Cost,
+            DeployProcessed::ErrorMessage,
             DeployProcessed::Raw,
             DeployProcessed::EventLogId,
         ])
-        .values(vec![deploy_hash.into(), raw.into(), event_log_id.into()])
+        .values(vec![
+            deploy_hash.into(),
+            block_hash.into(),
+            execution_outcome.is_success.into(),
+            execution_outcome.cost.into(),
+            execution_outcome.error_message.into(),
+            raw.into(),
+            event_log_id.into(),
+        ])
         .map(|stmt| stmt.to_owned())
 }
 
+/// Values of the columns describing the outcome of a deploy's execution.
+pub struct ExecutionOutcome {
+    pub is_success: bool,
+    /// Decimal string of the U512 cost.
+    pub cost: String,
+    /// `None` if the execution succeeded.
+    pub error_message: Option<String>,
+}
+
+/// Selects `deploy_hash` and `raw` of records stored before the `block_hash` column was populated.
+pub fn create_get_without_block_hash_stmt(limit: u32) -> SelectStatement {
+    Query::select()
+        .columns([DeployProcessed::DeployHash, DeployProcessed::Raw])
+        .from(DeployProcessed::Table)
+        .and_where(Expr::col(DeployProcessed::BlockHash).is_null())
+        .limit(limit as u64)
+        .to_owned()
+}
+
+pub fn create_update_block_hash_stmt(deploy_hash: String, block_hash: String) -> UpdateStatement {
+    Query::update()
+        .table(DeployProcessed::Table)
+        .value(DeployProcessed::BlockHash, block_hash)
+        .and_where(Expr::col(DeployProcessed::DeployHash).eq(deploy_hash))
+        .to_owned()
+}
+
+/// Selects `deploy_hash` and `raw` of records stored before the `is_success`, `cost` and
+/// `error_message` columns were populated.
+pub fn create_get_without_execution_outcome_stmt(limit: u32) -> SelectStatement {
+    Query::select()
+        .columns([DeployProcessed::DeployHash, DeployProcessed::Raw])
+        .from(DeployProcessed::Table)
+        .and_where(Expr::col(DeployProcessed::IsSuccess).is_null())
+        .limit(limit as u64)
+        .to_owned()
+}
+
+pub fn create_update_execution_outcome_stmt(
+    deploy_hash: String,
+    execution_outcome: ExecutionOutcome,
+) -> UpdateStatement {
+    Query::update()
+        .table(DeployProcessed::Table)
+        .values([
+            (
+                DeployProcessed::IsSuccess,
+                execution_outcome.is_success.into(),
+            ),
+            (DeployProcessed::Cost, execution_outcome.cost.into()),
+            (
+                DeployProcessed::ErrorMessage,
+                execution_outcome.error_message.into(),
+            ),
+        ])
+        .and_where(Expr::col(DeployProcessed::DeployHash).eq(deploy_hash))
+        .to_owned()
+}
+
+/// Selects failed deploys, newest first. Every row holds `deploy_hash`, `event_log_id` (used as
+/// the pagination cursor), `block_hash`, `cost`, `error_message` and the nullable `height` of the
+/// block, which is only known if the block is stored.
+///
+/// * `error_contains` - case-insensitive substring of the error message, any if `None`
+/// * `from_height`/`to_height` - inclusive bounds of the height of the block, which exclude
+/// deploys of blocks that aren't stored
+pub fn create_get_failed_stmt(
+    error_contains: Option<String>,
+    from_height: Option<u64>,
+    to_height: Option<u64>,
+    cursor: Option<u64>,
+    limit: u32,
+) -> SelectStatement {
+    Query::select()
+        .column((DeployProcessed::Table, DeployProcessed::DeployHash))
+        .column((DeployProcessed::Table, DeployProcessed::EventLogId))
+        .column((DeployProcessed::Table, DeployProcessed::BlockHash))
+        .column((DeployProcessed::Table, DeployProcessed::Cost))
+        .column((DeployProcessed::Table, DeployProcessed::ErrorMessage))
+        .column((BlockAdded::Table, BlockAdded::Height))
+        .from(DeployProcessed::Table)
+        .left_join(
+            BlockAdded::Table,
+            Expr::col((BlockAdded::Table, BlockAdded::BlockHash))
+                .equals((DeployProcessed::Table, DeployProcessed::BlockHash)),
+        )
+        .and_where(Expr::col((DeployProcessed::Table, DeployProcessed::IsSuccess)).eq(false))
+        .and_where_option(error_contains.map(|error_contains| {
+            Expr::expr(Func::lower(Expr::col((
+                DeployProcessed::Table,
+                DeployProcessed::ErrorMessage,
+            ))))
+            .like(
+                LikeExpr::new(format!(
+                    "%{}%",
+                    escape_like_pattern(&error_contains.to_lowercase())
+                ))
+                .escape(LIKE_ESCAPE),
+            )
+        }))
+        .and_where_option(
+            from_height
+                .map(|height| Expr::col((BlockAdded::Table, BlockAdded::Height)).gte(height)),
+        )
+        .and_where_option(
+            to_height.map(|height| Expr::col((BlockAdded::Table, BlockAdded::Height)).lte(height)),
+        )
+        .and_where_option(cursor.map(|event_log_id| {
+            Expr::col((DeployProcessed::Table, DeployProcessed::EventLogId)).lt(event_log_id)
+        }))
+        .order_by(
+            (DeployProcessed::Table, DeployProcessed::EventLogId),
+            Order::Desc,
+        )
+        .limit(limit as u64)
+        .to_owned()
+}
+
+/// Makes the `LIKE` wildcards of `pattern` match literally.
+fn escape_like_pattern(pattern: &str) -> String {
+    let mut escaped = String::with_capacity(pattern.len());
+    for character in pattern.chars() {
+        if character == LIKE_ESCAPE || character == '%' || character == '_' {
+            escaped.push(LIKE_ESCAPE);
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+/// Selects the deploys processed in the given block. Every row holds `deploy_hash`,
+/// `processed_raw` and the nullable `accepted_raw` of the matching [DeployAccepted] record.
+pub fn create_get_with_accepted_by_block_hash_stmt(block_hash: String) -> SelectStatement {
+    Query::select()
+        .column((DeployProcessed::Table, DeployProcessed::DeployHash))
+        .expr_as(
+            Expr::col((DeployProcessed::Table, DeployProcessed::Raw)),
+            Alias::new("processed_raw"),
+        )
+        .expr_as(
+            Expr::col((DeployAccepted::Table, DeployAccepted::Raw)),
+            Alias::new("accepted_raw"),
+        )
+        .from(DeployProcessed::Table)
+        .left_join(
+            DeployAccepted::Table,
+            Expr::col((DeployAccepted::Table, DeployAccepted::DeployHash))
+                .equals((DeployProcessed::Table, DeployProcessed::DeployHash)),
+        )
+        .and_where(Expr::col((DeployProcessed::Table, DeployProcessed::BlockHash)).eq(block_hash))
+        .to_owned()
+}
+
+/// Selects `event_log_id` (cast to text) and `raw` of at most `limit` records with an
+/// `event_log_id` greater than `after_event_log_id`, oldest first, so that all records can be read
+/// in batches.
+pub fn create_get_after_event_log_id_stmt(
+    after_event_log_id: Option<u64>,
+    limit: u32,
+) -> SelectStatement {
+    Query::select()
+        .expr_as(
+            Func::cast_as(Expr::col(DeployProcessed::EventLogId), Alias::new("TEXT")),
+            Alias::new("event_log_id"),
+        )
+        .column(DeployProcessed::Raw)
+        .from(DeployProcessed::Table)
+        .and_where_option(
+            after_event_log_id
+                .map(|event_log_id| Expr::col(DeployProcessed::EventLogId).gt(event_log_id)),
+        )
+        .order_by(DeployProcessed::EventLogId, Order::Asc)
+        .limit(limit as u64)
+        .to_owned()
+}
+
 pub fn create_get_by_hash_stmt(deploy_hash: String) -> SelectStatement {
     Query::select()
         .column(DeployProcessed::Raw)
@@ -69,3 +324,30 @@ pub fn create_get_by_hash_stmt(deploy_hash: String) -> SelectStatement {
         .and_where(Expr::col(DeployProcessed::DeployHash).eq(deploy_hash))
         .to_owned()
 }
+
+#[test]
+fn create_get_failed_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"DeployProcessed\".\"deploy_hash\", \"DeployProcessed\".\"event_log_id\", \"DeployProcessed\".\"block_hash\", \"DeployProcessed\".\"cost\", \"DeployProcessed\".\"error_message\", \"BlockAdded\".\"height\" FROM \"DeployProcessed\" LEFT JOIN \"BlockAdded\" ON \"BlockAdded\".\"block_hash\" = \"DeployProcessed\".\"block_hash\" WHERE \"DeployProcessed\".\"is_success\" = FALSE AND LOWER(\"DeployProcessed\".\"error_message\") LIKE '%out of gas!_%' ESCAPE '!' AND \"BlockAdded\".\"height\" >= 10 AND \"BlockAdded\".\"height\" <= 20 AND \"DeployProcessed\".\"event_log_id\" < 7 ORDER BY \"DeployProcessed\".\"event_log_id\" DESC LIMIT 5";
+
+    let got_sql = create_get_failed_stmt(
+        Some("Out of Gas_".to_string()),
+        Some(10),
+        Some(20),
+        Some(7),
+        5,
+    )
+    .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_after_event_log_id_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT CAST(\"event_log_id\" AS TEXT) AS \"event_log_id\", \"raw\" FROM \"DeployProcessed\" WHERE \"event_log_id\" > 3 ORDER BY \"event_log_id\" ASC LIMIT 5";
+
+    let got_sql = create_get_after_event_log_id_stmt(Some(3), 5).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
@@ -1,9 +1,15 @@
+use casper_types::Timestamp;
 use sea_query::{
-    error::Result as SqResult, Asterisk, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Iden,
-    Index, InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
+    error::Result as SqResult, Alias, Asterisk, ColumnDef, Expr, ForeignKey, ForeignKeyAction,
+    Func, Iden, Index, IndexCreateStatement, InsertStatement, Order, Query, SelectStatement, Table,
+    TableCreateStatement,
 };
 
-use super::event_type::EventType;
+use super::{
+    block_added::BlockAdded, deploy_accepted::DeployAccepted, deploy_expired::DeployExpired,
+    deploy_processed::DeployProcessed, event_type::EventType, fault::Fault,
+    finality_signature::FinalitySignature, step::Step,
+};
 
 #[derive(Iden)]
 pub enum EventLog {
@@ -78,6 +84,15 @@ pub fn create_table_stmt(is_big_integer_id: bool) -> TableCreateStatement {
         .to_owned()
 }
 
+pub fn create_inserted_timestamp_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_event_log_inserted_timestamp")
+        .table(EventLog::Table)
+        .col(EventLog::InsertedTimestamp)
+        .to_owned()
+}
+
 pub fn create_insert_stmt(
     event_type_id: u8,
     event_source_address: &str,
@@ -109,3 +124,188 @@ pub fn count() -> SelectStatement {
         .from(EventLog::Table)
         .to_owned()
 }
+
+/// Selects the event log entries matching the given filters, oldest first. Every row holds
+/// `event_log_id` (used as the pagination cursor), `event_type_name`, `event_source_address`,
+/// `event_id`, `event_key`, the `inserted_timestamp` and `emitted_timestamp` cast to text and the
+/// nullable `payload`, which is the raw event stored in the table of its type.
+///
+/// * `event_type_id` - if set, only events of this type are selected
+/// * `event_source_address` - if set, only events delivered by this node are selected
+/// * `since` - if set, only events stored at or after this time are selected
+/// * `until` - if set, only events stored before this time are selected
+pub fn create_get_stmt(
+    event_type_id: Option<u8>,
+    event_source_address: Option<String>,
+    since: Option<Timestamp>,
+    until: Option<Timestamp>,
+    cursor: Option<u64>,
+    limit: u32,
+) -> SelectStatement {
+    Query::select()
+        .column((EventLog::Table, EventLog::EventLogId))
+        .column((EventType::Table, EventType::EventTypeName))
+        .column((EventLog::Table, EventLog::EventSourceAddress))
+        .column((EventLog::Table, EventLog::EventId))
+        .column((EventLog::Table, EventLog::EventKey))
+        .expr_as(
+            Func::cast_as(
+                Expr::col((EventLog::Table, EventLog::InsertedTimestamp)),
+                Alias::new("TEXT"),
+            ),
+            Alias::new("inserted_timestamp"),
+        )
+        .expr_as(
+            Func::cast_as(
+                Expr::col((EventLog::Table, EventLog::EmittedTimestamp)),
+                Alias::new("TEXT"),
+            ),
+            Alias::new("emitted_timestamp"),
+        )
+        .expr_as(
+            Func::coalesce([
+                Expr::col((BlockAdded::Table, BlockAdded::Raw)).into(),
+                Expr::col((DeployAccepted::Table, DeployAccepted::Raw)).into(),
+                Expr::col((DeployProcessed::Table, DeployProcessed::Raw)).into(),
+                Expr::col((DeployExpired::Table, DeployExpired::Raw)).into(),
+                Expr::col((Fault::Table, Fault::Raw)).into(),
+                Expr::col((FinalitySignature::Table, FinalitySignature::Raw)).into(),
+                Expr::col((Step::Table, Step::Raw)).into(),
+            ]),
+            Alias::new("payload"),
+        )
+        .from(EventLog::Table)
+        .inner_join(
+            EventType::Table,
+            Expr::col((EventType::Table, EventType::EventTypeId))
+                .equals((EventLog::Table, EventLog::EventTypeId)),
+        )
+        // Every event is stored in the table of its type together with the id of its log entry.
+        .left_join(
+            BlockAdded::Table,
+            Expr::col((BlockAdded::Table, BlockAdded::EventLogId))
+                .equals((EventLog::Table, EventLog::EventLogId)),
+        )
+        .left_join(
+            DeployAccepted::Table,
+            Expr::col((DeployAccepted::Table, DeployAccepted::EventLogId))
+                .equals((EventLog::Table, EventLog::EventLogId)),
+        )
+        .left_join(
+            DeployProcessed::Table,
+            Expr::col((DeployProcessed::Table, DeployProcessed::EventLogId))
+                .equals((EventLog::Table, EventLog::EventLogId)),
+        )
+        .left_join(
+            DeployExpired::Table,
+            Expr::col((DeployExpired::Table, DeployExpired::EventLogId))
+                .equals((EventLog::Table, EventLog::EventLogId)),
+        )
+        .left_join(
+            Fault::Table,
+            Expr::col((Fault::Table, Fault::EventLogId))
+                .equals((EventLog::Table, EventLog::EventLogId)),
+        )
+        .left_join(
+            FinalitySignature::Table,
+            Expr::col((FinalitySignature::Table, FinalitySignature::EventLogId))
+                .equals((EventLog::Table, EventLog::EventLogId)),
+        )
+        .left_join(
+            Step::Table,
+            Expr::col((Step::Table, Step::EventLogId))
+                .equals((EventLog::Table, EventLog::EventLogId)),
+        )
+        .and_where_option(event_type_id.map(|event_type_id| {
+            Expr::col((EventLog::Table, EventLog::EventTypeId)).eq(event_type_id)
+        }))
+        .and_where_option(event_source_address.map(|event_source_address| {
+            Expr::col((EventLog::Table, EventLog::EventSourceAddress)).eq(event_source_address)
+        }))
+        .and_where_option(since.map(|since| {
+            Expr::col((EventLog::Table, EventLog::InsertedTimestamp))
+                .gte(to_stored_timestamp(since))
+        }))
+        .and_where_option(until.map(|until| {
+            Expr::col((EventLog::Table, EventLog::InsertedTimestamp)).lt(to_stored_timestamp(until))
+        }))
+        .and_where_option(cursor.map(|event_log_id| {
+            Expr::col((EventLog::Table, EventLog::EventLogId)).gt(event_log_id)
+        }))
+        .order_by((EventLog::Table, EventLog::EventLogId), Order::Asc)
+        .limit(limit as u64)
+        .to_owned()
+}
+
+/// Selects, for every type of event delivered by the node with the given address, the
+/// `event_type_name`, the `event_count` and the `last_inserted_timestamp` cast to text.
+pub fn create_get_statistics_by_event_source_address_stmt(
+    event_source_address: String,
+) -> SelectStatement {
+    Query::select()
+        .column((EventType::Table, EventType::EventTypeName))
+        .expr_as(
+            Expr::col((EventLog::Table, EventLog::EventLogId)).count(),
+            Alias::new("event_count"),
+        )
+        .expr_as(
+            Func::cast_as(
+                Expr::col((EventLog::Table, EventLog::InsertedTimestamp)).max(),
+                Alias::new("TEXT"),
+            ),
+            Alias::new("last_inserted_timestamp"),
+        )
+        .from(EventLog::Table)
+        .inner_join(
+            EventType::Table,
+            Expr::col((EventType::Table, EventType::EventTypeId))
+                .equals((EventLog::Table, EventLog::EventTypeId)),
+        )
+        .and_where(
+            Expr::col((EventLog::Table, EventLog::EventSourceAddress)).eq(event_source_address),
+        )
+        .group_by_col((EventType::Table, EventType::EventTypeName))
+        .to_owned()
+}
+
+/// Formats `timestamp` the way the databases store timestamps, `YYYY-MM-DD HH:MM:SS`, so that
+/// it compares correctly with the stored ones. Milliseconds are dropped, as SQLite stores seconds.
+fn to_stored_timestamp(timestamp: Timestamp) -> String {
+    // `Timestamp` is displayed in RFC 3339 format with milliseconds, `YYYY-MM-DDTHH:MM:SS.sssZ`.
+    timestamp
+        .to_string()
+        .replacen('T', " ", 1)
+        .chars()
+        .take(19)
+        .collect()
+}
+
+#[test]
+fn create_get_stmt_should_filter_by_all_given_criteria() {
+    use sea_query::SqliteQueryBuilder;
+    use std::str::FromStr;
+
+    let got_sql = create_get_stmt(
+        Some(1),
+        Some("127.0.0.1".to_string()),
+        Some(Timestamp::from_str("2023-03-01T12:00:00.500Z").unwrap()),
+        Some(Timestamp::from_str("2023-03-02T00:00:00Z").unwrap()),
+        Some(10),
+        5,
+    )
+    .to_string(SqliteQueryBuilder);
+
+    assert!(got_sql.ends_with("WHERE \"event_log\".\"event_type_id\" = 1 AND \"event_log\".\"event_source_address\" = '127.0.0.1' AND \"event_log\".\"inserted_timestamp\" >= '2023-03-01 12:00:00' AND \"event_log\".\"inserted_timestamp\" < '2023-03-02 00:00:00' AND \"event_log\".\"event_log_id\" > 10 ORDER BY \"event_log\".\"event_log_id\" ASC LIMIT 5"));
+}
+
+#[test]
+fn create_get_statistics_by_event_source_address_stmt_should_group_by_event_type() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"event_type\".\"event_type_name\", COUNT(\"event_log\".\"event_log_id\") AS \"event_count\", CAST(MAX(\"event_log\".\"inserted_timestamp\") AS TEXT) AS \"last_inserted_timestamp\" FROM \"event_log\" INNER JOIN \"event_type\" ON \"event_type\".\"event_type_id\" = \"event_log\".\"event_type_id\" WHERE \"event_log\".\"event_source_address\" = 'http://127.0.0.1:18101/' GROUP BY \"event_type\".\"event_type_name\"";
+
+    let got_sql =
+        create_get_statistics_by_event_source_address_stmt("http://127.0.0.1:18101/".to_string())
+            .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
@@ -21,6 +21,23 @@ pub enum EventTypeId {
     Shutdown = 8,
 }
 
+impl EventTypeId {
+    /// Returns the event type with the given name, as stored in the `event_type` table.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "BlockAdded" => Some(EventTypeId::BlockAdded),
+            "DeployAccepted" => Some(EventTypeId::DeployAccepted),
+            "DeployExpired" => Some(EventTypeId::DeployExpired),
+            "DeployProcessed" => Some(EventTypeId::DeployProcessed),
+            "Fault" => Some(EventTypeId::Fault),
+            "FinalitySignature" => Some(EventTypeId::FinalitySignature),
+            "Step" => Some(EventTypeId::Step),
+            "Shutdown" => Some(EventTypeId::Shutdown),
+            _ => None,
+        }
+    }
+}
+
 pub fn create_table_stmt() -> TableCreateStatement {
     Table::create()
         .table(EventType::Table)
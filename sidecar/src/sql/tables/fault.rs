@@ -1,12 +1,12 @@
 use sea_query::{
     error::Result as SqResult, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Iden, Index,
-    InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
+    IndexCreateStatement, InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
 };
 
 use super::event_log::EventLog;
 
 #[derive(Iden)]
-enum Fault {
+pub(super) enum Fault {
     #[iden = "Fault"]
     Table,
     Era,
@@ -48,6 +48,15 @@ pub fn create_table_stmt(db_supports_unsigned: bool) -> TableCreateStatement {
         .to_owned()
 }
 
+pub fn create_event_log_id_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_Fault_event_log_id")
+        .table(Fault::Table)
+        .col(Fault::EventLogId)
+        .to_owned()
+}
+
 pub fn create_insert_stmt(
     era: u64,
     public_key: String,
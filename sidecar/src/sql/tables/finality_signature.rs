@@ -1,12 +1,13 @@
 use sea_query::{
-    error::Result as SqResult, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Iden, Index,
-    InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
+    error::Result as SqResult, Alias, ColumnDef, Condition, Expr, ForeignKey, ForeignKeyAction,
+    Iden, Index, IndexCreateStatement, InsertStatement, Order, Query, SelectStatement, Table,
+    TableCreateStatement,
 };
 
-use super::event_log::EventLog;
+use super::{block_added::BlockAdded, event_log::EventLog, validator_weight::ValidatorWeight};
 
 #[derive(Iden)]
-enum FinalitySignature {
+pub(super) enum FinalitySignature {
     #[iden = "FinalitySignature"]
     Table,
     BlockHash,
@@ -53,6 +54,24 @@ pub fn create_table_stmt() -> TableCreateStatement {
         .to_owned()
 }
 
+pub fn create_event_log_id_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_FinalitySignature_event_log_id")
+        .table(FinalitySignature::Table)
+        .col(FinalitySignature::EventLogId)
+        .to_owned()
+}
+
+pub fn create_public_key_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_FinalitySignature_public_key")
+        .table(FinalitySignature::Table)
+        .col(FinalitySignature::PublicKey)
+        .to_owned()
+}
+
 pub fn create_insert_stmt(
     block_hash: String,
     public_key: String,
@@ -83,3 +102,114 @@ pub fn create_get_finality_signatures_by_block_stmt(block_hash: String) -> Selec
         .and_where(Expr::col(FinalitySignature::BlockHash).eq(block_hash))
         .to_owned()
 }
+
+/// Selects the `block_hash` of at most one finality signature made with the given public key.
+pub fn create_has_finality_signatures_by_public_key_stmt(public_key: String) -> SelectStatement {
+    Query::select()
+        .column(FinalitySignature::BlockHash)
+        .from(FinalitySignature::Table)
+        .and_where(Expr::col(FinalitySignature::PublicKey).eq(public_key))
+        .limit(1)
+        .to_owned()
+}
+
+/// Selects the `height` and `block_hash` of at most `limit` stored blocks from eras in which the
+/// given public key had a weight, lowest height first, together with the `raw` finality signature
+/// made with the key for the block, NULL if it didn't sign it.
+pub fn create_get_by_public_key_stmt(
+    public_key: String,
+    from_height: u64,
+    to_height: Option<u64>,
+    limit: u32,
+) -> SelectStatement {
+    Query::select()
+        .column((BlockAdded::Table, BlockAdded::Height))
+        .column((BlockAdded::Table, BlockAdded::BlockHash))
+        .column((FinalitySignature::Table, FinalitySignature::Raw))
+        .from(BlockAdded::Table)
+        .inner_join(
+            ValidatorWeight::Table,
+            Condition::all()
+                .add(
+                    Expr::col((ValidatorWeight::Table, ValidatorWeight::EraId))
+                        .equals((BlockAdded::Table, BlockAdded::EraId)),
+                )
+                .add(
+                    Expr::col((ValidatorWeight::Table, ValidatorWeight::PublicKey))
+                        .eq(public_key.clone()),
+                ),
+        )
+        .left_join(
+            FinalitySignature::Table,
+            Condition::all()
+                .add(
+                    Expr::col((FinalitySignature::Table, FinalitySignature::BlockHash))
+                        .equals((BlockAdded::Table, BlockAdded::BlockHash)),
+                )
+                .add(
+                    Expr::col((FinalitySignature::Table, FinalitySignature::PublicKey))
+                        .eq(public_key),
+                ),
+        )
+        .and_where(Expr::col((BlockAdded::Table, BlockAdded::Height)).gte(from_height))
+        .and_where_option(
+            to_height.map(|height| Expr::col((BlockAdded::Table, BlockAdded::Height)).lte(height)),
+        )
+        .order_by((BlockAdded::Table, BlockAdded::Height), Order::Asc)
+        .limit(limit as u64)
+        .to_owned()
+}
+
+/// Selects, for every era in the given range, the number (`signed_blocks`) of its stored blocks
+/// signed with the given public key.
+pub fn create_count_by_public_key_per_era_stmt(
+    public_key: String,
+    from_era: Option<u64>,
+    to_era: Option<u64>,
+) -> SelectStatement {
+    Query::select()
+        .column((BlockAdded::Table, BlockAdded::EraId))
+        .expr_as(
+            Expr::col((BlockAdded::Table, BlockAdded::Height)).count(),
+            Alias::new("signed_blocks"),
+        )
+        .from(FinalitySignature::Table)
+        .inner_join(
+            BlockAdded::Table,
+            Expr::col((BlockAdded::Table, BlockAdded::BlockHash))
+                .equals((FinalitySignature::Table, FinalitySignature::BlockHash)),
+        )
+        .and_where(
+            Expr::col((FinalitySignature::Table, FinalitySignature::PublicKey)).eq(public_key),
+        )
+        .and_where_option(
+            from_era.map(|era_id| Expr::col((BlockAdded::Table, BlockAdded::EraId)).gte(era_id)),
+        )
+        .and_where_option(
+            to_era.map(|era_id| Expr::col((BlockAdded::Table, BlockAdded::EraId)).lte(era_id)),
+        )
+        .group_by_col((BlockAdded::Table, BlockAdded::EraId))
+        .to_owned()
+}
+
+#[test]
+fn create_get_by_public_key_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"BlockAdded\".\"height\", \"BlockAdded\".\"block_hash\", \"FinalitySignature\".\"raw\" FROM \"BlockAdded\" INNER JOIN \"ValidatorWeight\" ON \"ValidatorWeight\".\"era_id\" = \"BlockAdded\".\"era_id\" AND \"ValidatorWeight\".\"public_key\" = 'ab' LEFT JOIN \"FinalitySignature\" ON \"FinalitySignature\".\"block_hash\" = \"BlockAdded\".\"block_hash\" AND \"FinalitySignature\".\"public_key\" = 'ab' WHERE \"BlockAdded\".\"height\" >= 10 AND \"BlockAdded\".\"height\" <= 20 ORDER BY \"BlockAdded\".\"height\" ASC LIMIT 5";
+
+    let got_sql = create_get_by_public_key_stmt("ab".to_string(), 10, Some(20), 5)
+        .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_count_by_public_key_per_era_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"BlockAdded\".\"era_id\", COUNT(\"BlockAdded\".\"height\") AS \"signed_blocks\" FROM \"FinalitySignature\" INNER JOIN \"BlockAdded\" ON \"BlockAdded\".\"block_hash\" = \"FinalitySignature\".\"block_hash\" WHERE \"FinalitySignature\".\"public_key\" = 'ab' AND \"BlockAdded\".\"era_id\" >= 2 GROUP BY \"BlockAdded\".\"era_id\"";
+
+    let got_sql = create_count_by_public_key_per_era_stmt("ab".to_string(), Some(2), None)
+        .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
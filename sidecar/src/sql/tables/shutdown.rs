@@ -1,6 +1,6 @@
 use sea_query::{
-    error::Result as SqResult, ColumnDef, ForeignKey, ForeignKeyAction, Iden, Index,
-    InsertStatement, Query, Table, TableCreateStatement,
+    error::Result as SqResult, Alias, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Func, Iden,
+    Index, InsertStatement, Order, Query, SelectStatement, Table, TableCreateStatement,
 };
 
 use super::event_log::EventLog;
@@ -63,6 +63,24 @@ pub fn create_insert_stmt(
         .map(|stmt| stmt.to_owned())
 }
 
+/// Selects the `shutdown_timestamp`s, cast to text, of the most recent shutdowns of the node with
+/// the given address, most recent first.
+pub fn create_get_by_event_source_address_stmt(
+    event_source_address: String,
+    limit: u32,
+) -> SelectStatement {
+    Query::select()
+        .expr_as(
+            Func::cast_as(Expr::col(Shutdown::ShutdownTimestamp), Alias::new("TEXT")),
+            Alias::new("shutdown_timestamp"),
+        )
+        .from(Shutdown::Table)
+        .and_where(Expr::col(Shutdown::EventSourceAddress).eq(event_source_address))
+        .order_by(Shutdown::ShutdownTimestamp, Order::Desc)
+        .limit(limit as u64)
+        .to_owned()
+}
+
 #[test]
 fn create_table_stmt_should_produce_create_table_sql() {
     use sea_query::SqliteQueryBuilder;
@@ -85,3 +103,15 @@ fn create_insert_stmt_should_produce_insert_sql() {
 
     assert_eq!(got_sql, expected_sql);
 }
+
+#[test]
+fn create_get_by_event_source_address_stmt_should_select_most_recent_shutdowns() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT CAST(\"shutdown_timestamp\" AS TEXT) AS \"shutdown_timestamp\" FROM \"Shutdown\" WHERE \"event_source_address\" = 'http://100.100.100.1:1782/' ORDER BY \"shutdown_timestamp\" DESC LIMIT 10";
+
+    let got_sql =
+        create_get_by_event_source_address_stmt("http://100.100.100.1:1782/".to_string(), 10)
+            .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
@@ -1,12 +1,12 @@
 use sea_query::{
     error::Result as SqResult, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Iden, Index,
-    InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
+    IndexCreateStatement, InsertStatement, Query, SelectStatement, Table, TableCreateStatement,
 };
 
 use super::event_log::EventLog;
 
 #[derive(Iden)]
-enum Step {
+pub(super) enum Step {
     #[iden = "Step"]
     Table,
     Era,
@@ -40,6 +40,15 @@ pub fn create_table_stmt(db_supports_unsigned: bool) -> TableCreateStatement {
         .to_owned()
 }
 
+pub fn create_event_log_id_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_Step_event_log_id")
+        .table(Step::Table)
+        .col(Step::EventLogId)
+        .to_owned()
+}
+
 pub fn create_insert_stmt(era: u64, raw: String, event_log_id: u64) -> SqResult<InsertStatement> {
     Query::insert()
         .into_table(Step::Table)
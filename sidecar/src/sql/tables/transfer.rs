@@ -0,0 +1,230 @@
+use sea_query::{
+    error::Result as SqResult, ColumnDef, Cond, Expr, Iden, Index, IndexCreateStatement,
+    InsertStatement, Order, Query, SelectStatement, Table, TableCreateStatement,
+};
+
+use super::block_added::BlockAdded;
+
+/// Native transfers, extracted from the execution results of processed deploys.
+#[derive(Iden)]
+enum Transfer {
+    #[iden = "Transfer"]
+    Table,
+    /// Synthetic key, increasing in the order the transfers were stored.
+    Id,
+    DeployHash,
+    /// Position of the transfer among those of its deploy.
+    TransferIndex,
+    BlockHash,
+    FromAccount,
+    ToAccount,
+    Amount,
+    TransferId,
+}
+
+pub fn create_table_stmt(is_big_integer_id: bool) -> TableCreateStatement {
+    let mut binding = ColumnDef::new(Transfer::Id);
+    let mut id_col_definition = binding.auto_increment().not_null().primary_key();
+    if is_big_integer_id {
+        id_col_definition = id_col_definition.big_integer();
+    } else {
+        id_col_definition = id_col_definition.integer();
+    }
+    Table::create()
+        .table(Transfer::Table)
+        .if_not_exists()
+        .col(id_col_definition)
+        .col(ColumnDef::new(Transfer::DeployHash).string().not_null())
+        .col(ColumnDef::new(Transfer::TransferIndex).integer().not_null())
+        .col(ColumnDef::new(Transfer::BlockHash).string().not_null())
+        .col(ColumnDef::new(Transfer::FromAccount).string().not_null())
+        .col(ColumnDef::new(Transfer::ToAccount).string().null())
+        // Amounts are U512 values, so they are stored as decimal strings.
+        .col(ColumnDef::new(Transfer::Amount).string().not_null())
+        // Transfer ids are arbitrary u64 values which don't fit a signed BIGINT, so they are
+        // stored as decimal strings too.
+        .col(ColumnDef::new(Transfer::TransferId).string().null())
+        .index(
+            Index::create()
+                .unique()
+                .name("UDX_Transfer_deploy_hash")
+                .col(Transfer::DeployHash)
+                .col(Transfer::TransferIndex),
+        )
+        .to_owned()
+}
+
+pub fn create_from_account_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_Transfer_from_account")
+        .table(Transfer::Table)
+        .col(Transfer::FromAccount)
+        .to_owned()
+}
+
+pub fn create_to_account_index_stmt() -> IndexCreateStatement {
+    Index::create()
+        .if_not_exists()
+        .name("IDX_Transfer_to_account")
+        .table(Transfer::Table)
+        .col(Transfer::ToAccount)
+        .to_owned()
+}
+
+/// Values of the columns of a single transfer.
+pub struct TransferRecord {
+    /// Hex-encoded account hash of the sender.
+    pub from_account: String,
+    /// Hex-encoded account hash of the recipient, `None` if the transfer targeted a purse.
+    pub to_account: Option<String>,
+    /// Decimal string of the U512 amount.
+    pub amount: String,
+    pub transfer_id: Option<u64>,
+}
+
+/// Inserts the transfers of the given deploy, in the order they were made.
+/// `transfers` must not be empty.
+pub fn create_insert_stmt(
+    deploy_hash: String,
+    block_hash: String,
+    transfers: Vec<TransferRecord>,
+) -> SqResult<InsertStatement> {
+    let mut insert_stmt = Query::insert()
+        .into_table(Transfer::Table)
+        .columns([
+            Transfer::DeployHash,
+            Transfer::TransferIndex,
+            Transfer::BlockHash,
+            Transfer::FromAccount,
+            Transfer::ToAccount,
+            Transfer::Amount,
+            Transfer::TransferId,
+        ])
+        .to_owned();
+    for (transfer_index, transfer) in transfers.into_iter().enumerate() {
+        insert_stmt.values(vec![
+            deploy_hash.clone().into(),
+            (transfer_index as u32).into(),
+            block_hash.clone().into(),
+            transfer.from_account.into(),
+            transfer.to_account.into(),
+            transfer.amount.into(),
+            transfer
+                .transfer_id
+                .map(|transfer_id| transfer_id.to_string())
+                .into(),
+        ])?;
+    }
+    Ok(insert_stmt)
+}
+
+/// Selects the transfers sent or received by the given account, newest first. Every row holds
+/// `id` (used as the pagination cursor), `deploy_hash`, `block_hash`, `from_account`,
+/// `to_account`, `amount`, `transfer_id` and the nullable `height` of the block, which is only
+/// known if the block is stored.
+///
+/// * `account` - hex-encoded account hash of the sender or the recipient
+/// * `from_height`/`to_height` - inclusive bounds of the height of the block, which exclude
+/// transfers of blocks that aren't stored
+pub fn create_get_by_account_stmt(
+    account: String,
+    from_height: Option<u64>,
+    to_height: Option<u64>,
+    cursor: Option<u64>,
+    limit: u32,
+) -> SelectStatement {
+    select_with_height()
+        .cond_where(
+            Cond::all()
+                .add(
+                    Cond::any()
+                        .add(
+                            Expr::col((Transfer::Table, Transfer::FromAccount)).eq(account.clone()),
+                        )
+                        .add(Expr::col((Transfer::Table, Transfer::ToAccount)).eq(account)),
+                )
+                .add_option(
+                    from_height.map(|height| {
+                        Expr::col((BlockAdded::Table, BlockAdded::Height)).gte(height)
+                    }),
+                )
+                .add_option(
+                    to_height.map(|height| {
+                        Expr::col((BlockAdded::Table, BlockAdded::Height)).lte(height)
+                    }),
+                )
+                .add_option(cursor.map(|id| Expr::col((Transfer::Table, Transfer::Id)).lt(id))),
+        )
+        .order_by((Transfer::Table, Transfer::Id), Order::Desc)
+        .limit(limit as u64)
+        .to_owned()
+}
+
+/// Selects the transfers made by the given deploy, in the order they were made. The rows have the
+/// same columns as those of [create_get_by_account_stmt].
+pub fn create_get_by_deploy_hash_stmt(deploy_hash: String) -> SelectStatement {
+    select_with_height()
+        .and_where(Expr::col((Transfer::Table, Transfer::DeployHash)).eq(deploy_hash))
+        .order_by((Transfer::Table, Transfer::TransferIndex), Order::Asc)
+        .to_owned()
+}
+
+fn select_with_height() -> SelectStatement {
+    Query::select()
+        .column((Transfer::Table, Transfer::Id))
+        .column((Transfer::Table, Transfer::DeployHash))
+        .column((Transfer::Table, Transfer::BlockHash))
+        .column((Transfer::Table, Transfer::FromAccount))
+        .column((Transfer::Table, Transfer::ToAccount))
+        .column((Transfer::Table, Transfer::Amount))
+        .column((Transfer::Table, Transfer::TransferId))
+        .column((BlockAdded::Table, BlockAdded::Height))
+        .from(Transfer::Table)
+        .left_join(
+            BlockAdded::Table,
+            Expr::col((BlockAdded::Table, BlockAdded::BlockHash))
+                .equals((Transfer::Table, Transfer::BlockHash)),
+        )
+        .to_owned()
+}
+
+#[test]
+fn create_insert_stmt_should_produce_insert_sql_with_all_transfers() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "INSERT INTO \"Transfer\" (\"deploy_hash\", \"transfer_index\", \"block_hash\", \"from_account\", \"to_account\", \"amount\", \"transfer_id\") VALUES ('deploy', 0, 'block', 'aa', 'bb', '10', '7'), ('deploy', 1, 'block', 'aa', NULL, '20', NULL)";
+
+    let got_sql = create_insert_stmt(
+        "deploy".to_string(),
+        "block".to_string(),
+        vec![
+            TransferRecord {
+                from_account: "aa".to_string(),
+                to_account: Some("bb".to_string()),
+                amount: "10".to_string(),
+                transfer_id: Some(7),
+            },
+            TransferRecord {
+                from_account: "aa".to_string(),
+                to_account: None,
+                amount: "20".to_string(),
+                transfer_id: None,
+            },
+        ],
+    )
+    .unwrap()
+    .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_by_account_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"Transfer\".\"id\", \"Transfer\".\"deploy_hash\", \"Transfer\".\"block_hash\", \"Transfer\".\"from_account\", \"Transfer\".\"to_account\", \"Transfer\".\"amount\", \"Transfer\".\"transfer_id\", \"BlockAdded\".\"height\" FROM \"Transfer\" LEFT JOIN \"BlockAdded\" ON \"BlockAdded\".\"block_hash\" = \"Transfer\".\"block_hash\" WHERE (\"Transfer\".\"from_account\" = 'aa' OR \"Transfer\".\"to_account\" = 'aa') AND \"BlockAdded\".\"height\" >= 10 AND \"BlockAdded\".\"height\" <= 20 AND \"Transfer\".\"id\" < 7 ORDER BY \"Transfer\".\"id\" DESC LIMIT 5";
+
+    let got_sql = create_get_by_account_stmt("aa".to_string(), Some(10), Some(20), Some(7), 5)
+        .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
@@ -0,0 +1,105 @@
+use sea_query::{
+    error::Result as SqResult, ColumnDef, Expr, Iden, Index, InsertStatement, Order, Query,
+    SelectStatement, Table, TableCreateStatement,
+};
+
+/// Rewards of the validators, extracted from the era reports of switch blocks.
+#[derive(Iden)]
+enum ValidatorReward {
+    #[iden = "ValidatorReward"]
+    Table,
+    EraId,
+    PublicKey,
+    Amount,
+}
+
+pub fn create_table_stmt() -> TableCreateStatement {
+    Table::create()
+        .table(ValidatorReward::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(ValidatorReward::EraId)
+                .big_unsigned()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(ValidatorReward::PublicKey)
+                .string()
+                .not_null(),
+        )
+        // Amounts are u64 values, which don't all fit into a signed BIGINT, so they are stored as
+        // decimal strings.
+        .col(ColumnDef::new(ValidatorReward::Amount).string().not_null())
+        .index(
+            Index::create()
+                .primary()
+                .name("PDX_ValidatorReward")
+                .col(ValidatorReward::PublicKey)
+                .col(ValidatorReward::EraId),
+        )
+        .to_owned()
+}
+
+/// Inserts the rewards paid in the given era, one record per `(public_key, amount)` pair.
+/// `rewards` must not be empty.
+pub fn create_insert_stmt(era_id: u64, rewards: Vec<(String, u64)>) -> SqResult<InsertStatement> {
+    let mut insert_stmt = Query::insert()
+        .into_table(ValidatorReward::Table)
+        .columns([
+            ValidatorReward::EraId,
+            ValidatorReward::PublicKey,
+            ValidatorReward::Amount,
+        ])
+        .to_owned();
+    for (public_key, amount) in rewards {
+        insert_stmt.values(vec![
+            era_id.into(),
+            public_key.into(),
+            amount.to_string().into(),
+        ])?;
+    }
+    Ok(insert_stmt)
+}
+
+/// Selects `era_id` and `amount` of the rewards of the given validator in the inclusive range of
+/// eras, lowest era first.
+pub fn create_get_by_public_key_stmt(
+    public_key: String,
+    from_era: Option<u64>,
+    to_era: Option<u64>,
+) -> SelectStatement {
+    Query::select()
+        .columns([ValidatorReward::EraId, ValidatorReward::Amount])
+        .from(ValidatorReward::Table)
+        .and_where(Expr::col(ValidatorReward::PublicKey).eq(public_key))
+        .and_where_option(from_era.map(|era_id| Expr::col(ValidatorReward::EraId).gte(era_id)))
+        .and_where_option(to_era.map(|era_id| Expr::col(ValidatorReward::EraId).lte(era_id)))
+        .order_by(ValidatorReward::EraId, Order::Asc)
+        .to_owned()
+}
+
+#[test]
+fn create_insert_stmt_should_produce_insert_sql_with_all_rewards() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "INSERT INTO \"ValidatorReward\" (\"era_id\", \"public_key\", \"amount\") VALUES (3, 'ab', '10'), (3, 'cd', '18446744073709551615')";
+
+    let got_sql = create_insert_stmt(
+        3,
+        vec![("ab".to_string(), 10), ("cd".to_string(), u64::MAX)],
+    )
+    .unwrap()
+    .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
+
+#[test]
+fn create_get_by_public_key_stmt_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"era_id\", \"amount\" FROM \"ValidatorReward\" WHERE \"public_key\" = 'ab' AND \"era_id\" >= 2 AND \"era_id\" <= 5 ORDER BY \"era_id\" ASC";
+
+    let got_sql = create_get_by_public_key_stmt("ab".to_string(), Some(2), Some(5))
+        .to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
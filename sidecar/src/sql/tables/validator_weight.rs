@@ -0,0 +1,88 @@
+use sea_query::{
+    error::Result as SqResult, ColumnDef, Expr, Iden, Index, InsertStatement, Order, Query,
+    SelectStatement, Table, TableCreateStatement,
+};
+
+/// Weights of the validators, extracted from the `next_era_validator_weights` of switch blocks.
+#[derive(Iden)]
+pub(super) enum ValidatorWeight {
+    #[iden = "ValidatorWeight"]
+    Table,
+    EraId,
+    PublicKey,
+    Weight,
+}
+
+pub fn create_table_stmt() -> TableCreateStatement {
+    Table::create()
+        .table(ValidatorWeight::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(ValidatorWeight::EraId)
+                .big_unsigned()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(ValidatorWeight::PublicKey)
+                .string()
+                .not_null(),
+        )
+        // Weights are U512 values, so they are stored as decimal strings.
+        .col(ColumnDef::new(ValidatorWeight::Weight).string().not_null())
+        .index(
+            Index::create()
+                .primary()
+                .name("PDX_ValidatorWeight")
+                .col(ValidatorWeight::PublicKey)
+                .col(ValidatorWeight::EraId),
+        )
+        .to_owned()
+}
+
+/// Inserts the weights the validators have in the given era, one record per
+/// `(public_key, weight)` pair. `weights` must not be empty.
+pub fn create_insert_stmt(
+    era_id: u64,
+    weights: Vec<(String, String)>,
+) -> SqResult<InsertStatement> {
+    let mut insert_stmt = Query::insert()
+        .into_table(ValidatorWeight::Table)
+        .columns([
+            ValidatorWeight::EraId,
+            ValidatorWeight::PublicKey,
+            ValidatorWeight::Weight,
+        ])
+        .to_owned();
+    for (public_key, weight) in weights {
+        insert_stmt.values(vec![era_id.into(), public_key.into(), weight.into()])?;
+    }
+    Ok(insert_stmt)
+}
+
+/// Selects `era_id` and `weight` of the given validator in the inclusive range of eras, lowest
+/// era first.
+pub fn create_get_by_public_key_stmt(
+    public_key: String,
+    from_era: Option<u64>,
+    to_era: Option<u64>,
+) -> SelectStatement {
+    Query::select()
+        .columns([ValidatorWeight::EraId, ValidatorWeight::Weight])
+        .from(ValidatorWeight::Table)
+        .and_where(Expr::col(ValidatorWeight::PublicKey).eq(public_key))
+        .and_where_option(from_era.map(|era_id| Expr::col(ValidatorWeight::EraId).gte(era_id)))
+        .and_where_option(to_era.map(|era_id| Expr::col(ValidatorWeight::EraId).lte(era_id)))
+        .order_by(ValidatorWeight::EraId, Order::Asc)
+        .to_owned()
+}
+
+#[test]
+fn create_get_by_public_key_stmt_without_era_range_should_produce_select_sql() {
+    use sea_query::SqliteQueryBuilder;
+    let expected_sql = "SELECT \"era_id\", \"weight\" FROM \"ValidatorWeight\" WHERE \"public_key\" = 'ab' ORDER BY \"era_id\" ASC";
+
+    let got_sql =
+        create_get_by_public_key_stmt("ab".to_string(), None, None).to_string(SqliteQueryBuilder);
+
+    assert_eq!(got_sql, expected_sql);
+}
@@ -4,15 +4,19 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use async_trait::async_trait;
 use casper_types::testing::TestRng;
-use casper_types::AsymmetricType;
+use casper_types::{AsymmetricType, Timestamp};
 use rand::Rng;
 
 use casper_event_types::FinalitySignature as FinSig;
 
+use crate::sql::tables::event_type::EventTypeId;
 use crate::types::{
     database::{
-        DatabaseReadError, DatabaseReader, DatabaseWriteError, DatabaseWriter, DeployAggregate,
-        Migration,
+        contract_call_of, ContractDeploy, DatabaseReadError, DatabaseReader, DatabaseWriteError,
+        DatabaseWriter, DeployAggregate, DeployEventTiming, DeployStatus, DeploySummary,
+        DeployTimeline, EraBlockRange, EventLogEntry, FailedDeploy, Migration, NativeTransfer,
+        NodeEventStatistics, Page, ValidatorEraParticipation, ValidatorEraReward,
+        ValidatorEraWeight, ValidatorSignature,
     },
     sse_events::*,
 };
@@ -20,15 +24,42 @@ use crate::types::{
 #[derive(Clone)]
 pub struct FakeDatabase {
     data: Arc<Mutex<HashMap<String, String>>>,
+    event_log: Arc<Mutex<Vec<EventLogEntry>>>,
 }
 
 impl FakeDatabase {
     pub(crate) fn new() -> Self {
         Self {
             data: Arc::new(Mutex::new(HashMap::new())),
+            event_log: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
+    /// Appends an entry for a saved event to the event log.
+    fn log_event(
+        &self,
+        event_type: &str,
+        event_id: u32,
+        event_source_address: String,
+        event_key: String,
+        payload: Option<&str>,
+    ) {
+        let mut event_log = self
+            .event_log
+            .lock()
+            .expect("Error acquiring lock on event log");
+        event_log.push(EventLogEntry {
+            event_log_id: event_log.len() as u64 + 1,
+            event_type: event_type.to_string(),
+            event_source_address,
+            event_id,
+            event_key,
+            inserted_timestamp: Timestamp::now(),
+            emitted_timestamp: Timestamp::now(),
+            payload: payload.map(|raw| serde_json::from_str(raw).expect("Error parsing payload")),
+        });
+    }
+
     /// Creates random SSE event data and saves them, returning the identifiers for each record.
     pub(crate) async fn populate_with_events(
         &self,
@@ -47,6 +78,7 @@ impl FakeDatabase {
             block_added_hash: block_added.hex_encoded_hash(),
             block_added_height: block_added.get_height(),
             deploy_accepted_hash: deploy_accepted.hex_encoded_hash(),
+            deploy_accepted_account: deploy_accepted.hex_encoded_account(),
             deploy_processed_hash: deploy_processed.hex_encoded_hash(),
             deploy_expired_hash: deploy_expired.hex_encoded_hash(),
             fault_era_id: fault.era_id.value(),
@@ -72,6 +104,36 @@ impl FakeDatabase {
 
         Ok(test_stored_keys)
     }
+
+    /// Finality signatures made with the given public key, together with the blocks they sign.
+    /// Signatures of blocks which aren't stored are skipped.
+    fn signatures_with_blocks(&self, public_key: &str) -> Vec<(FinalitySignature, BlockAdded)> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        data.iter()
+            .filter(|(key, _)| key.ends_with("-signatures"))
+            .filter_map(|(_, event)| serde_json::from_str::<Vec<FinalitySignature>>(event).ok())
+            .flatten()
+            .filter(|signature| signature.hex_encoded_public_key() == public_key)
+            .filter_map(|signature| {
+                let block = data
+                    .get(&signature.hex_encoded_block_hash())
+                    .and_then(|event| serde_json::from_str::<BlockAdded>(event).ok())?;
+                Some((signature, block))
+            })
+            .collect()
+    }
+
+    /// Stored blocks which carry an era end.
+    fn switch_blocks(&self) -> Vec<BlockAdded> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        data.iter()
+            .filter(|(key, _)| key.parse::<u64>().is_ok())
+            .filter_map(|(_, event)| serde_json::from_str::<BlockAdded>(event).ok())
+            .filter(|block| block.era_end().is_some())
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -92,6 +154,14 @@ impl DatabaseWriter for FakeDatabase {
 
         // For the sake of keeping the test fixture simple, I'm saving the event twice, one record for each identifier.
 
+        self.log_event(
+            "BlockAdded",
+            event_id,
+            event_source_address,
+            identifier_hash.clone(),
+            Some(&stringified_event),
+        );
+
         data.insert(identifier_hash, stringified_event.clone());
 
         data.insert(identifier_height, stringified_event);
@@ -114,6 +184,14 @@ impl DatabaseWriter for FakeDatabase {
         let stringified_event =
             serde_json::to_string(&deploy_accepted).expect("Error serialising event data");
 
+        self.log_event(
+            "DeployAccepted",
+            event_id,
+            event_source_address,
+            hash,
+            Some(&stringified_event),
+        );
+
         data.insert(identifier, stringified_event);
 
         Ok(0)
@@ -134,6 +212,14 @@ impl DatabaseWriter for FakeDatabase {
         let stringified_event =
             serde_json::to_string(&deploy_processed).expect("Error serialising event data");
 
+        self.log_event(
+            "DeployProcessed",
+            event_id,
+            event_source_address,
+            hash,
+            Some(&stringified_event),
+        );
+
         data.insert(identifier, stringified_event);
 
         Ok(0)
@@ -154,6 +240,14 @@ impl DatabaseWriter for FakeDatabase {
         let stringified_event =
             serde_json::to_string(&deploy_expired).expect("Error serialising event data");
 
+        self.log_event(
+            "DeployExpired",
+            event_id,
+            event_source_address,
+            hash,
+            Some(&stringified_event),
+        );
+
         data.insert(identifier, stringified_event);
 
         Ok(0)
@@ -168,12 +262,20 @@ impl DatabaseWriter for FakeDatabase {
     ) -> Result<u64, DatabaseWriteError> {
         let mut data = self.data.lock().expect("Error acquiring lock on data");
 
-        let identifier_era = fault.era_id.value().to_string();
+        let identifier_era = format!("{}-faults", fault.era_id.value());
         let identifier_public_key = fault.public_key.to_hex();
 
         let stringified_event =
             serde_json::to_string(&fault).expect("Error serialising event data");
 
+        self.log_event(
+            "Fault",
+            event_id,
+            event_source_address,
+            format!("{} {}", fault.era_id.value(), identifier_public_key),
+            Some(&stringified_event),
+        );
+
         // For the sake of keeping the test fixture simple, I'm saving the event twice, one record for each identifier.
 
         data.insert(identifier_era, stringified_event.clone());
@@ -192,9 +294,31 @@ impl DatabaseWriter for FakeDatabase {
     ) -> Result<u64, DatabaseWriteError> {
         let mut data = self.data.lock().expect("Error acquiring lock on data");
 
-        let identifier = finality_signature.hex_encoded_block_hash();
+        // This is suffixed to avoid overwriting the block, all signatures of a block are kept together.
+        let identifier = format!("{}-signatures", finality_signature.hex_encoded_block_hash());
+        self.log_event(
+            "FinalitySignature",
+            event_id,
+            event_source_address,
+            format!(
+                "{} {}",
+                finality_signature.hex_encoded_block_hash(),
+                finality_signature.hex_encoded_public_key()
+            ),
+            Some(
+                &serde_json::to_string(&finality_signature).expect("Error serialising event data"),
+            ),
+        );
+        let mut signatures = data
+            .get(&identifier)
+            .map(|event| {
+                serde_json::from_str::<Vec<FinalitySignature>>(event)
+                    .expect("Error deserialising event data")
+            })
+            .unwrap_or_default();
+        signatures.push(finality_signature);
         let stringified_event =
-            serde_json::to_string(&finality_signature).expect("Error serialising event data");
+            serde_json::to_string(&signatures).expect("Error serialising event data");
 
         data.insert(identifier, stringified_event);
 
@@ -210,9 +334,17 @@ impl DatabaseWriter for FakeDatabase {
     ) -> Result<u64, DatabaseWriteError> {
         let mut data = self.data.lock().expect("Error acquiring lock on data");
 
-        let identifier = step.era_id.value().to_string();
+        let identifier = format!("{}-step", step.era_id.value());
         let stringified_event = serde_json::to_string(&step).expect("Error serialising event data");
 
+        self.log_event(
+            "Step",
+            event_id,
+            event_source_address,
+            step.era_id.value().to_string(),
+            Some(&stringified_event),
+        );
+
         data.insert(identifier, stringified_event);
 
         Ok(0)
@@ -232,6 +364,14 @@ impl DatabaseWriter for FakeDatabase {
         let event_key = format!("{}-{}", event_source_address, unix_timestamp);
         let stringified_event = serde_json::to_string("{}").expect("Error serialising event data");
 
+        self.log_event(
+            "Shutdown",
+            event_id,
+            event_source_address,
+            event_key.clone(),
+            None,
+        );
+
         data.insert(event_key, stringified_event);
         Ok(0)
     }
@@ -252,6 +392,37 @@ impl DatabaseReader for FakeDatabase {
         Ok(block_added)
     }
 
+    async fn get_latest_blocks(&self, count: u32) -> Result<Vec<BlockAdded>, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        let mut blocks: Vec<BlockAdded> = data
+            .iter()
+            .filter(|(key, _)| key.parse::<u64>().is_ok())
+            .filter_map(|(_, event)| serde_json::from_str::<BlockAdded>(event).ok())
+            .collect();
+        blocks.sort_by_key(|block| std::cmp::Reverse(block.get_height()));
+        blocks.truncate(count as usize);
+
+        if blocks.is_empty() {
+            return Err(DatabaseReadError::NotFound);
+        }
+        Ok(blocks)
+    }
+
+    async fn get_last_block_added_event_log_id(&self) -> Result<u64, DatabaseReadError> {
+        let event_log = self
+            .event_log
+            .lock()
+            .expect("Error acquiring lock on event log");
+
+        event_log
+            .iter()
+            .rev()
+            .find(|entry| entry.event_type == "BlockAdded")
+            .map(|entry| entry.event_log_id)
+            .ok_or(DatabaseReadError::NotFound)
+    }
+
     async fn get_block_by_height(&self, height: u64) -> Result<BlockAdded, DatabaseReadError> {
         let data = self.data.lock().expect("Error acquiring lock on data");
 
@@ -272,6 +443,61 @@ impl DatabaseReader for FakeDatabase {
         };
     }
 
+    async fn get_blocks_by_height_range(
+        &self,
+        from_height: u64,
+        to_height: Option<u64>,
+        limit: u32,
+    ) -> Result<Vec<BlockAdded>, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        // Blocks are stored under their height, but so are other events keyed by era - those won't deserialise as BlockAdded.
+        let mut blocks: Vec<BlockAdded> = data
+            .iter()
+            .filter_map(|(key, event)| {
+                let height = key.parse::<u64>().ok()?;
+                if height < from_height || to_height.map_or(false, |to| height > to) {
+                    return None;
+                }
+                serde_json::from_str::<BlockAdded>(event).ok()
+            })
+            .collect();
+        blocks.sort_by_key(BlockAdded::get_height);
+        blocks.truncate(limit as usize);
+
+        Ok(blocks)
+    }
+
+    async fn get_era_block_range(&self, era_id: u64) -> Result<EraBlockRange, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        let heights: Vec<u64> = data
+            .iter()
+            .filter(|(key, _)| key.parse::<u64>().is_ok())
+            .filter_map(|(_, event)| serde_json::from_str::<BlockAdded>(event).ok())
+            .filter(|block| block.get_era_id() == era_id)
+            .map(|block| block.get_height())
+            .collect();
+        match (heights.iter().min(), heights.iter().max()) {
+            (Some(first_block_height), Some(last_block_height)) => Ok(EraBlockRange {
+                first_block_height: *first_block_height,
+                last_block_height: *last_block_height,
+                block_count: heights.len() as u64,
+            }),
+            _ => Err(DatabaseReadError::NotFound),
+        }
+    }
+
+    async fn get_switch_block_of_era(&self, era_id: u64) -> Result<BlockAdded, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        data.iter()
+            .filter(|(key, _)| key.parse::<u64>().is_ok())
+            .filter_map(|(_, event)| serde_json::from_str::<BlockAdded>(event).ok())
+            .find(|block| block.get_era_id() == era_id && block.era_end().is_some())
+            .ok_or(DatabaseReadError::NotFound)
+    }
+
     async fn get_deploy_aggregate_by_hash(
         &self,
         hash: &str,
@@ -295,6 +521,7 @@ impl DatabaseReader for FakeDatabase {
                     deploy_accepted: Some(deploy_accepted),
                     deploy_processed: Some(deploy_processed),
                     deploy_expired: false,
+                    timeline: None,
                 })
             } else if data.get(&expired_key).is_some() {
                 let deploy_expired = match data.get(&expired_key) {
@@ -309,6 +536,7 @@ impl DatabaseReader for FakeDatabase {
                     deploy_accepted: Some(deploy_accepted),
                     deploy_processed: None,
                     deploy_expired: deploy_expired.is_some(),
+                    timeline: None,
                 })
             } else {
                 Ok(DeployAggregate {
@@ -316,6 +544,7 @@ impl DatabaseReader for FakeDatabase {
                     deploy_accepted: Some(deploy_accepted),
                     deploy_processed: None,
                     deploy_expired: false,
+                    timeline: None,
                 })
             }
         } else {
@@ -368,6 +597,400 @@ impl DatabaseReader for FakeDatabase {
         };
     }
 
+    async fn get_deploy_aggregates_by_block(
+        &self,
+        block_hash: &str,
+    ) -> Result<Vec<DeployAggregate>, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        let mut aggregates = Vec::new();
+        for (key, event) in data.iter() {
+            let hash = match key.strip_suffix("-processed") {
+                Some(hash) => hash,
+                None => continue,
+            };
+            let deploy_processed = serde_json::from_str::<DeployProcessed>(event)
+                .map_err(DatabaseReadError::Serialisation)?;
+            if deploy_processed.hex_encoded_block_hash() != block_hash {
+                continue;
+            }
+            let deploy_accepted = match data.get(&format!("{}-accepted", hash)) {
+                Some(raw) => Some(
+                    serde_json::from_str::<DeployAccepted>(raw)
+                        .map_err(DatabaseReadError::Serialisation)?,
+                ),
+                None => None,
+            };
+            aggregates.push(DeployAggregate {
+                deploy_hash: hash.to_string(),
+                deploy_accepted,
+                deploy_processed: Some(deploy_processed),
+                deploy_expired: false,
+                timeline: None,
+            });
+        }
+
+        Ok(aggregates)
+    }
+
+    async fn get_deploy_aggregates_by_hashes(
+        &self,
+        deploy_hashes: &[String],
+    ) -> Result<Vec<DeployAggregate>, DatabaseReadError> {
+        let mut aggregates = Vec::new();
+        for deploy_hash in deploy_hashes {
+            match self.get_deploy_aggregate_by_hash(deploy_hash).await {
+                Ok(aggregate) => aggregates.push(aggregate),
+                Err(DatabaseReadError::NotFound) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(aggregates)
+    }
+
+    async fn get_deploy_timeline(&self, hash: &str) -> Result<DeployTimeline, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        // The fake doesn't record where or when events arrived, so every stored event gets the
+        // same placeholder timing.
+        let timing_of = |suffix: &str| {
+            data.get(&format!("{}-{}", hash, suffix))
+                .map(|_| DeployEventTiming {
+                    event_source_address: "127.0.0.1".to_string(),
+                    inserted_timestamp: Timestamp::zero(),
+                    emitted_timestamp: Timestamp::zero(),
+                })
+        };
+        let accepted = timing_of("accepted");
+        let processed = timing_of("processed");
+        let expired = timing_of("expired");
+
+        if accepted.is_none() && processed.is_none() && expired.is_none() {
+            return Err(DatabaseReadError::NotFound);
+        }
+        Ok(DeployTimeline::new(accepted, processed, expired))
+    }
+
+    async fn get_deploy_summaries_by_account(
+        &self,
+        public_key: &str,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<DeploySummary>, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        let mut summaries = Vec::new();
+        for (key, event) in data.iter() {
+            let hash = match key.strip_suffix("-accepted") {
+                Some(hash) => hash,
+                None => continue,
+            };
+            let deploy_accepted = serde_json::from_str::<DeployAccepted>(event)
+                .map_err(DatabaseReadError::Serialisation)?;
+            if deploy_accepted.hex_encoded_account() != public_key {
+                continue;
+            }
+            let status = DeployStatus::from_flags(
+                data.contains_key(&format!("{}-processed", hash)),
+                data.contains_key(&format!("{}-expired", hash)),
+            );
+            summaries.push(DeploySummary {
+                deploy_hash: hash.to_string(),
+                status,
+            });
+        }
+        // There is no insertion order to page by, so the cursor is simply the number of summaries already returned.
+        summaries.sort_by(|a, b| a.deploy_hash.cmp(&b.deploy_hash));
+        let offset = cursor.unwrap_or(0) as usize;
+        let summaries = summaries
+            .into_iter()
+            .enumerate()
+            .skip(offset)
+            .take(limit as usize + 1)
+            .map(|(index, summary)| (index as u64 + 1, summary))
+            .collect();
+
+        Ok(
+            Page::from_overfetched(summaries, limit, |(position, _)| *position)
+                .map(|(_, summary)| summary),
+        )
+    }
+
+    async fn get_failed_deploys(
+        &self,
+        error_contains: Option<&str>,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<FailedDeploy>, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        let error_contains = error_contains.map(str::to_lowercase);
+        let mut failed_deploys = Vec::new();
+        for (key, event) in data.iter() {
+            if !key.ends_with("-processed") {
+                continue;
+            }
+            let deploy_processed = serde_json::from_str::<DeployProcessed>(event)
+                .map_err(DatabaseReadError::Serialisation)?;
+            let error_message = match deploy_processed.error_message() {
+                Some(error_message) => error_message.to_string(),
+                None => continue,
+            };
+            if let Some(error_contains) = &error_contains {
+                if !error_message
+                    .to_lowercase()
+                    .contains(error_contains.as_str())
+                {
+                    continue;
+                }
+            }
+            let block_height = data
+                .get(&deploy_processed.hex_encoded_block_hash())
+                .and_then(|block| serde_json::from_str::<BlockAdded>(block).ok())
+                .map(|block| block.get_height());
+            let is_in_range = match block_height {
+                Some(height) => {
+                    from_height.map_or(true, |from_height| height >= from_height)
+                        && to_height.map_or(true, |to_height| height <= to_height)
+                }
+                None => from_height.is_none() && to_height.is_none(),
+            };
+            if !is_in_range {
+                continue;
+            }
+            failed_deploys.push(FailedDeploy {
+                deploy_hash: deploy_processed.hex_encoded_hash(),
+                block_hash: deploy_processed.hex_encoded_block_hash(),
+                block_height,
+                cost: deploy_processed.cost().to_string(),
+                error_message,
+            });
+        }
+        // There is no insertion order to page by, so the cursor is simply the number of deploys already returned.
+        failed_deploys.sort_by(|a, b| a.deploy_hash.cmp(&b.deploy_hash));
+        let offset = cursor.unwrap_or(0) as usize;
+        let failed_deploys = failed_deploys
+            .into_iter()
+            .enumerate()
+            .skip(offset)
+            .take(limit as usize + 1)
+            .map(|(index, failed_deploy)| (index as u64 + 1, failed_deploy))
+            .collect();
+
+        Ok(
+            Page::from_overfetched(failed_deploys, limit, |(position, _)| *position)
+                .map(|(_, failed_deploy)| failed_deploy),
+        )
+    }
+
+    async fn get_transfers_by_account(
+        &self,
+        account: &str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<NativeTransfer>, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        let mut transfers = Vec::new();
+        for (key, event) in data.iter() {
+            if !key.ends_with("-processed") {
+                continue;
+            }
+            let deploy_processed = serde_json::from_str::<DeployProcessed>(event)
+                .map_err(DatabaseReadError::Serialisation)?;
+            let block_height = data
+                .get(&deploy_processed.hex_encoded_block_hash())
+                .and_then(|block| serde_json::from_str::<BlockAdded>(block).ok())
+                .map(|block| block.get_height());
+            let is_in_range = match block_height {
+                Some(height) => {
+                    from_height.map_or(true, |from_height| height >= from_height)
+                        && to_height.map_or(true, |to_height| height <= to_height)
+                }
+                None => from_height.is_none() && to_height.is_none(),
+            };
+            if !is_in_range {
+                continue;
+            }
+            transfers.extend(
+                native_transfers_of(&deploy_processed, block_height)
+                    .into_iter()
+                    .filter(|transfer| {
+                        transfer.from == account || transfer.to.as_deref() == Some(account)
+                    }),
+            );
+        }
+        // There is no insertion order to page by, so the cursor is simply the number of transfers already returned.
+        transfers.sort_by(|a, b| a.deploy_hash.cmp(&b.deploy_hash));
+        let offset = cursor.unwrap_or(0) as usize;
+        let transfers = transfers
+            .into_iter()
+            .enumerate()
+            .skip(offset)
+            .take(limit as usize + 1)
+            .map(|(index, transfer)| (index as u64 + 1, transfer))
+            .collect();
+
+        Ok(
+            Page::from_overfetched(transfers, limit, |(position, _)| *position)
+                .map(|(_, transfer)| transfer),
+        )
+    }
+
+    async fn get_transfers_by_deploy(
+        &self,
+        hash: &str,
+    ) -> Result<Vec<NativeTransfer>, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        let deploy_processed = match data.get(&format!("{}-processed", hash)) {
+            Some(event) => serde_json::from_str::<DeployProcessed>(event)
+                .map_err(DatabaseReadError::Serialisation)?,
+            None => return Ok(Vec::new()),
+        };
+        let block_height = data
+            .get(&deploy_processed.hex_encoded_block_hash())
+            .and_then(|block| serde_json::from_str::<BlockAdded>(block).ok())
+            .map(|block| block.get_height());
+        Ok(native_transfers_of(&deploy_processed, block_height))
+    }
+
+    async fn get_deploys_by_contract(
+        &self,
+        hash: &str,
+        entry_point: Option<&str>,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<ContractDeploy>, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        let mut contract_deploys = Vec::new();
+        for (key, event) in data.iter() {
+            if !key.ends_with("-accepted") {
+                continue;
+            }
+            let deploy_accepted = serde_json::from_str::<DeployAccepted>(event)
+                .map_err(DatabaseReadError::Serialisation)?;
+            let contract_call = match contract_call_of(&deploy_accepted) {
+                Some(contract_call) => contract_call,
+                None => continue,
+            };
+            let is_called = contract_call.contract_hash.as_deref() == Some(hash)
+                || contract_call.contract_package_hash.as_deref() == Some(hash);
+            if !is_called
+                || entry_point.map_or(false, |entry_point| {
+                    entry_point != contract_call.entry_point
+                })
+            {
+                continue;
+            }
+            let deploy_hash = deploy_accepted.hex_encoded_hash();
+            let deploy_processed = data
+                .get(&format!("{}-processed", deploy_hash))
+                .map(|event| serde_json::from_str::<DeployProcessed>(event))
+                .transpose()
+                .map_err(DatabaseReadError::Serialisation)?;
+            let is_expired = data.contains_key(&format!("{}-expired", deploy_hash));
+            contract_deploys.push(ContractDeploy {
+                deploy_hash,
+                contract_hash: contract_call.contract_hash,
+                contract_package_hash: contract_call.contract_package_hash,
+                entry_point: contract_call.entry_point,
+                status: DeployStatus::from_flags(deploy_processed.is_some(), is_expired),
+                is_success: deploy_processed
+                    .as_ref()
+                    .map(|deploy_processed| deploy_processed.is_success()),
+                block_hash: deploy_processed
+                    .as_ref()
+                    .map(|deploy_processed| deploy_processed.hex_encoded_block_hash()),
+            });
+        }
+        // There is no insertion order to page by, so the cursor is simply the number of deploys already returned.
+        contract_deploys.sort_by(|a, b| a.deploy_hash.cmp(&b.deploy_hash));
+        let offset = cursor.unwrap_or(0) as usize;
+        let contract_deploys = contract_deploys
+            .into_iter()
+            .enumerate()
+            .skip(offset)
+            .take(limit as usize + 1)
+            .map(|(index, contract_deploy)| (index as u64 + 1, contract_deploy))
+            .collect();
+
+        Ok(
+            Page::from_overfetched(contract_deploys, limit, |(position, _)| *position)
+                .map(|(_, contract_deploy)| contract_deploy),
+        )
+    }
+
+    async fn get_event_log(
+        &self,
+        event_type: Option<EventTypeId>,
+        event_source_address: Option<&str>,
+        since: Option<Timestamp>,
+        until: Option<Timestamp>,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<EventLogEntry>, DatabaseReadError> {
+        let event_log = self
+            .event_log
+            .lock()
+            .expect("Error acquiring lock on event log");
+
+        let event_type_id = event_type.map(|event_type| event_type as u8);
+        let entries = event_log
+            .iter()
+            .filter(|entry| {
+                event_type_id.map_or(true, |event_type_id| {
+                    EventTypeId::from_name(&entry.event_type)
+                        .map_or(false, |entry_type| entry_type as u8 == event_type_id)
+                })
+            })
+            .filter(|entry| {
+                event_source_address.map_or(true, |address| entry.event_source_address == address)
+            })
+            .filter(|entry| since.map_or(true, |since| entry.inserted_timestamp >= since))
+            .filter(|entry| until.map_or(true, |until| entry.inserted_timestamp < until))
+            .filter(|entry| cursor.map_or(true, |cursor| entry.event_log_id > cursor))
+            .take(limit as usize + 1)
+            .cloned()
+            .collect();
+        Ok(Page::from_overfetched(entries, limit, |entry| {
+            entry.event_log_id
+        }))
+    }
+
+    async fn get_node_event_statistics(
+        &self,
+        event_source_address: &str,
+        max_shutdowns: u32,
+    ) -> Result<NodeEventStatistics, DatabaseReadError> {
+        let event_log = self
+            .event_log
+            .lock()
+            .expect("Error acquiring lock on event log");
+
+        let mut statistics = NodeEventStatistics::default();
+        for entry in event_log
+            .iter()
+            .filter(|entry| entry.event_source_address == event_source_address)
+        {
+            *statistics
+                .events_received
+                .entry(entry.event_type.clone())
+                .or_default() += 1;
+            statistics.last_seen = statistics.last_seen.max(Some(entry.inserted_timestamp));
+            if entry.event_type == "Shutdown" {
+                statistics.shutdowns.insert(0, entry.inserted_timestamp);
+            }
+        }
+        statistics.shutdowns.truncate(max_shutdowns as usize);
+        Ok(statistics)
+    }
+
     async fn get_faults_by_public_key(
         &self,
         public_key: &str,
@@ -386,7 +1009,7 @@ impl DatabaseReader for FakeDatabase {
     async fn get_faults_by_era(&self, era: u64) -> Result<Vec<Fault>, DatabaseReadError> {
         let data = self.data.lock().expect("Error acquiring lock on data");
 
-        return if let Some(event) = data.get(&era.to_string()) {
+        return if let Some(event) = data.get(&format!("{}-faults", era)) {
             let fault =
                 serde_json::from_str::<Fault>(event).map_err(DatabaseReadError::Serialisation)?;
             Ok(vec![fault])
@@ -401,25 +1024,185 @@ impl DatabaseReader for FakeDatabase {
     ) -> Result<Vec<FinSig>, DatabaseReadError> {
         let data = self.data.lock().expect("Error acquiring lock on data");
 
-        return if let Some(event) = data.get(block_hash) {
-            let finality_signature =
-                serde_json::from_str::<FinSig>(event).map_err(DatabaseReadError::Serialisation)?;
-            Ok(vec![finality_signature])
+        return if let Some(event) = data.get(&format!("{}-signatures", block_hash)) {
+            serde_json::from_str::<Vec<FinSig>>(event).map_err(DatabaseReadError::Serialisation)
         } else {
             Err(DatabaseReadError::NotFound)
         };
     }
 
+    async fn has_finality_signatures_by_public_key(
+        &self,
+        public_key: &str,
+    ) -> Result<bool, DatabaseReadError> {
+        let data = self.data.lock().expect("Error acquiring lock on data");
+
+        let has_signatures = data
+            .iter()
+            .filter(|(key, _)| key.ends_with("-signatures"))
+            .filter_map(|(_, event)| serde_json::from_str::<Vec<FinalitySignature>>(event).ok())
+            .flatten()
+            .any(|signature| signature.hex_encoded_public_key() == public_key);
+        Ok(has_signatures)
+    }
+
+    async fn get_validator_signatures(
+        &self,
+        public_key: &str,
+        from_height: u64,
+        to_height: Option<u64>,
+        limit: u32,
+    ) -> Result<Vec<ValidatorSignature>, DatabaseReadError> {
+        // The validator is expected to sign the blocks of the eras it had a weight in.
+        let weighted_eras: Vec<u64> = match self.get_validator_weights(public_key, None, None).await
+        {
+            Ok(weights) => weights.iter().map(|weight| weight.era_id).collect(),
+            Err(DatabaseReadError::NotFound) => return Ok(vec![]),
+            Err(error) => return Err(error),
+        };
+        let mut finality_signatures: HashMap<String, FinalitySignature> = self
+            .signatures_with_blocks(public_key)
+            .into_iter()
+            .map(|(signature, block)| (block.hex_encoded_hash(), signature))
+            .collect();
+
+        let mut signatures: Vec<ValidatorSignature> = {
+            let data = self.data.lock().expect("Error acquiring lock on data");
+            data.iter()
+                .filter(|(key, _)| key.parse::<u64>().is_ok())
+                .filter_map(|(_, event)| serde_json::from_str::<BlockAdded>(event).ok())
+                .filter(|block| {
+                    weighted_eras.contains(&block.get_era_id())
+                        && block.get_height() >= from_height
+                        && to_height.map_or(true, |to| block.get_height() <= to)
+                })
+                .map(|block| {
+                    let finality_signature = finality_signatures
+                        .remove(&block.hex_encoded_hash())
+                        .map(|signature| signature.inner());
+                    ValidatorSignature {
+                        block_height: block.get_height(),
+                        block_hash: block.hex_encoded_hash(),
+                        signed: finality_signature.is_some(),
+                        finality_signature,
+                    }
+                })
+                .collect()
+        };
+        signatures.sort_by_key(|signature| signature.block_height);
+        signatures.truncate(limit as usize);
+
+        Ok(signatures)
+    }
+
+    async fn get_validator_participation(
+        &self,
+        public_key: &str,
+        from_era: Option<u64>,
+        to_era: Option<u64>,
+    ) -> Result<Vec<ValidatorEraParticipation>, DatabaseReadError> {
+        let weights = self
+            .get_validator_weights(public_key, from_era, to_era)
+            .await?;
+
+        let mut block_counts: HashMap<u64, u64> = HashMap::new();
+        {
+            let data = self.data.lock().expect("Error acquiring lock on data");
+            data.iter()
+                .filter(|(key, _)| key.parse::<u64>().is_ok())
+                .filter_map(|(_, event)| serde_json::from_str::<BlockAdded>(event).ok())
+                .for_each(|block| *block_counts.entry(block.get_era_id()).or_default() += 1);
+        }
+        let mut signed_counts: HashMap<u64, u64> = HashMap::new();
+        for (_, block) in self.signatures_with_blocks(public_key) {
+            *signed_counts.entry(block.get_era_id()).or_default() += 1;
+        }
+
+        Ok(weights
+            .into_iter()
+            .map(|weight| ValidatorEraParticipation {
+                era_id: weight.era_id,
+                weight: weight.weight,
+                expected_blocks: block_counts.get(&weight.era_id).copied().unwrap_or(0),
+                signed_blocks: signed_counts.get(&weight.era_id).copied().unwrap_or(0),
+            })
+            .collect())
+    }
+
     async fn get_step_by_era(&self, era: u64) -> Result<Step, DatabaseReadError> {
         let data = self.data.lock().expect("Error acquiring lock on data");
 
-        return if let Some(event) = data.get(&era.to_string()) {
+        return if let Some(event) = data.get(&format!("{}-step", era)) {
             serde_json::from_str::<Step>(event).map_err(DatabaseReadError::Serialisation)
         } else {
             Err(DatabaseReadError::NotFound)
         };
     }
 
+    async fn get_validator_rewards(
+        &self,
+        public_key: &str,
+        from_era: Option<u64>,
+        to_era: Option<u64>,
+    ) -> Result<Vec<ValidatorEraReward>, DatabaseReadError> {
+        let mut rewards: Vec<ValidatorEraReward> = self
+            .switch_blocks()
+            .iter()
+            .flat_map(|block| {
+                let era_id = block.get_era_id();
+                block
+                    .era_end()
+                    .map(|era_end| era_end.era_report().rewards().to_vec())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|reward| reward.validator().to_hex() == public_key)
+                    .map(move |reward| ValidatorEraReward {
+                        era_id,
+                        amount: reward.amount(),
+                    })
+            })
+            .filter(|reward| is_in_era_range(reward.era_id, from_era, to_era))
+            .collect();
+        rewards.sort_by_key(|reward| reward.era_id);
+
+        if rewards.is_empty() {
+            return Err(DatabaseReadError::NotFound);
+        }
+        Ok(rewards)
+    }
+
+    async fn get_validator_weights(
+        &self,
+        public_key: &str,
+        from_era: Option<u64>,
+        to_era: Option<u64>,
+    ) -> Result<Vec<ValidatorEraWeight>, DatabaseReadError> {
+        let mut weights: Vec<ValidatorEraWeight> = self
+            .switch_blocks()
+            .iter()
+            .flat_map(|block| {
+                let era_id = block.get_era_id() + 1;
+                block
+                    .era_end()
+                    .map(|era_end| era_end.next_era_validator_weights().to_vec())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|weight| weight.validator().to_hex() == public_key)
+                    .map(move |weight| ValidatorEraWeight {
+                        era_id,
+                        weight: weight.weight().to_string(),
+                    })
+            })
+            .filter(|weight| is_in_era_range(weight.era_id, from_era, to_era))
+            .collect();
+        weights.sort_by_key(|weight| weight.era_id);
+
+        if weights.is_empty() {
+            return Err(DatabaseReadError::NotFound);
+        }
+        Ok(weights)
+    }
+
     async fn get_number_of_events(&self) -> Result<u64, DatabaseReadError> {
         Ok(0)
     }
@@ -429,10 +1212,37 @@ impl DatabaseReader for FakeDatabase {
     }
 }
 
+fn is_in_era_range(era_id: u64, from_era: Option<u64>, to_era: Option<u64>) -> bool {
+    from_era.map_or(true, |from_era| era_id >= from_era)
+        && to_era.map_or(true, |to_era| era_id <= to_era)
+}
+
+fn native_transfers_of(
+    deploy_processed: &DeployProcessed,
+    block_height: Option<u64>,
+) -> Vec<NativeTransfer> {
+    deploy_processed
+        .transfers()
+        .into_iter()
+        .map(|transfer| NativeTransfer {
+            deploy_hash: deploy_processed.hex_encoded_hash(),
+            block_hash: deploy_processed.hex_encoded_block_hash(),
+            block_height,
+            from: hex::encode(transfer.from.value()),
+            to: transfer
+                .to
+                .map(|account_hash| hex::encode(account_hash.value())),
+            amount: transfer.amount.to_string(),
+            transfer_id: transfer.id,
+        })
+        .collect()
+}
+
 pub struct IdentifiersForStoredEvents {
     pub block_added_hash: String,
     pub block_added_height: u64,
     pub deploy_accepted_hash: String,
+    pub deploy_accepted_account: String,
     pub deploy_processed_hash: String,
     pub deploy_expired_hash: String,
     pub fault_public_key: String,
@@ -5,7 +5,7 @@ use std::{
 };
 
 use anyhow::{Context, Error};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::database::{
     database_errors::DatabaseConfigError,
@@ -249,23 +249,89 @@ impl TryFrom<PostgresqlConfigSerdeTarget> for PostgresqlConfig {
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub struct RestServerConfig {
+    /// IP address or host name the server binds to, all interfaces if not set.
+    pub address: Option<String>,
     pub port: u16,
+    /// If set, the server listens on the Unix domain socket at this path instead of a TCP port.
+    pub unix_socket_path: Option<String>,
     pub max_concurrent_requests: u32,
     pub max_requests_per_second: u32,
+    pub max_deploys_in_batch: Option<u32>,
+    /// Maximum number of requests waiting for a deploy at the same time.
+    pub max_concurrent_deploy_waits: Option<u32>,
+    /// Keys accepted by the server. If empty, requests don't need a key.
+    #[serde(default)]
+    pub api_keys: Vec<RestApiKey>,
+    /// If set, the server only accepts HTTPS connections.
+    pub tls: Option<TlsConfig>,
+}
+
+/// API key granting access to the REST server.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct RestApiKey {
+    /// Identifies the key in logs and metrics, so that the key itself isn't disclosed.
+    pub name: String,
+    pub key: String,
+    /// Maximum number of requests per second made with this key, unlimited if not set.
+    pub max_requests_per_second: Option<u32>,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub struct EventStreamServerConfig {
+    /// IP address or host name the server binds to, all interfaces if not set.
+    pub address: Option<String>,
     pub port: u16,
+    /// If set, the server listens on the Unix domain socket at this path instead of a TCP port.
+    pub unix_socket_path: Option<String>,
     pub max_concurrent_subscribers: u32,
     pub event_stream_buffer_length: u32,
+    /// Keys accepted by the server. If empty, subscribers don't need a key.
+    #[serde(default)]
+    pub api_keys: Vec<EventStreamApiKey>,
+    /// If set, the server only accepts HTTPS connections.
+    pub tls: Option<TlsConfig>,
+}
+
+/// API key granting access to the event stream server.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct EventStreamApiKey {
+    /// Identifies the key in logs and metrics, so that the key itself isn't disclosed.
+    pub name: String,
+    pub key: String,
+    /// Maximum number of concurrent subscriptions made with this key, unlimited if not set.
+    pub max_subscribers: Option<u32>,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub struct AdminServerConfig {
+    /// IP address or host name the server binds to, all interfaces if not set.
+    pub address: Option<String>,
     pub port: u16,
+    /// If set, the server listens on the Unix domain socket at this path instead of a TCP port.
+    pub unix_socket_path: Option<String>,
     pub max_concurrent_requests: u32,
     pub max_requests_per_second: u32,
+    /// If set, the server only accepts HTTPS connections.
+    pub tls: Option<TlsConfig>,
+    /// Maximum time since the last event was received for the sidecar to be reported as ready,
+    /// not checked if not set.
+    pub max_event_age_in_seconds: Option<u64>,
+    /// Maximum percentage of the outbound channel which can be filled for the sidecar to be
+    /// reported as ready, 90 if not set.
+    pub max_outbound_channel_saturation_percent: Option<u32>,
+}
+
+/// Certificate of a server accepting HTTPS connections. The certificate and the key are reloaded
+/// when their files change.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// Path of the PEM file holding the certificate chain, starting with the server's certificate.
+    pub cert_path: String,
+    /// Path of the PEM file holding the private key of the certificate.
+    pub key_path: String,
+    /// Path of the PEM file holding the CA certificates of clients. If set, clients must present a
+    /// certificate signed by one of them.
+    pub client_ca_path: Option<String>,
 }
 
 #[cfg(test)]
@@ -333,9 +399,14 @@ mod tests {
             rest_server: build_rest_server_config(),
             event_stream_server: EventStreamServerConfig::default(),
             admin_server: Some(AdminServerConfig {
+                address: None,
                 port: 18887,
+                unix_socket_path: None,
                 max_concurrent_requests: 1,
                 max_requests_per_second: 1,
+                tls: None,
+                max_event_age_in_seconds: None,
+                max_outbound_channel_saturation_percent: None,
             }),
         };
         let parsed_config: Config = read_config("../EXAMPLE_NODE_CONFIG.toml")
@@ -348,9 +419,15 @@ mod tests {
 
     fn build_rest_server_config() -> RestServerConfig {
         RestServerConfig {
+            address: None,
             port: 18888,
+            unix_socket_path: None,
             max_concurrent_requests: 50,
             max_requests_per_second: 50,
+            max_deploys_in_batch: None,
+            max_concurrent_deploy_waits: None,
+            api_keys: vec![],
+            tls: None,
         }
     }
 
@@ -440,9 +517,15 @@ mod tests {
     impl Default for RestServerConfig {
         fn default() -> Self {
             Self {
+                address: None,
                 port: 17777,
+                unix_socket_path: None,
                 max_concurrent_requests: 50,
                 max_requests_per_second: 50,
+                max_deploys_in_batch: None,
+                max_concurrent_deploy_waits: None,
+                api_keys: vec![],
+                tls: None,
             }
         }
     }
@@ -450,9 +533,13 @@ mod tests {
     impl Default for EventStreamServerConfig {
         fn default() -> Self {
             Self {
+                address: None,
                 port: 19999,
+                unix_socket_path: None,
                 max_concurrent_subscribers: 100,
                 event_stream_buffer_length: 5000,
+                api_keys: vec![],
+                tls: None,
             }
         }
     }
@@ -3,16 +3,23 @@ use crate::{
         postgresql_database::PostgreSqlDatabase, sqlite_database::SqliteDatabase,
         types::DDLConfiguration,
     },
-    sql::tables,
+    sql::{tables, tables::event_type::EventTypeId},
     types::sse_events::{
         BlockAdded, DeployAccepted, DeployExpired, DeployProcessed, Fault, FinalitySignature, Step,
     },
 };
 use anyhow::Error;
 use async_trait::async_trait;
-use casper_event_types::FinalitySignature as FinSig;
+use casper_event_types::{
+    block::json_compatibility::{JsonEraEnd, JsonEraReport},
+    ExecutableDeployItem, FinalitySignature as FinSig,
+};
+use casper_types::{AsymmetricType, PublicKey, Timestamp, U512};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
 use utoipa::ToSchema;
 
 #[derive(Clone)]
@@ -209,6 +216,13 @@ impl From<anyhow::Error> for DatabaseWriteError {
 pub trait DatabaseReader {
     /// Returns the latest [BlockAdded] by height from the database.
     async fn get_latest_block(&self) -> Result<BlockAdded, DatabaseReadError>;
+    /// Returns the `count` latest [BlockAdded]s, ordered by height descending.
+    ///
+    /// * `count` - Maximum number of blocks to retrieve
+    async fn get_latest_blocks(&self, count: u32) -> Result<Vec<BlockAdded>, DatabaseReadError>;
+    /// Returns the id of the event log entry of the most recently stored [BlockAdded], which
+    /// changes whenever a new block is stored.
+    async fn get_last_block_added_event_log_id(&self) -> Result<u64, DatabaseReadError>;
     /// Returns the [BlockAdded] corresponding to the provided `height`.
     ///
     /// * `height` - Height of the block which should be retrieved
@@ -217,6 +231,25 @@ pub trait DatabaseReader {
     ///
     /// * `hash` - hash which identifies the block
     async fn get_block_by_hash(&self, hash: &str) -> Result<BlockAdded, DatabaseReadError>;
+    /// Returns the heights and the number of the stored blocks of the given era.
+    ///
+    /// * `era_id` - Era whose blocks should be described
+    async fn get_era_block_range(&self, era_id: u64) -> Result<EraBlockRange, DatabaseReadError>;
+    /// Returns the switch block which ended the given era.
+    ///
+    /// * `era_id` - Era whose switch block should be retrieved
+    async fn get_switch_block_of_era(&self, era_id: u64) -> Result<BlockAdded, DatabaseReadError>;
+    /// Returns at most `limit` [BlockAdded]s with heights in the given range, ordered by height ascending.
+    ///
+    /// * `from_height` - Lowest height (inclusive) of the blocks which should be retrieved
+    /// * `to_height` - Highest height (inclusive) of the blocks which should be retrieved, unbounded if `None`
+    /// * `limit` - Maximum number of blocks to retrieve
+    async fn get_blocks_by_height_range(
+        &self,
+        from_height: u64,
+        to_height: Option<u64>,
+        limit: u32,
+    ) -> Result<Vec<BlockAdded>, DatabaseReadError>;
     /// Returns an aggregate of the deploy's events corresponding to the given hex-encoded `hash`
     ///
     /// * `hash` - deploy hash of which the aggregate data should be fetched
@@ -246,6 +279,116 @@ pub trait DatabaseReader {
         &self,
         hash: &str,
     ) -> Result<DeployExpired, DatabaseReadError>;
+    /// Returns [DeployAggregate]s of all deploys processed in the block with the given hex-encoded `block_hash`
+    ///
+    /// * `block_hash` - hash of the block in which the deploys were processed
+    async fn get_deploy_aggregates_by_block(
+        &self,
+        block_hash: &str,
+    ) -> Result<Vec<DeployAggregate>, DatabaseReadError>;
+    /// Returns [DeployAggregate]s of the deploys with the given hex-encoded `deploy_hashes` which
+    /// have been accepted, in no particular order. Hashes of unknown deploys are skipped.
+    ///
+    /// * `deploy_hashes` - hashes of the deploys of which the aggregate data should be fetched
+    async fn get_deploy_aggregates_by_hashes(
+        &self,
+        deploy_hashes: &[String],
+    ) -> Result<Vec<DeployAggregate>, DatabaseReadError>;
+    /// Returns the [DeployTimeline] of the deploy with the given hex-encoded `hash`.
+    ///
+    /// * `hash` - hash of the deploy whose timeline should be retrieved
+    async fn get_deploy_timeline(&self, hash: &str) -> Result<DeployTimeline, DatabaseReadError>;
+    /// Returns a page of [DeploySummary]s of deploys sent by the given hex-encoded `public_key`,
+    /// newest first.
+    ///
+    /// * `public_key` - key of the account which sent the deploys
+    /// * `cursor` - `next_cursor` of the previous page, `None` to fetch the first page
+    /// * `limit` - Maximum number of summaries in the page
+    async fn get_deploy_summaries_by_account(
+        &self,
+        public_key: &str,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<DeploySummary>, DatabaseReadError>;
+    /// Returns a page of [FailedDeploy]s, newest first.
+    ///
+    /// * `error_contains` - case-insensitive substring of the error message, any if `None`
+    /// * `from_height` - Lowest height (inclusive) of the block of the deploys, unbounded if `None`
+    /// * `to_height` - Highest height (inclusive) of the block of the deploys, unbounded if `None`
+    /// * `cursor` - `next_cursor` of the previous page, `None` to fetch the first page
+    /// * `limit` - Maximum number of deploys in the page
+    async fn get_failed_deploys(
+        &self,
+        error_contains: Option<&str>,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<FailedDeploy>, DatabaseReadError>;
+    /// Returns a page of [NativeTransfer]s sent or received by the given account, newest first.
+    ///
+    /// * `account` - hex-encoded account hash of the sender or the recipient
+    /// * `from_height` - Lowest height (inclusive) of the block of the transfers, unbounded if `None`
+    /// * `to_height` - Highest height (inclusive) of the block of the transfers, unbounded if `None`
+    /// * `cursor` - `next_cursor` of the previous page, `None` to fetch the first page
+    /// * `limit` - Maximum number of transfers in the page
+    async fn get_transfers_by_account(
+        &self,
+        account: &str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<NativeTransfer>, DatabaseReadError>;
+    /// Returns the [NativeTransfer]s made by the deploy with the given hex-encoded `hash`, in the
+    /// order they were made. The result is empty for deploys which made no transfer or weren't
+    /// processed yet.
+    ///
+    /// * `hash` - hash of the deploy whose transfers should be retrieved
+    async fn get_transfers_by_deploy(
+        &self,
+        hash: &str,
+    ) -> Result<Vec<NativeTransfer>, DatabaseReadError>;
+    /// Returns a page of [ContractDeploy]s calling the contract with the given hex-encoded `hash`,
+    /// newest first. The hash may be either a contract hash or a contract package hash.
+    ///
+    /// * `hash` - hash of the called contract or of its package
+    /// * `entry_point` - name of the called entry point, any if `None`
+    /// * `cursor` - `next_cursor` of the previous page, `None` to fetch the first page
+    /// * `limit` - Maximum number of deploys in the page
+    async fn get_deploys_by_contract(
+        &self,
+        hash: &str,
+        entry_point: Option<&str>,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<ContractDeploy>, DatabaseReadError>;
+    /// Returns a page of [EventLogEntry]s, oldest first.
+    ///
+    /// * `event_type` - type of the events, any if `None`
+    /// * `event_source_address` - address of the node which delivered the events, any if `None`
+    /// * `since` - Earliest time (inclusive) the events were stored, unbounded if `None`
+    /// * `until` - Latest time (exclusive) the events were stored, unbounded if `None`
+    /// * `cursor` - `next_cursor` of the previous page, `None` to fetch the first page
+    /// * `limit` - Maximum number of entries in the page
+    async fn get_event_log(
+        &self,
+        event_type: Option<EventTypeId>,
+        event_source_address: Option<&str>,
+        since: Option<Timestamp>,
+        until: Option<Timestamp>,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<Page<EventLogEntry>, DatabaseReadError>;
+    /// Returns the [NodeEventStatistics] of the node with the given address.
+    ///
+    /// * `event_source_address` - address of the node, as stored with its events
+    /// * `max_shutdowns` - Maximum number of the most recent shutdowns returned
+    async fn get_node_event_statistics(
+        &self,
+        event_source_address: &str,
+        max_shutdowns: u32,
+    ) -> Result<NodeEventStatistics, DatabaseReadError>;
     /// Returns all [Fault]s that correspond to the given hex-encoded `public_key`
     ///
     /// * `public_key` - key which identifies the fault
@@ -264,10 +407,70 @@ pub trait DatabaseReader {
         &self,
         block_hash: &str,
     ) -> Result<Vec<FinSig>, DatabaseReadError>;
+    /// Returns whether a [FinalitySignature](casper_event_types::FinalitySignature) made with the
+    /// given hex-encoded `public_key` is stored.
+    ///
+    /// * `public_key` - key of the validator which signed
+    async fn has_finality_signatures_by_public_key(
+        &self,
+        public_key: &str,
+    ) -> Result<bool, DatabaseReadError>;
+    /// Returns, for at most `limit` stored blocks with heights in the given range which the
+    /// validator with the given hex-encoded `public_key` was expected to sign, whether it signed
+    /// them, ordered by height ascending. The validator is expected to sign the blocks of the eras
+    /// it had a weight in.
+    ///
+    /// * `public_key` - key of the validator
+    /// * `from_height` - Lowest height (inclusive) of the blocks
+    /// * `to_height` - Highest height (inclusive) of the blocks, unbounded if `None`
+    /// * `limit` - Maximum number of blocks to retrieve
+    async fn get_validator_signatures(
+        &self,
+        public_key: &str,
+        from_height: u64,
+        to_height: Option<u64>,
+        limit: u32,
+    ) -> Result<Vec<ValidatorSignature>, DatabaseReadError>;
+    /// Returns, for every era in which the validator with the given hex-encoded `public_key` had a
+    /// weight, how many of the stored blocks of the era it signed, lowest era first.
+    ///
+    /// * `public_key` - key of the validator
+    /// * `from_era` - Lowest era (inclusive) of the participation, unbounded if `None`
+    /// * `to_era` - Highest era (inclusive) of the participation, unbounded if `None`
+    async fn get_validator_participation(
+        &self,
+        public_key: &str,
+        from_era: Option<u64>,
+        to_era: Option<u64>,
+    ) -> Result<Vec<ValidatorEraParticipation>, DatabaseReadError>;
     /// Returns the [Step] event for the given era.
     ///
     /// * `era` - identifier of era
     async fn get_step_by_era(&self, era: u64) -> Result<Step, DatabaseReadError>;
+    /// Returns the rewards paid to the validator with the given hex-encoded `public_key`, lowest
+    /// era first.
+    ///
+    /// * `public_key` - key of the validator
+    /// * `from_era` - Lowest era (inclusive) of the rewards, unbounded if `None`
+    /// * `to_era` - Highest era (inclusive) of the rewards, unbounded if `None`
+    async fn get_validator_rewards(
+        &self,
+        public_key: &str,
+        from_era: Option<u64>,
+        to_era: Option<u64>,
+    ) -> Result<Vec<ValidatorEraReward>, DatabaseReadError>;
+    /// Returns the weights of the validator with the given hex-encoded `public_key`, lowest era
+    /// first.
+    ///
+    /// * `public_key` - key of the validator
+    /// * `from_era` - Lowest era (inclusive) of the weights, unbounded if `None`
+    /// * `to_era` - Highest era (inclusive) of the weights, unbounded if `None`
+    async fn get_validator_weights(
+        &self,
+        public_key: &str,
+        from_era: Option<u64>,
+        to_era: Option<u64>,
+    ) -> Result<Vec<ValidatorEraWeight>, DatabaseReadError>;
 
     /// Returns number of events stored in db.
     async fn get_number_of_events(&self) -> Result<u64, DatabaseReadError>;
@@ -293,12 +496,617 @@ pub struct DeployAggregate {
     pub(crate) deploy_accepted: Option<DeployAccepted>,
     pub(crate) deploy_processed: Option<DeployProcessed>,
     pub(crate) deploy_expired: bool,
+    /// When and from which node the events of the deploy were received, only included on request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) timeline: Option<DeployTimeline>,
+}
+
+/// When and from which node an event of a deploy was received.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct DeployEventTiming {
+    /// Address of the node which delivered the event.
+    pub(crate) event_source_address: String,
+    /// When the event was stored.
+    #[schema(value_type = String)]
+    pub(crate) inserted_timestamp: Timestamp,
+    /// When the event was emitted.
+    #[schema(value_type = String)]
+    pub(crate) emitted_timestamp: Timestamp,
+}
+
+/// Timings of the events of a deploy's lifecycle.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct DeployTimeline {
+    pub(crate) accepted: Option<DeployEventTiming>,
+    pub(crate) processed: Option<DeployEventTiming>,
+    pub(crate) expired: Option<DeployEventTiming>,
+    /// Milliseconds between storing the DeployAccepted and the DeployProcessed event, `null` until
+    /// both are stored.
+    pub(crate) accept_to_process_latency_ms: Option<u64>,
+}
+
+impl DeployTimeline {
+    pub(crate) fn new(
+        accepted: Option<DeployEventTiming>,
+        processed: Option<DeployEventTiming>,
+        expired: Option<DeployEventTiming>,
+    ) -> Self {
+        let accept_to_process_latency_ms = match (&accepted, &processed) {
+            (Some(accepted), Some(processed)) => Some(
+                processed
+                    .inserted_timestamp
+                    .millis()
+                    .saturating_sub(accepted.inserted_timestamp.millis()),
+            ),
+            _ => None,
+        };
+        DeployTimeline {
+            accepted,
+            processed,
+            expired,
+            accept_to_process_latency_ms,
+        }
+    }
+}
+
+/// Deploys included in a block, in the order of the block body.
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct BlockDeploys {
+    pub(crate) block_hash: String,
+    /// Aggregates of the deploys which were stored as processed in this block.
+    pub(crate) deploys: Vec<DeployAggregate>,
+    /// Hashes listed in the block body which haven't been stored as processed (yet).
+    pub(crate) missing_deploy_hashes: Vec<String>,
+}
+
+impl BlockDeploys {
+    pub(crate) fn new(block: &BlockAdded, aggregates: Vec<DeployAggregate>) -> Self {
+        let mut aggregates_by_hash: HashMap<String, DeployAggregate> = aggregates
+            .into_iter()
+            .map(|aggregate| (aggregate.deploy_hash.clone(), aggregate))
+            .collect();
+        let mut deploys = Vec::new();
+        let mut missing_deploy_hashes = Vec::new();
+        for deploy_hash in block.hex_encoded_deploy_hashes() {
+            match aggregates_by_hash.remove(&deploy_hash) {
+                Some(aggregate) => deploys.push(aggregate),
+                None => missing_deploy_hashes.push(deploy_hash),
+            }
+        }
+        BlockDeploys {
+            block_hash: block.hex_encoded_hash(),
+            deploys,
+            missing_deploy_hashes,
+        }
+    }
+}
+
+/// Deploys requested in a batch, in the order of the request.
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct DeployAggregatesBatch {
+    /// Aggregates of the requested deploys which have been stored as accepted.
+    pub(crate) deploys: Vec<DeployAggregate>,
+    /// Requested hashes of deploys which haven't been stored as accepted (yet).
+    pub(crate) missing_deploy_hashes: Vec<String>,
+}
+
+impl DeployAggregatesBatch {
+    pub(crate) fn new(deploy_hashes: &[String], aggregates: Vec<DeployAggregate>) -> Self {
+        let mut aggregates_by_hash: HashMap<String, DeployAggregate> = aggregates
+            .into_iter()
+            .map(|aggregate| (aggregate.deploy_hash.clone(), aggregate))
+            .collect();
+        let mut deploys = Vec::new();
+        let mut missing_deploy_hashes = Vec::new();
+        for deploy_hash in deploy_hashes {
+            match aggregates_by_hash.remove(deploy_hash) {
+                Some(aggregate) => deploys.push(aggregate),
+                None => missing_deploy_hashes.push(deploy_hash.clone()),
+            }
+        }
+        DeployAggregatesBatch {
+            deploys,
+            missing_deploy_hashes,
+        }
+    }
+}
+
+/// Heights and number of the stored blocks of an era.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EraBlockRange {
+    pub(crate) first_block_height: u64,
+    pub(crate) last_block_height: u64,
+    pub(crate) block_count: u64,
+}
+
+/// Summary of an era, combining its stored blocks, switch block, step and faults.
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct EraSummary {
+    pub(crate) era_id: u64,
+    /// Height of the first stored block of the era.
+    pub(crate) first_block_height: u64,
+    /// Height of the last stored block of the era.
+    pub(crate) last_block_height: u64,
+    /// Number of stored blocks of the era.
+    pub(crate) block_count: u64,
+    /// The block which ended the era, `null` while the era is in progress.
+    pub(crate) switch_block: Option<BlockAdded>,
+    /// Rewards, equivocators and inactive validators reported by the switch block.
+    pub(crate) era_report: Option<JsonEraReport>,
+    /// The step which ended the era, holding its execution effect.
+    pub(crate) step: Option<Step>,
+    /// Faults which occurred in the era.
+    pub(crate) faults: Vec<Fault>,
+}
+
+impl EraSummary {
+    pub(crate) fn new(
+        era_id: u64,
+        block_range: EraBlockRange,
+        switch_block: Option<BlockAdded>,
+        step: Option<Step>,
+        faults: Vec<Fault>,
+    ) -> Self {
+        let era_report = switch_block
+            .as_ref()
+            .and_then(BlockAdded::era_end)
+            .map(|era_end| era_end.era_report().clone());
+        EraSummary {
+            era_id,
+            first_block_height: block_range.first_block_height,
+            last_block_height: block_range.last_block_height,
+            block_count: block_range.block_count,
+            switch_block,
+            era_report,
+            step,
+            faults,
+        }
+    }
+}
+
+/// Reward paid to a validator for an era, as reported by the era's switch block.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct ValidatorEraReward {
+    pub(crate) era_id: u64,
+    pub(crate) amount: u64,
+}
+
+/// Weight of a validator in an era, as announced by the switch block of the preceding era.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct ValidatorEraWeight {
+    pub(crate) era_id: u64,
+    /// Weight of the validator, as a decimal string.
+    pub(crate) weight: String,
+}
+
+/// Whether a validator signed a stored block it was expected to sign.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct ValidatorSignature {
+    pub(crate) block_height: u64,
+    /// Hex-encoded hash of the block.
+    pub(crate) block_hash: String,
+    /// Whether a finality signature of the validator for the block was stored.
+    pub(crate) signed: bool,
+    /// The finality signature of the validator, `null` if the block was missed.
+    pub(crate) finality_signature: Option<FinSig>,
+}
+
+/// Participation of a validator in the finality of the blocks of an era.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct ValidatorEraParticipation {
+    pub(crate) era_id: u64,
+    /// Weight of the validator in the era, as a decimal string.
+    pub(crate) weight: String,
+    /// Number of stored blocks of the era, all of which the validator was expected to sign.
+    pub(crate) expected_blocks: u64,
+    /// Number of stored blocks of the era the validator signed.
+    pub(crate) signed_blocks: u64,
+}
+
+/// Builds the statements storing the validator rewards and weights carried by a switch block,
+/// nothing is stored for other blocks.
+///
+/// Rewards are stored under the era which the block ends, weights under the era which follows it.
+pub(crate) fn create_validator_era_insert_stmts(
+    block_added: &BlockAdded,
+) -> Result<Vec<sea_query::InsertStatement>, sea_query::error::Error> {
+    let era_end = match block_added.era_end() {
+        Some(era_end) => era_end,
+        None => return Ok(vec![]),
+    };
+    let era_id = block_added.get_era_id();
+    let mut insert_stmts = vec![];
+    let rewards: Vec<(String, u64)> = era_end
+        .era_report()
+        .rewards()
+        .iter()
+        .map(|reward| (reward.validator().to_hex(), reward.amount()))
+        .collect();
+    if !rewards.is_empty() {
+        insert_stmts.push(tables::validator_reward::create_insert_stmt(
+            era_id, rewards,
+        )?);
+    }
+    let weights: Vec<(String, String)> = era_end
+        .next_era_validator_weights()
+        .iter()
+        .map(|weight| (weight.validator().to_hex(), weight.weight().to_string()))
+        .collect();
+    if !weights.is_empty() {
+        insert_stmts.push(tables::validator_weight::create_insert_stmt(
+            era_id + 1,
+            weights,
+        )?);
+    }
+    Ok(insert_stmts)
+}
+
+/// Extracts the values of the columns describing the outcome of the deploy's execution.
+pub(crate) fn execution_outcome_of(
+    deploy_processed: &DeployProcessed,
+) -> tables::deploy_processed::ExecutionOutcome {
+    tables::deploy_processed::ExecutionOutcome {
+        is_success: deploy_processed.is_success(),
+        cost: deploy_processed.cost().to_string(),
+        error_message: deploy_processed.error_message().map(str::to_string),
+    }
+}
+
+/// Builds the statement storing the native transfers made by the deploy, `None` if it made none.
+pub(crate) fn create_transfer_insert_stmt(
+    deploy_processed: &DeployProcessed,
+) -> Result<Option<sea_query::InsertStatement>, sea_query::error::Error> {
+    let transfers: Vec<tables::transfer::TransferRecord> = deploy_processed
+        .transfers()
+        .into_iter()
+        .map(|transfer| tables::transfer::TransferRecord {
+            from_account: hex::encode(transfer.from.value()),
+            to_account: transfer
+                .to
+                .map(|account_hash| hex::encode(account_hash.value())),
+            amount: transfer.amount.to_string(),
+            transfer_id: transfer.id,
+        })
+        .collect();
+    if transfers.is_empty() {
+        return Ok(None);
+    }
+    tables::transfer::create_insert_stmt(
+        deploy_processed.hex_encoded_hash(),
+        deploy_processed.hex_encoded_block_hash(),
+        transfers,
+    )
+    .map(Some)
+}
+
+/// Extracts the contract called by the session code of the deploy, `None` if the session doesn't
+/// call a stored contract.
+pub(crate) fn contract_call_of(
+    deploy_accepted: &DeployAccepted,
+) -> Option<tables::contract_call::ContractCallRecord> {
+    let record = match deploy_accepted.deploy().session() {
+        ExecutableDeployItem::StoredContractByHash {
+            hash, entry_point, ..
+        } => tables::contract_call::ContractCallRecord {
+            contract_hash: Some(hex::encode(hash.value())),
+            contract_package_hash: None,
+            contract_name: None,
+            entry_point: entry_point.clone(),
+        },
+        ExecutableDeployItem::StoredVersionedContractByHash {
+            hash, entry_point, ..
+        } => tables::contract_call::ContractCallRecord {
+            contract_hash: None,
+            contract_package_hash: Some(hex::encode(hash.value())),
+            contract_name: None,
+            entry_point: entry_point.clone(),
+        },
+        ExecutableDeployItem::StoredContractByName {
+            name, entry_point, ..
+        }
+        | ExecutableDeployItem::StoredVersionedContractByName {
+            name, entry_point, ..
+        } => tables::contract_call::ContractCallRecord {
+            contract_hash: None,
+            contract_package_hash: None,
+            contract_name: Some(name.clone()),
+            entry_point: entry_point.clone(),
+        },
+        ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
+            return None
+        }
+    };
+    Some(record)
+}
+
+/// Builds the statement storing the contract called by the deploy, `None` if it calls none.
+pub(crate) fn create_contract_call_insert_stmt(
+    deploy_accepted: &DeployAccepted,
+    event_log_id: u64,
+) -> Result<Option<sea_query::InsertStatement>, sea_query::error::Error> {
+    contract_call_of(deploy_accepted)
+        .map(|contract_call| {
+            tables::contract_call::create_insert_stmt(
+                deploy_accepted.hex_encoded_hash(),
+                contract_call,
+                event_log_id,
+            )
+        })
+        .transpose()
+}
+
+/// Finality of a block, computed from its stored finality signatures and the validator weights
+/// announced in the switch block of the preceding era.
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct BlockFinality {
+    pub(crate) block_hash: String,
+    pub(crate) era_id: u64,
+    /// Summed weight of the validators which signed the block, as a decimal string.
+    pub(crate) signed_weight: String,
+    /// Summed weight of all validators of the era, as a decimal string.
+    pub(crate) total_weight: String,
+    /// `signed_weight / total_weight`, rounded down to six decimal places.
+    pub(crate) signed_weight_fraction: f64,
+    /// Hex-encoded public keys of the validators of the era which haven't signed the block.
+    pub(crate) missing_validators: Vec<String>,
+    /// Whether strictly more than two thirds of the total weight signed the block.
+    pub(crate) is_finalized: bool,
+}
+
+impl BlockFinality {
+    pub(crate) fn new(
+        block: &BlockAdded,
+        switch_block: &BlockAdded,
+        signatures: &[FinSig],
+    ) -> Self {
+        let signers: HashSet<&PublicKey> = signatures
+            .iter()
+            .map(|signature| signature.public_key())
+            .collect();
+        let validator_weights = switch_block
+            .era_end()
+            .map(JsonEraEnd::next_era_validator_weights)
+            .unwrap_or_default();
+
+        let mut signed_weight = U512::zero();
+        let mut total_weight = U512::zero();
+        let mut missing_validators = Vec::new();
+        for validator_weight in validator_weights {
+            total_weight += validator_weight.weight();
+            if signers.contains(validator_weight.validator()) {
+                signed_weight += validator_weight.weight();
+            } else {
+                missing_validators.push(validator_weight.validator().to_hex());
+            }
+        }
+
+        let (signed_weight_fraction, is_finalized) = if total_weight.is_zero() {
+            (0.0, false)
+        } else {
+            let parts_per_million = signed_weight * U512::from(1_000_000u64) / total_weight;
+            (
+                parts_per_million.as_u64() as f64 / 1_000_000.0,
+                signed_weight * U512::from(3u64) > total_weight * U512::from(2u64),
+            )
+        };
+
+        BlockFinality {
+            block_hash: block.hex_encoded_hash(),
+            era_id: block.get_era_id(),
+            signed_weight: signed_weight.to_string(),
+            total_weight: total_weight.to_string(),
+            signed_weight_fraction,
+            missing_validators,
+            is_finalized,
+        }
+    }
+}
+
+/// The header of a block, without its era end and proofs, together with the number of deploys
+/// and transfers it includes.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct BlockHeaderSummary {
+    pub(crate) block_hash: String,
+    pub(crate) parent_hash: String,
+    pub(crate) height: u64,
+    pub(crate) era_id: u64,
+    #[schema(value_type = String)]
+    pub(crate) timestamp: Timestamp,
+    /// Hex-encoded public key of the validator which proposed the block.
+    pub(crate) proposer: String,
+    pub(crate) deploy_count: u64,
+    pub(crate) transfer_count: u64,
+    pub(crate) is_switch_block: bool,
+}
+
+impl From<&BlockAdded> for BlockHeaderSummary {
+    fn from(block_added: &BlockAdded) -> Self {
+        let block = block_added.block();
+        BlockHeaderSummary {
+            block_hash: block_added.hex_encoded_hash(),
+            parent_hash: hex::encode(block.header.parent_hash.inner()),
+            height: block.header.height,
+            era_id: block.header.era_id.value(),
+            timestamp: block.header.timestamp,
+            proposer: block.proposer().to_hex(),
+            deploy_count: block.deploy_hashes().len() as u64,
+            transfer_count: block.transfer_hashes().len() as u64,
+            is_switch_block: block.header.era_end.is_some(),
+        }
+    }
+}
+
+/// A single page of a paginated result set.
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[aliases(
+    BlocksPage = Page<BlockAdded>,
+    DeploySummariesPage = Page<DeploySummary>,
+    FailedDeploysPage = Page<FailedDeploy>,
+    TransfersPage = Page<NativeTransfer>,
+    ContractDeploysPage = Page<ContractDeploy>,
+    EventLogPage = Page<EventLogEntry>,
+    ValidatorSignaturesPage = Page<ValidatorSignature>
+)]
+pub struct Page<T> {
+    /// Items of this page, in the order defined by the endpoint.
+    pub(crate) items: Vec<T>,
+    /// Value to pass as `cursor` to fetch the next page. Absent if this is the last page.
+    pub(crate) next_cursor: Option<u64>,
+}
+
+impl<T> Page<T> {
+    /// Builds a page from `items` which were fetched with a limit of `limit + 1`.
+    /// The surplus item is only used to detect that there is a next page and is dropped.
+    ///
+    /// * `cursor_of` - extracts the cursor value of an item
+    pub(crate) fn from_overfetched(
+        mut items: Vec<T>,
+        limit: u32,
+        cursor_of: impl Fn(&T) -> u64,
+    ) -> Self {
+        let next_cursor = if items.len() > limit as usize {
+            items.truncate(limit as usize);
+            items.last().map(cursor_of)
+        } else {
+            None
+        };
+        Page { items, next_cursor }
+    }
+
+    pub(crate) fn empty() -> Self {
+        Page {
+            items: Vec::new(),
+            next_cursor: None,
+        }
+    }
+
+    pub(crate) fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+/// The furthest lifecycle stage a deploy has reached according to the stored events.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(rename_all = "snake_case")]
+pub enum DeployStatus {
+    Accepted,
+    Processed,
+    Expired,
+}
+
+impl DeployStatus {
+    pub(crate) fn from_flags(is_processed: bool, is_expired: bool) -> Self {
+        if is_processed {
+            DeployStatus::Processed
+        } else if is_expired {
+            DeployStatus::Expired
+        } else {
+            DeployStatus::Accepted
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct DeploySummary {
+    pub(crate) deploy_hash: String,
+    pub(crate) status: DeployStatus,
+}
+
+/// A deploy whose execution failed.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct FailedDeploy {
+    pub(crate) deploy_hash: String,
+    pub(crate) block_hash: String,
+    /// Height of the block which executed the deploy, `null` if the block isn't stored.
+    pub(crate) block_height: Option<u64>,
+    /// Cost of the execution, as a decimal string.
+    pub(crate) cost: String,
+    pub(crate) error_message: String,
+}
+
+/// A native transfer made by a processed deploy.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct NativeTransfer {
+    /// Hash of the deploy which made the transfer.
+    pub(crate) deploy_hash: String,
+    pub(crate) block_hash: String,
+    /// Height of the block which executed the deploy, `null` if the block isn't stored.
+    pub(crate) block_height: Option<u64>,
+    /// Hex-encoded account hash of the sender.
+    pub(crate) from: String,
+    /// Hex-encoded account hash of the recipient, `null` if the transfer targeted a purse.
+    pub(crate) to: Option<String>,
+    /// Transferred amount in motes, as a decimal string.
+    pub(crate) amount: String,
+    /// Identifier chosen by the sender.
+    pub(crate) transfer_id: Option<u64>,
+}
+
+/// A deploy whose session code called a stored contract.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct ContractDeploy {
+    pub(crate) deploy_hash: String,
+    /// Hex-encoded hash of the called contract, `null` if it was called through its package.
+    pub(crate) contract_hash: Option<String>,
+    /// Hex-encoded hash of the package of the called contract, `null` if the contract was called
+    /// directly.
+    pub(crate) contract_package_hash: Option<String>,
+    pub(crate) entry_point: String,
+    pub(crate) status: DeployStatus,
+    /// Whether the execution succeeded, `null` if the deploy wasn't processed yet.
+    pub(crate) is_success: Option<bool>,
+    /// Hash of the block which executed the deploy, `null` if the deploy wasn't processed yet.
+    pub(crate) block_hash: Option<String>,
+}
+
+/// An event as it was received from a node.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, ToSchema)]
+pub struct EventLogEntry {
+    /// Position of the event in the log.
+    pub(crate) event_log_id: u64,
+    /// Type of the event, e.g. `BlockAdded`.
+    pub(crate) event_type: String,
+    /// Address of the node which delivered the event.
+    pub(crate) event_source_address: String,
+    /// Id of the event in the event stream of the node.
+    pub(crate) event_id: u32,
+    /// Identifies what the event is about, e.g. the hash of the block or of the deploy.
+    pub(crate) event_key: String,
+    /// When the event was stored.
+    #[schema(value_type = String)]
+    pub(crate) inserted_timestamp: Timestamp,
+    /// When the event was emitted.
+    #[schema(value_type = String)]
+    pub(crate) emitted_timestamp: Timestamp,
+    /// The event as it was stored, `null` for `Shutdown` events which have no payload.
+    #[schema(value_type = Option<Object>)]
+    pub(crate) payload: Option<serde_json::Value>,
+}
+
+/// What the sidecar stored about the events delivered by a node.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Default, ToSchema)]
+pub struct NodeEventStatistics {
+    /// Number of events received from the node, by event type.
+    pub(crate) events_received: BTreeMap<String, u64>,
+    /// When the last event received from the node was stored, `null` if there was none.
+    #[schema(value_type = Option<String>)]
+    pub(crate) last_seen: Option<Timestamp>,
+    /// When the most recent shutdowns of the node were stored, most recent first.
+    #[schema(value_type = Vec<String>)]
+    pub(crate) shutdowns: Vec<Timestamp>,
 }
 
 #[allow(dead_code)] //Allowing dead code here because the Raw enum is used only in ITs
 pub enum StatementWrapper {
     TableCreateStatement(Box<sea_query::TableCreateStatement>),
+    TableAlterStatement(Box<sea_query::TableAlterStatement>),
+    IndexCreateStatement(Box<sea_query::IndexCreateStatement>),
     InsertStatement(sea_query::InsertStatement),
+    UpdateStatement(sea_query::UpdateStatement),
     Raw(String),
 }
 
@@ -307,6 +1115,19 @@ pub enum StatementWrapper {
 pub trait TransactionWrapper: Send + Sync {
     /// Execute the *sql* param in transaction
     async fn execute(&self, sql: &str) -> Result<(), DatabaseWriteError>;
+
+    /// Materialize the *statement* param for the underlying database and execute it in transaction
+    async fn execute_statement(
+        &self,
+        statement: StatementWrapper,
+    ) -> Result<(), DatabaseWriteError>;
+
+    /// Run the *query* param in transaction and return the selected columns of every row.
+    /// All of the selected columns have to be of a textual type.
+    async fn fetch_text_rows(
+        &self,
+        query: sea_query::SelectStatement,
+    ) -> Result<Vec<Vec<String>>, DatabaseWriteError>;
 }
 
 /// Trait used to abstract a set of instructions necessary to perform a migration.
@@ -338,7 +1159,18 @@ pub struct Migration {
 
 impl Migration {
     pub fn get_all_migrations() -> Vec<Migration> {
-        vec![Migration::migration_1()]
+        vec![
+            Migration::migration_1(),
+            Migration::migration_2(),
+            Migration::migration_3(),
+            Migration::migration_4(),
+            Migration::migration_5(),
+            Migration::migration_6(),
+            Migration::migration_7(),
+            Migration::migration_8(),
+            Migration::migration_9(),
+            Migration::migration_10(),
+        ]
     }
 
     pub fn initial() -> Migration {
@@ -367,6 +1199,187 @@ impl Migration {
         }
     }
 
+    pub fn migration_2() -> Migration {
+        Migration {
+            version: Some(2),
+            statement_producers: |_| {
+                Ok(vec![
+                    StatementWrapper::TableAlterStatement(Box::new(
+                        tables::deploy_accepted::create_add_account_column_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::deploy_accepted::create_account_index_stmt(),
+                    )),
+                ])
+            },
+            script_executor: Some(Arc::new(DeployAcceptedAccountBackfill)),
+        }
+    }
+
+    pub fn migration_3() -> Migration {
+        Migration {
+            version: Some(3),
+            statement_producers: |_| {
+                Ok(vec![
+                    StatementWrapper::TableAlterStatement(Box::new(
+                        tables::deploy_processed::create_add_block_hash_column_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::deploy_processed::create_block_hash_index_stmt(),
+                    )),
+                ])
+            },
+            script_executor: Some(Arc::new(DeployProcessedBlockHashBackfill)),
+        }
+    }
+
+    pub fn migration_4() -> Migration {
+        Migration {
+            version: Some(4),
+            statement_producers: |_| {
+                Ok(vec![
+                    StatementWrapper::TableAlterStatement(Box::new(
+                        tables::block_added::create_add_era_id_column_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::block_added::create_era_id_index_stmt(),
+                    )),
+                ])
+            },
+            script_executor: Some(Arc::new(BlockAddedEraIdBackfill)),
+        }
+    }
+
+    pub fn migration_5() -> Migration {
+        Migration {
+            version: Some(5),
+            statement_producers: |_| {
+                Ok(vec![
+                    StatementWrapper::TableCreateStatement(Box::new(
+                        tables::validator_reward::create_table_stmt(),
+                    )),
+                    StatementWrapper::TableCreateStatement(Box::new(
+                        tables::validator_weight::create_table_stmt(),
+                    )),
+                ])
+            },
+            script_executor: Some(Arc::new(ValidatorEraBackfill)),
+        }
+    }
+
+    pub fn migration_6() -> Migration {
+        Migration {
+            version: Some(6),
+            statement_producers: |_| {
+                Ok(vec![
+                    StatementWrapper::TableAlterStatement(Box::new(
+                        tables::deploy_processed::create_add_is_success_column_stmt(),
+                    )),
+                    StatementWrapper::TableAlterStatement(Box::new(
+                        tables::deploy_processed::create_add_cost_column_stmt(),
+                    )),
+                    StatementWrapper::TableAlterStatement(Box::new(
+                        tables::deploy_processed::create_add_error_message_column_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::deploy_processed::create_is_success_index_stmt(),
+                    )),
+                ])
+            },
+            script_executor: Some(Arc::new(DeployProcessedExecutionOutcomeBackfill)),
+        }
+    }
+
+    pub fn migration_7() -> Migration {
+        Migration {
+            version: Some(7),
+            statement_producers: |config: DDLConfiguration| {
+                Ok(vec![
+                    StatementWrapper::TableCreateStatement(Box::new(
+                        tables::transfer::create_table_stmt(config.is_big_integer_id),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::transfer::create_from_account_index_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::transfer::create_to_account_index_stmt(),
+                    )),
+                ])
+            },
+            script_executor: Some(Arc::new(TransferBackfill)),
+        }
+    }
+
+    pub fn migration_8() -> Migration {
+        Migration {
+            version: Some(8),
+            statement_producers: |config: DDLConfiguration| {
+                Ok(vec![
+                    StatementWrapper::TableCreateStatement(Box::new(
+                        tables::contract_call::create_table_stmt(config.is_big_integer_id),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::contract_call::create_contract_hash_index_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::contract_call::create_contract_package_hash_index_stmt(),
+                    )),
+                ])
+            },
+            script_executor: Some(Arc::new(ContractCallBackfill)),
+        }
+    }
+
+    /// Indexes the event log by time and the event tables by event log entry, for the event log
+    /// to be filtered by time and joined to the stored events.
+    pub fn migration_9() -> Migration {
+        Migration {
+            version: Some(9),
+            statement_producers: |_| {
+                Ok(vec![
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::event_log::create_inserted_timestamp_index_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::block_added::create_event_log_id_index_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::deploy_accepted::create_event_log_id_index_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::deploy_processed::create_event_log_id_index_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::deploy_expired::create_event_log_id_index_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::fault::create_event_log_id_index_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::finality_signature::create_event_log_id_index_stmt(),
+                    )),
+                    StatementWrapper::IndexCreateStatement(Box::new(
+                        tables::step::create_event_log_id_index_stmt(),
+                    )),
+                ])
+            },
+            script_executor: None,
+        }
+    }
+
+    /// Indexes the finality signatures by public key, for validators to be looked up by key.
+    pub fn migration_10() -> Migration {
+        Migration {
+            version: Some(10),
+            statement_producers: |_| {
+                Ok(vec![StatementWrapper::IndexCreateStatement(Box::new(
+                    tables::finality_signature::create_public_key_index_stmt(),
+                ))])
+            },
+            script_executor: None,
+        }
+    }
+
     pub fn get_version(&self) -> Option<u32> {
         self.version
     }
@@ -415,3 +1428,288 @@ fn migration_1_ddl_statements(
         init_stmt,
     ]
 }
+
+/// Number of records updated per round of a column backfill.
+const BACKFILL_BATCH_SIZE: u32 = 1000;
+
+/// Populates the `account` column of [DeployAccepted] records which were stored before the column
+/// was introduced.
+struct DeployAcceptedAccountBackfill;
+
+#[async_trait]
+impl MigrationScriptExecutor for DeployAcceptedAccountBackfill {
+    async fn execute(
+        &self,
+        transaction: Arc<dyn TransactionWrapper>,
+    ) -> Result<(), DatabaseWriteError> {
+        backfill_column(
+            transaction.as_ref(),
+            tables::deploy_accepted::create_get_without_account_stmt,
+            |deploy_hash, raw| {
+                let deploy_accepted = serde_json::from_str::<DeployAccepted>(raw)?;
+                Ok(tables::deploy_accepted::create_update_account_stmt(
+                    deploy_hash.to_string(),
+                    deploy_accepted.hex_encoded_account(),
+                ))
+            },
+        )
+        .await
+    }
+}
+
+/// Populates the `block_hash` column of [DeployProcessed] records which were stored before the
+/// column was introduced.
+struct DeployProcessedBlockHashBackfill;
+
+#[async_trait]
+impl MigrationScriptExecutor for DeployProcessedBlockHashBackfill {
+    async fn execute(
+        &self,
+        transaction: Arc<dyn TransactionWrapper>,
+    ) -> Result<(), DatabaseWriteError> {
+        backfill_column(
+            transaction.as_ref(),
+            tables::deploy_processed::create_get_without_block_hash_stmt,
+            |deploy_hash, raw| {
+                let deploy_processed = serde_json::from_str::<DeployProcessed>(raw)?;
+                Ok(tables::deploy_processed::create_update_block_hash_stmt(
+                    deploy_hash.to_string(),
+                    deploy_processed.hex_encoded_block_hash(),
+                ))
+            },
+        )
+        .await
+    }
+}
+
+/// Populates the `era_id` column of [BlockAdded] records which were stored before the column was
+/// introduced.
+struct BlockAddedEraIdBackfill;
+
+#[async_trait]
+impl MigrationScriptExecutor for BlockAddedEraIdBackfill {
+    async fn execute(
+        &self,
+        transaction: Arc<dyn TransactionWrapper>,
+    ) -> Result<(), DatabaseWriteError> {
+        backfill_column(
+            transaction.as_ref(),
+            tables::block_added::create_get_without_era_id_stmt,
+            |block_hash, raw| {
+                let block_added = serde_json::from_str::<BlockAdded>(raw)?;
+                Ok(tables::block_added::create_update_era_id_stmt(
+                    block_hash.to_string(),
+                    block_added.get_era_id(),
+                ))
+            },
+        )
+        .await
+    }
+}
+
+/// Populates the [ValidatorEraReward] and [ValidatorEraWeight] tables from the switch blocks which
+/// were stored before the tables were introduced.
+struct ValidatorEraBackfill;
+
+#[async_trait]
+impl MigrationScriptExecutor for ValidatorEraBackfill {
+    async fn execute(
+        &self,
+        transaction: Arc<dyn TransactionWrapper>,
+    ) -> Result<(), DatabaseWriteError> {
+        // Every block has to be read as switch blocks can only be told apart by their `raw`.
+        let mut last_block_hash = None;
+        loop {
+            let rows = transaction
+                .fetch_text_rows(tables::block_added::create_get_after_hash_stmt(
+                    last_block_hash.take(),
+                    BACKFILL_BATCH_SIZE,
+                ))
+                .await?;
+            for row in rows.iter() {
+                let (block_hash, raw) = match row.as_slice() {
+                    [block_hash, raw] => (block_hash, raw),
+                    _ => {
+                        return Err(DatabaseWriteError::Unhandled(Error::msg(
+                            "Expected block_hash and raw columns in the backfilled row",
+                        )))
+                    }
+                };
+                let block_added = serde_json::from_str::<BlockAdded>(raw)?;
+                for insert_stmt in create_validator_era_insert_stmts(&block_added)? {
+                    transaction
+                        .execute_statement(StatementWrapper::InsertStatement(insert_stmt))
+                        .await?;
+                }
+                last_block_hash = Some(block_hash.clone());
+            }
+            if rows.len() < BACKFILL_BATCH_SIZE as usize {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Populates the `is_success`, `cost` and `error_message` columns of [DeployProcessed] records
+/// which were stored before the columns were introduced.
+struct DeployProcessedExecutionOutcomeBackfill;
+
+#[async_trait]
+impl MigrationScriptExecutor for DeployProcessedExecutionOutcomeBackfill {
+    async fn execute(
+        &self,
+        transaction: Arc<dyn TransactionWrapper>,
+    ) -> Result<(), DatabaseWriteError> {
+        backfill_column(
+            transaction.as_ref(),
+            tables::deploy_processed::create_get_without_execution_outcome_stmt,
+            |deploy_hash, raw| {
+                let deploy_processed = serde_json::from_str::<DeployProcessed>(raw)?;
+                Ok(
+                    tables::deploy_processed::create_update_execution_outcome_stmt(
+                        deploy_hash.to_string(),
+                        execution_outcome_of(&deploy_processed),
+                    ),
+                )
+            },
+        )
+        .await
+    }
+}
+
+/// Populates the [NativeTransfer] table from the [DeployProcessed] records which were stored before
+/// the table was introduced.
+struct TransferBackfill;
+
+#[async_trait]
+impl MigrationScriptExecutor for TransferBackfill {
+    async fn execute(
+        &self,
+        transaction: Arc<dyn TransactionWrapper>,
+    ) -> Result<(), DatabaseWriteError> {
+        // Records are read oldest first, so that the backfilled transfers keep their order.
+        let mut last_event_log_id = None;
+        loop {
+            let rows = transaction
+                .fetch_text_rows(
+                    tables::deploy_processed::create_get_after_event_log_id_stmt(
+                        last_event_log_id.take(),
+                        BACKFILL_BATCH_SIZE,
+                    ),
+                )
+                .await?;
+            for row in rows.iter() {
+                let (event_log_id, raw) = match row.as_slice() {
+                    [event_log_id, raw] => (event_log_id, raw),
+                    _ => {
+                        return Err(DatabaseWriteError::Unhandled(Error::msg(
+                            "Expected event_log_id and raw columns in the backfilled row",
+                        )))
+                    }
+                };
+                let deploy_processed = serde_json::from_str::<DeployProcessed>(raw)?;
+                if let Some(insert_stmt) = create_transfer_insert_stmt(&deploy_processed)? {
+                    transaction
+                        .execute_statement(StatementWrapper::InsertStatement(insert_stmt))
+                        .await?;
+                }
+                last_event_log_id = Some(event_log_id.parse::<u64>().map_err(|err| {
+                    DatabaseWriteError::Unhandled(Error::msg(format!(
+                        "Error parsing event_log_id of the backfilled row: {}",
+                        err
+                    )))
+                })?);
+            }
+            if rows.len() < BACKFILL_BATCH_SIZE as usize {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Populates the `ContractCall` table from the [DeployAccepted] records which were stored before
+/// the table was introduced.
+struct ContractCallBackfill;
+
+#[async_trait]
+impl MigrationScriptExecutor for ContractCallBackfill {
+    async fn execute(
+        &self,
+        transaction: Arc<dyn TransactionWrapper>,
+    ) -> Result<(), DatabaseWriteError> {
+        let mut last_event_log_id = None;
+        loop {
+            let rows = transaction
+                .fetch_text_rows(tables::deploy_accepted::create_get_after_event_log_id_stmt(
+                    last_event_log_id.take(),
+                    BACKFILL_BATCH_SIZE,
+                ))
+                .await?;
+            for row in rows.iter() {
+                let (event_log_id, raw) = match row.as_slice() {
+                    [event_log_id, raw] => (event_log_id, raw),
+                    _ => {
+                        return Err(DatabaseWriteError::Unhandled(Error::msg(
+                            "Expected event_log_id and raw columns in the backfilled row",
+                        )))
+                    }
+                };
+                let event_log_id = event_log_id.parse::<u64>().map_err(|err| {
+                    DatabaseWriteError::Unhandled(Error::msg(format!(
+                        "Error parsing event_log_id of the backfilled row: {}",
+                        err
+                    )))
+                })?;
+                let deploy_accepted = serde_json::from_str::<DeployAccepted>(raw)?;
+                if let Some(insert_stmt) =
+                    create_contract_call_insert_stmt(&deploy_accepted, event_log_id)?
+                {
+                    transaction
+                        .execute_statement(StatementWrapper::InsertStatement(insert_stmt))
+                        .await?;
+                }
+                last_event_log_id = Some(event_log_id);
+            }
+            if rows.len() < BACKFILL_BATCH_SIZE as usize {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Fills a newly added column of already stored records, [BACKFILL_BATCH_SIZE] records at a time.
+///
+/// * `select_batch` - builds a query selecting the key and `raw` columns of at most the given
+/// number of records which still miss the value
+/// * `build_update` - builds the statement setting the value of a single record from its key and `raw`
+async fn backfill_column<S, U>(
+    transaction: &dyn TransactionWrapper,
+    select_batch: S,
+    build_update: U,
+) -> Result<(), DatabaseWriteError>
+where
+    S: Fn(u32) -> sea_query::SelectStatement + Send + Sync,
+    U: Fn(&str, &str) -> Result<sea_query::UpdateStatement, DatabaseWriteError> + Send + Sync,
+{
+    loop {
+        let rows = transaction
+            .fetch_text_rows(select_batch(BACKFILL_BATCH_SIZE))
+            .await?;
+        if rows.is_empty() {
+            return Ok(());
+        }
+        for row in rows {
+            let update_stmt = match row.as_slice() {
+                [key, raw] => build_update(key, raw)?,
+                _ => {
+                    return Err(DatabaseWriteError::Unhandled(Error::msg(
+                        "Expected key and raw columns in the backfilled row",
+                    )))
+                }
+            };
+            transaction
+                .execute_statement(StatementWrapper::UpdateStatement(update_stmt))
+                .await?;
+        }
+    }
+}
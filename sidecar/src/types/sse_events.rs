@@ -1,11 +1,17 @@
+use casper_event_types::{
+    block::json_compatibility::JsonEraEnd, BlockHash, Deploy, DeployHash,
+    FinalitySignature as FinSig, JsonBlock,
+};
 #[cfg(test)]
-use casper_event_types::Digest;
-use casper_event_types::{BlockHash, Deploy, DeployHash, FinalitySignature as FinSig, JsonBlock};
+use casper_event_types::{Digest, ExecutableDeployItem};
 #[cfg(test)]
-use casper_types::testing::TestRng;
+use casper_types::{testing::TestRng, SecretKey};
 use casper_types::{
     AsymmetricType, EraId, ExecutionResult, ProtocolVersion, PublicKey, TimeDiff, Timestamp,
+    Transfer, Transform, U512,
 };
+#[cfg(test)]
+use casper_types::{ExecutionEffect, TransformEntry};
 use derive_new::new;
 #[cfg(test)]
 use rand::Rng;
@@ -38,6 +44,53 @@ impl BlockAdded {
             block: Box::new(block),
         }
     }
+
+    pub fn random_with_height(rng: &mut TestRng, height: u64) -> Self {
+        let mut block = JsonBlock::random(rng);
+        block.header.height = height;
+        Self {
+            block_hash: block.hash,
+            block: Box::new(block),
+        }
+    }
+
+    pub fn random_with_timestamp(rng: &mut TestRng, height: u64, timestamp: Timestamp) -> Self {
+        let mut block = JsonBlock::random(rng);
+        block.header.height = height;
+        block.header.timestamp = timestamp;
+        Self {
+            block_hash: block.hash,
+            block: Box::new(block),
+        }
+    }
+
+    pub fn random_with_deploy_hashes(rng: &mut TestRng, deploy_hashes: Vec<DeployHash>) -> Self {
+        let block = JsonBlock::random_with_deploy_hashes(rng, deploy_hashes);
+        Self {
+            block_hash: block.hash,
+            block: Box::new(block),
+        }
+    }
+
+    pub fn random_in_era(
+        rng: &mut TestRng,
+        era_id: u64,
+        height: u64,
+        era_end: Option<JsonEraEnd>,
+    ) -> Self {
+        let mut block = JsonBlock::random(rng);
+        block.header.era_id = EraId::new(era_id);
+        block.header.height = height;
+        block.header.era_end = era_end;
+        Self {
+            block_hash: block.hash,
+            block: Box::new(block),
+        }
+    }
+
+    pub fn block_hash(&self) -> BlockHash {
+        self.block_hash
+    }
 }
 
 impl BlockAdded {
@@ -48,6 +101,33 @@ impl BlockAdded {
     pub fn get_height(&self) -> u64 {
         self.block.header.height
     }
+
+    pub fn get_era_id(&self) -> u64 {
+        self.block.header.era_id.value()
+    }
+
+    pub fn get_protocol_version(&self) -> ProtocolVersion {
+        self.block.header.protocol_version
+    }
+
+    pub fn block(&self) -> &JsonBlock {
+        &self.block
+    }
+
+    /// The era end of a switch block, `None` for any other block.
+    pub fn era_end(&self) -> Option<&JsonEraEnd> {
+        self.block.header.era_end.as_ref()
+    }
+
+    /// Hex-encoded hashes of the deploys included in the block, followed by those of the transfers.
+    pub fn hex_encoded_deploy_hashes(&self) -> Vec<String> {
+        self.block
+            .deploy_hashes()
+            .iter()
+            .chain(self.block.transfer_hashes())
+            .map(|deploy_hash| hex::encode(deploy_hash.inner()))
+            .collect()
+    }
 }
 
 /// The given deploy has been newly-accepted by this node.
@@ -65,6 +145,20 @@ impl DeployAccepted {
         }
     }
 
+    #[cfg(test)]
+    pub fn random_with_secret_key(rng: &mut TestRng, secret_key: &SecretKey) -> Self {
+        Self {
+            deploy: Arc::new(Deploy::random_with_secret_key(rng, secret_key)),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn random_with_session(rng: &mut TestRng, session: ExecutableDeployItem) -> Self {
+        Self {
+            deploy: Arc::new(Deploy::random_with_session(rng, session)),
+        }
+    }
+
     #[cfg(test)]
     pub fn deploy_hash(&self) -> DeployHash {
         self.deploy.hash().to_owned()
@@ -73,6 +167,14 @@ impl DeployAccepted {
     pub fn hex_encoded_hash(&self) -> String {
         hex::encode(self.deploy.hash().inner())
     }
+
+    pub fn hex_encoded_account(&self) -> String {
+        self.deploy.header().account().to_hex()
+    }
+
+    pub fn deploy(&self) -> &Deploy {
+        &self.deploy
+    }
 }
 
 /// The given deploy has been executed, committed and forms part of the given block.
@@ -105,9 +207,121 @@ impl DeployProcessed {
         }
     }
 
+    #[cfg(test)]
+    pub fn random_in_block(
+        rng: &mut TestRng,
+        deploy_hash: DeployHash,
+        block_hash: BlockHash,
+    ) -> Self {
+        let mut deploy_processed = Self::random(rng, Some(deploy_hash));
+        deploy_processed.block_hash = Box::new(block_hash);
+        deploy_processed
+    }
+
+    /// Generates a random instance, executed in the given block, whose execution failed with the
+    /// given `error_message`.
+    #[cfg(test)]
+    pub fn random_failed(rng: &mut TestRng, block_hash: BlockHash, error_message: &str) -> Self {
+        let mut deploy_processed = Self::random(rng, None);
+        deploy_processed.block_hash = Box::new(block_hash);
+        let (effect, transfers, cost) = match rng.gen() {
+            ExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+            }
+            | ExecutionResult::Failure {
+                effect,
+                transfers,
+                cost,
+                ..
+            } => (effect, transfers, cost),
+        };
+        deploy_processed.execution_result = Box::new(ExecutionResult::Failure {
+            effect,
+            transfers,
+            cost,
+            error_message: error_message.to_string(),
+        });
+        deploy_processed
+    }
+
+    /// Generates a random instance, executed successfully in the given block, whose execution
+    /// made only the given transfers.
+    #[cfg(test)]
+    pub fn random_with_transfers(
+        rng: &mut TestRng,
+        block_hash: BlockHash,
+        transfers: Vec<Transfer>,
+    ) -> Self {
+        let mut deploy_processed = Self::random(rng, None);
+        deploy_processed.block_hash = Box::new(block_hash);
+        let transforms = transfers
+            .into_iter()
+            .map(|transfer| TransformEntry {
+                key: format!("transfer-{}", hex::encode(rng.gen::<[u8; 32]>())),
+                transform: Transform::WriteTransfer(transfer),
+            })
+            .collect();
+        deploy_processed.execution_result = Box::new(ExecutionResult::Success {
+            effect: ExecutionEffect {
+                operations: vec![],
+                transforms,
+            },
+            transfers: vec![],
+            cost: U512::from(rng.gen::<u64>()),
+        });
+        deploy_processed
+    }
+
     pub fn hex_encoded_hash(&self) -> String {
         hex::encode(self.deploy_hash.inner())
     }
+
+    pub fn hex_encoded_block_hash(&self) -> String {
+        hex::encode(self.block_hash.inner())
+    }
+
+    pub fn execution_result(&self) -> &ExecutionResult {
+        &self.execution_result
+    }
+
+    /// Whether the deploy was executed successfully.
+    pub fn is_success(&self) -> bool {
+        matches!(*self.execution_result, ExecutionResult::Success { .. })
+    }
+
+    /// Cost of the execution of the deploy.
+    pub fn cost(&self) -> U512 {
+        match *self.execution_result {
+            ExecutionResult::Success { cost, .. } | ExecutionResult::Failure { cost, .. } => cost,
+        }
+    }
+
+    /// Error message of a failed execution, `None` if the deploy was executed successfully.
+    pub fn error_message(&self) -> Option<&str> {
+        match &*self.execution_result {
+            ExecutionResult::Success { .. } => None,
+            ExecutionResult::Failure { error_message, .. } => Some(error_message.as_str()),
+        }
+    }
+
+    /// Native transfers written by the execution of the deploy, in the order they were made.
+    pub fn transfers(&self) -> Vec<&Transfer> {
+        let effect = match &*self.execution_result {
+            ExecutionResult::Success { effect, .. } | ExecutionResult::Failure { effect, .. } => {
+                effect
+            }
+        };
+        effect
+            .transforms
+            .iter()
+            .filter_map(|entry| match &entry.transform {
+                Transform::WriteTransfer(transfer) => Some(transfer),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 /// The given deploy has expired.
@@ -172,6 +386,11 @@ impl FinalitySignature {
         )))
     }
 
+    #[cfg(test)]
+    pub fn signed_by(block_hash: BlockHash, era_id: u64, secret_key: &SecretKey) -> Self {
+        Self(Box::new(FinSig::create(block_hash, era_id, secret_key)))
+    }
+
     pub fn inner(&self) -> FinSig {
         *self.0.clone()
     }
@@ -9,7 +9,7 @@ use std::time::Instant;
 use std::{
     fmt::{self, Debug, Display, Formatter},
     io,
-    net::{SocketAddr, ToSocketAddrs},
+    net::{IpAddr, SocketAddr, ToSocketAddrs},
 };
 use thiserror::Error;
 #[cfg(feature = "additional-metrics")]
@@ -59,6 +59,19 @@ impl Display for ResolveAddressErrorKind {
     }
 }
 
+/// Address the servers bind to if none is configured.
+const BIND_ALL_INTERFACES: &str = "0.0.0.0";
+
+/// Joins the configured address of a server, or all interfaces if it isn't set, with its port.
+/// IPv6 addresses are enclosed in brackets, so that the result can be resolved.
+pub(crate) fn bind_address(maybe_address: Option<&str>, port: u16) -> String {
+    let address = maybe_address.unwrap_or(BIND_ALL_INTERFACES);
+    match address.parse::<IpAddr>() {
+        Ok(ip_address) => SocketAddr::new(ip_address, port).to_string(),
+        Err(_) => format!("{}:{}", address, port),
+    }
+}
+
 /// Parses a network address from a string, with DNS resolution.
 pub(crate) fn resolve_address(address: &str) -> Result<SocketAddr, ResolveAddressError> {
     address
@@ -89,6 +102,24 @@ pub(crate) enum ListeningError {
         /// The failure reason.
         error: Box<dyn std::error::Error + Send + Sync>,
     },
+
+    /// Failed to listen on a Unix domain socket.
+    #[error("failed to listen on Unix domain socket {path}: {error}")]
+    ListenUnixSocket {
+        /// The path of the socket.
+        path: String,
+        /// The failure reason.
+        error: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    /// Failed to set up TLS.
+    #[error("failed to set up TLS on {listening_on}: {error:#}")]
+    Tls {
+        /// Where the server listens.
+        listening_on: String,
+        /// The failure reason.
+        error: anyhow::Error,
+    },
 }
 
 /// Handle the case where no filter URL was specified after the root address (HOST:PORT).
@@ -339,11 +339,16 @@ impl FinalitySignature {
 #[cfg(feature = "sse-data-testing")]
 impl FinalitySignature {
     pub fn random_for_block(block_hash: BlockHash, era_id: u64, rng: &mut TestRng) -> Self {
+        let secret_key = SecretKey::random(rng);
+        FinalitySignature::create(block_hash, era_id, &secret_key)
+    }
+
+    /// Creates a signature of the given block by the validator owning `secret_key`.
+    pub fn create(block_hash: BlockHash, era_id: u64, secret_key: &SecretKey) -> Self {
         let mut bytes = block_hash.inner().into_vec();
         bytes.extend_from_slice(&era_id.to_le_bytes());
-        let secret_key = SecretKey::random(rng);
-        let public_key = PublicKey::from(&secret_key);
-        let signature = crypto::sign(bytes, &secret_key, &public_key);
+        let public_key = PublicKey::from(secret_key);
+        let signature = crypto::sign(bytes, secret_key, &public_key);
 
         FinalitySignature {
             block_hash,
@@ -379,6 +384,30 @@ pub mod json_compatibility {
         weight: U512,
     }
 
+    impl Reward {
+        /// Public key of the rewarded validator.
+        pub fn validator(&self) -> &PublicKey {
+            &self.validator
+        }
+
+        /// Amount of the reward.
+        pub fn amount(&self) -> u64 {
+            self.amount
+        }
+    }
+
+    impl ValidatorWeight {
+        /// Public key of the validator.
+        pub fn validator(&self) -> &PublicKey {
+            &self.validator
+        }
+
+        /// Weight of the validator.
+        pub fn weight(&self) -> U512 {
+            self.weight
+        }
+    }
+
     /// Equivocation and reward information to be included in the terminal block.
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
     #[serde(deny_unknown_fields)]
@@ -390,6 +419,13 @@ pub mod json_compatibility {
         inactive_validators: Vec<PublicKey>,
     }
 
+    impl JsonEraReport {
+        /// Rewards paid to the validators for the ended era.
+        pub fn rewards(&self) -> &[Reward] {
+            &self.rewards
+        }
+    }
+
     impl From<EraReport> for JsonEraReport {
         fn from(era_report: EraReport) -> Self {
             JsonEraReport {
@@ -428,6 +464,48 @@ pub mod json_compatibility {
         next_era_validator_weights: Vec<ValidatorWeight>,
     }
 
+    impl JsonEraEnd {
+        /// Equivocation, reward and inactivity information of the ended era.
+        pub fn era_report(&self) -> &JsonEraReport {
+            &self.era_report
+        }
+
+        /// Weights of the validators of the following era.
+        pub fn next_era_validator_weights(&self) -> &[ValidatorWeight] {
+            &self.next_era_validator_weights
+        }
+
+        /// Generates a random instance but with the given `next_era_validator_weights`.
+        #[cfg(feature = "sse-data-testing")]
+        pub fn random_with_validator_weights(
+            rng: &mut TestRng,
+            next_era_validator_weights: Vec<(PublicKey, U512)>,
+        ) -> Self {
+            let mut era_end = JsonEraEnd::from(random_era_end(rng));
+            era_end.next_era_validator_weights = next_era_validator_weights
+                .into_iter()
+                .map(|(validator, weight)| ValidatorWeight { validator, weight })
+                .collect();
+            era_end
+        }
+
+        /// Generates a random instance but with the given `rewards` and `next_era_validator_weights`.
+        #[cfg(feature = "sse-data-testing")]
+        pub fn random_with_rewards_and_validator_weights(
+            rng: &mut TestRng,
+            rewards: Vec<(PublicKey, u64)>,
+            next_era_validator_weights: Vec<(PublicKey, U512)>,
+        ) -> Self {
+            let mut era_end =
+                JsonEraEnd::random_with_validator_weights(rng, next_era_validator_weights);
+            era_end.era_report.rewards = rewards
+                .into_iter()
+                .map(|(validator, amount)| Reward { validator, amount })
+                .collect();
+            era_end
+        }
+    }
+
     impl From<EraEnd> for JsonEraEnd {
         fn from(data: EraEnd) -> Self {
             let json_era_end = JsonEraReport::from(data.era_report);
@@ -592,6 +670,11 @@ pub mod json_compatibility {
             &self.body.transfer_hashes
         }
 
+        /// Returns the public key of the validator which proposed the `Block`.
+        pub fn proposer(&self) -> &PublicKey {
+            &self.body.proposer
+        }
+
         #[cfg(feature = "sse-data-testing")]
         pub fn random(rng: &mut TestRng) -> Self {
             let block = Block::random(rng);
@@ -616,6 +699,18 @@ pub mod json_compatibility {
                 proofs,
             }
         }
+
+        /// Generates a random instance but with the given `deploy_hashes` and no transfers.
+        #[cfg(feature = "sse-data-testing")]
+        pub fn random_with_deploy_hashes(
+            rng: &mut TestRng,
+            deploy_hashes: Vec<DeployHash>,
+        ) -> Self {
+            let mut block = JsonBlock::random(rng);
+            block.body.deploy_hashes = deploy_hashes;
+            block.body.transfer_hashes = Vec::new();
+            block
+        }
     }
 
     impl From<JsonBlock> for Block {
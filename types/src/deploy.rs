@@ -242,6 +242,42 @@ impl Deploy {
         rng: &mut TestRng,
         timestamp: Timestamp,
         ttl: TimeDiff,
+    ) -> Self {
+        let secret_key = SecretKey::random(rng);
+        Deploy::random_with_timestamp_ttl_and_secret_key(rng, timestamp, ttl, &secret_key)
+    }
+
+    /// Generates a random instance but with the account and approval derived from `secret_key`.
+    pub fn random_with_secret_key(rng: &mut TestRng, secret_key: &SecretKey) -> Self {
+        let timestamp = Timestamp::random(rng);
+        let ttl = TimeDiff::from_millis(rng.gen_range(60_000..3_600_000));
+        Deploy::random_with_timestamp_ttl_and_secret_key(rng, timestamp, ttl, secret_key)
+    }
+
+    /// Generates a random instance but with the given `session` code.
+    pub fn random_with_session(rng: &mut TestRng, session: ExecutableDeployItem) -> Self {
+        let timestamp = Timestamp::random(rng);
+        let ttl = TimeDiff::from_millis(rng.gen_range(60_000..3_600_000));
+        let secret_key = SecretKey::random(rng);
+        Deploy::random_with_all_fields(rng, timestamp, ttl, &secret_key, session)
+    }
+
+    fn random_with_timestamp_ttl_and_secret_key(
+        rng: &mut TestRng,
+        timestamp: Timestamp,
+        ttl: TimeDiff,
+        secret_key: &SecretKey,
+    ) -> Self {
+        let session = rng.gen();
+        Deploy::random_with_all_fields(rng, timestamp, ttl, secret_key, session)
+    }
+
+    fn random_with_all_fields(
+        rng: &mut TestRng,
+        timestamp: Timestamp,
+        ttl: TimeDiff,
+        secret_key: &SecretKey,
+        session: ExecutableDeployItem,
     ) -> Self {
         // Create the deploy "body", i.e. the payment and session items.
         //
@@ -254,11 +290,9 @@ impl Deploy {
             entry_point: String::from("example-entry-point"),
             args: payment_args,
         };
-        let session = rng.gen();
 
         // Create the deploy header.
-        let secret_key = SecretKey::random(rng);
-        let account = PublicKey::from(&secret_key);
+        let account = PublicKey::from(secret_key);
         let gas_price = rng.gen_range(1..100);
         let body_hash = Digest::hash(serialize_body(&payment, &session));
         let dependencies_count = rng.gen_range(0..4);
@@ -278,7 +312,7 @@ impl Deploy {
 
         // Create the deploy hash and approval.
         let hash = DeployHash::new(Digest::hash(serialize_header(&header)));
-        let approvals = iter::once(Approval::create(&hash, &secret_key)).collect();
+        let approvals = iter::once(Approval::create(&hash, secret_key)).collect();
 
         Deploy {
             hash,
@@ -1,5 +1,5 @@
 use once_cell::sync::Lazy;
-use prometheus::{GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry};
+use prometheus::{Gauge, GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry};
 #[cfg(feature = "additional-metrics")]
 const DB_OPERATION_BUCKETS: &[f64; 8] = &[
     3e+5_f64, 3e+6_f64, 10e+6_f64, 20e+6_f64, 5e+7_f64, 1e+8_f64, 5e+8_f64, 1e+9_f64,
@@ -56,6 +56,31 @@ pub static NODE_STATUSES: Lazy<GaugeVec> = Lazy::new(|| {
         .expect("cannot register metric");
     counter
 });
+pub static LAST_INGESTED_EVENT_TIMESTAMP: Lazy<Gauge> = Lazy::new(|| {
+    let gauge = Gauge::new(
+        "last_ingested_event_timestamp",
+        "Unix time (in seconds) at which sidecar last received an event from one of the nodes. 0 if no event was received yet.",
+    )
+    .expect("metric can't be created");
+    REGISTRY
+        .register(Box::new(gauge.clone()))
+        .expect("cannot register metric");
+    gauge
+});
+pub static API_KEY_REJECTIONS: Lazy<IntCounterVec> = Lazy::new(|| {
+    let counter = IntCounterVec::new(
+        Opts::new(
+            "api_key_rejections",
+            "Requests rejected because of a missing or unknown API key, or because the key exceeded its quota. The \"key\" label holds the configured name of the key, which is empty if no known key was provided.",
+        ),
+        &["server", "key", "reason"],
+    )
+    .expect("metric can't be created");
+    REGISTRY
+        .register(Box::new(counter.clone()))
+        .expect("cannot register metric");
+    counter
+});
 
 #[cfg(feature = "additional-metrics")]
 pub static DB_OPERATION_TIMES: Lazy<HistogramVec> = Lazy::new(|| {